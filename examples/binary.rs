@@ -2,7 +2,7 @@
 // # Example: The binary field _F2_.
 //
 // The _binary_ values {`F`,`T`} form a _finite field_ with binary XOR
-// as "addition", binary AND as "multiplication", and binary NOT as
+// as "addition", binary AND as "multiplication", and the identity as
 // "negation". This field is known as _F2_ or _GF(2)_ [GF2].
 //
 // This means the familiar digital logic or computing boolean type
@@ -16,11 +16,6 @@ extern crate un_algebra;
 use un_algebra::prelude::*;
 
 
-// Use proptest for generative testing.
-#[macro_use]
-extern crate proptest;
-
-
 //
 // The binary field F2.
 //
@@ -70,12 +65,10 @@ impl AddMonoid for F2 {
 //
 impl AddGroup for F2 {
 
-  // F2 negation is binary NOT.
+  // F2 values are their own negation (binary XOR with itself is
+  // FALSE).
   fn negate(&self) -> Self {
-    match self {
-      F2::F => F2::T,
-      F2::T => F2::F,
-    }
+    self.clone()
   }
 }
 
@@ -126,19 +119,16 @@ impl MulMonoid for F2 {
 //
 impl MulGroup for F2 {
 
-  // Invert is binary NOT.
+  // The only invertible value, binary true, is its own inverse.
   fn invert(&self) -> Self {
-    match self {
-      F2::F => F2::T,
-      F2::T => F2::F,
-    }
+    self.clone()
   }
 
 
-  // All binary elements are invertible.
+  // Only binary true is invertible.
   fn is_invertible(&self) -> bool {
-    true
-  }  
+    *self == F2::T
+  }
 }
 
 
@@ -165,220 +155,274 @@ impl ComRing for F2 {}
 //
 impl Field for F2 {
 
-  // Invert is binary NOT.
+  // The only invertible value, binary true, is its own inverse.
   fn invert(&self) -> Self {
-    match self {
-      F2::F => F2::T,
-      F2::T => F2::F,
-    }
+    self.clone()
   }
 
 
-  // All boolean elements are invertible.
+  // Only binary true is invertible.
   fn is_invertible(&self) -> bool {
-    true
-  }    
-}
-
-
-prop_compose! {
-  // Generate 1 random boolean test value.
-  fn bln_1()(i in 0..1) -> F2 {
-    ([F2::F, F2::T][i as usize]).clone()
+    *self == F2::T
   }
 }
 
 
-prop_compose! {
-  // Generate 2 random boolean test values.
-  fn bln_2()(xs in (bln_1(), bln_1())) -> (F2, F2) {
-    xs
-  }
-}
-
+//
+// F2 values form a finite type.
+//
+impl Finite for F2 {
 
-prop_compose! {
-  // Generate 3 random boolean test values.
-  fn bln_3()(xs in (bln_1(), bln_1(), bln_1())) -> (F2, F2, F2) {
-    xs
+  // The F2 values in declaration order.
+  fn elements() -> impl Iterator<Item = Self> {
+    IntoIterator::into_iter([F2::F, F2::T])
   }
 }
 
 
-// Generative tests for F4 algebraic axioms and properties.
-proptest! {
+// Exhaustive tests of F2 algebraic axioms and properties.
+#[cfg(test)]
+mod tests {
+  use super::*;
+
 
   #[test]
-  fn axiom_add_closure((ref x, ref y) in bln_2()) {
-    AddMagma::axiom_add_closure((x, y))
+  fn axiom_left_add_identity() {
+    let result = exhaustive_1::<F2, _>(AddMonoid::axiom_left_add_identity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_mul_closure((ref x, ref y) in bln_2()) {
-    MulMagma::axiom_mul_closure((x, y))
+  fn axiom_right_add_identity() {
+    let result = exhaustive_1::<F2, _>(AddMonoid::axiom_right_add_identity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_mul_associativity((ref w, ref x, ref y) in bln_3()) {
-    MulSemigroup::axiom_mul_associativity((w, x, y))
+  fn axiom_left_mul_identity() {
+    let result = exhaustive_1::<F2, _>(MulMonoid::axiom_left_mul_identity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_add_associativity((ref w, ref x, ref y) in bln_3()) {
-    AddSemigroup::axiom_add_associativity((w, x, y))
+  fn axiom_right_mul_identity() {
+    let result = exhaustive_1::<F2, _>(MulMonoid::axiom_right_mul_identity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_left_add_identity(ref x in bln_1()) {
-    AddMonoid::axiom_left_add_identity(x)
+  fn axiom_left_negate() {
+    let result = exhaustive_1::<F2, _>(AddGroup::axiom_left_negate);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_right_add_identity(ref x in bln_1()) {
-    AddMonoid::axiom_left_add_identity(x)
+  fn axiom_right_negate() {
+    let result = exhaustive_1::<F2, _>(AddGroup::axiom_right_negate);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_left_mul_identity(ref x in bln_1()) {
-    MulMonoid::axiom_left_mul_identity(x)
+  fn prop_left_zero_absorb() {
+    let result = exhaustive_1::<F2, _>(Ring::prop_left_zero_absorb);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_right_mul_identity(ref x in bln_1()) {
-    MulMonoid::axiom_right_mul_identity(x)
+  fn prop_right_zero_absorb() {
+    let result = exhaustive_1::<F2, _>(Ring::prop_right_zero_absorb);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_left_negate(ref x in bln_1()) {
-    AddGroup::axiom_left_negate(x)
+  fn prop_left_one_negate() {
+    let result = exhaustive_1::<F2, _>(Ring::prop_left_one_negate);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_right_negate(ref x in bln_1()) {
-    AddGroup::axiom_right_negate(x)
+  fn prop_right_one_negate() {
+    let result = exhaustive_1::<F2, _>(Ring::prop_right_one_negate);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_left_invert(ref x in bln_1()) {
-    MulGroup::axiom_left_invert(x)
+  fn axiom_left_invert() {
+    let result = exhaustive_1(|x: &F2| {
+      implies(MulGroup::is_invertible(x), MulGroup::axiom_left_invert(x))
+    });
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_right_invert(ref x in bln_1()) {
-    MulGroup::axiom_right_invert(x)
+  fn axiom_right_invert() {
+    let result = exhaustive_1(|x: &F2| {
+      implies(MulGroup::is_invertible(x), MulGroup::axiom_right_invert(x))
+    });
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_add_commutivity((ref x, ref y) in bln_2()) {
-    AddComGroup::axiom_add_commutivity((x, y))
+  fn axiom_field_left_invert() {
+    let result = exhaustive_1(|x: &F2| {
+      implies(Field::is_invertible(x), Field::axiom_left_invert(x))
+    });
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_mul_commutivity((ref x, ref y) in bln_2()) {
-    MulComGroup::axiom_mul_commutivity((x, y))
+  fn axiom_field_right_invert() {
+    let result = exhaustive_1(|x: &F2| {
+      implies(Field::is_invertible(x), Field::axiom_right_invert(x))
+    });
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_left_distributivity((ref x, ref y, ref z) in bln_3()) {
-    Ring::axiom_left_distributivity((x, y, z))
+  fn axiom_add_closure() {
+    let result = exhaustive_2::<F2, _>(AddMagma::axiom_add_closure);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_right_distributivity((ref x, ref y, ref z) in bln_3()) {
-    Ring::axiom_right_distributivity((x, y, z))
+  fn axiom_mul_closure() {
+    let result = exhaustive_2::<F2, _>(MulMagma::axiom_mul_closure);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_left_zero_absorb(ref x in bln_1()) {
-    Ring::prop_left_zero_absorb(x)
+  fn axiom_add_commutivity() {
+    let result = exhaustive_2::<F2, _>(AddComGroup::axiom_add_commutivity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_right_zero_absorb(ref x in bln_1()) {
-    Ring::prop_right_zero_absorb(x)
+  fn axiom_mul_commutivity() {
+    let result = exhaustive_2::<F2, _>(MulComGroup::axiom_mul_commutivity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_left_mul_negate((ref x, ref y) in bln_2()) {
-    Ring::prop_left_mul_negate((x, y))
+  fn axiom_ring_mul_commutivity() {
+    let result = exhaustive_2::<F2, _>(ComRing::axiom_mul_commutivity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_right_mul_negate((ref x, ref y) in bln_2()) {
-    Ring::prop_right_mul_negate((x, y))
+  fn prop_left_mul_negate() {
+    let result = exhaustive_2::<F2, _>(Ring::prop_left_mul_negate);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_mul_negate((ref x, ref y) in bln_2()) {
-    Ring::prop_mul_negate((x, y))
+  fn prop_right_mul_negate() {
+    let result = exhaustive_2::<F2, _>(Ring::prop_right_mul_negate);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_left_one_negate(ref x in bln_1()) {
-    Ring::prop_left_one_negate(x)
+  fn prop_mul_negate() {
+    let result = exhaustive_2::<F2, _>(Ring::prop_mul_negate);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_right_one_negate(ref x in bln_1()) {
-    Ring::prop_right_one_negate(x)
+  fn prop_mul_zero() {
+    let result = exhaustive_2::<F2, _>(Field::prop_mul_zero);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_ring_mul_commutivity((ref x, ref y) in bln_2()) {
-    ComRing::axiom_mul_commutivity((x, y))
+  fn axiom_add_associativity() {
+    let result = exhaustive_3::<F2, _>(AddSemigroup::axiom_add_associativity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_field_left_invert(ref x in bln_1()) {
-    Field::axiom_left_invert(x)
+  fn axiom_mul_associativity() {
+    let result = exhaustive_3::<F2, _>(MulSemigroup::axiom_mul_associativity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_field_right_invert(ref x in bln_1()) {
-    Field::axiom_left_invert(x)
+  fn axiom_left_distributivity() {
+    let result = exhaustive_3::<F2, _>(Ring::axiom_left_distributivity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_mul_zero((ref x, ref y) in bln_2()) {
-    Field::prop_mul_zero((x, y))
+  fn axiom_right_distributivity() {
+    let result = exhaustive_3::<F2, _>(Ring::axiom_right_distributivity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_add_cancel((ref w, ref x, ref y) in bln_3()) {
-    Field::prop_add_cancel((w, x, y))
+  fn prop_add_cancel() {
+    let result = exhaustive_3::<F2, _>(Field::prop_add_cancel);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_mul_cancel((ref w, ref x, ref y) in bln_3()) {
-    Field::prop_mul_cancel((w, x, y))
+  fn prop_mul_cancel() {
+    let result = exhaustive_3::<F2, _>(Field::prop_mul_cancel);
+
+    assert_eq!(result, Ok(()));
   }
 }
 
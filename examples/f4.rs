@@ -12,11 +12,6 @@ extern crate un_algebra;
 use un_algebra::prelude::*;
 
 
-// Use proptest for generative testing.
-#[macro_use]
-extern crate proptest;
-
-
 //
 // The finite field with four elements _F4_ or _GF(4)_. 
 //
@@ -162,10 +157,10 @@ impl MulGroup for F4 {
   }
 
   
-  // All F4 elements are invertible.
+  // All non-zero F4 elements are invertible.
   fn is_invertible(&self) -> bool {
-    true
-  }  
+    *self != F4::O
+  }
 }
 
 
@@ -203,211 +198,268 @@ impl Field for F4 {
   }
 
  
-  // All F4 elements are invertible.
+  // All non-zero F4 elements are invertible.
   fn is_invertible(&self) -> bool {
-    true
-  }    
-}
-
-
-prop_compose! {
-  // Generate 1 random F4 test value.
-  fn f4_1()(i in 0..3) -> F4 {
-    ([F4::O, F4::I, F4::A, F4::B][i as usize]).clone()
+    *self != F4::O
   }
 }
 
 
-prop_compose! {
-  // Generate 2 random F4 test values.
-  fn f4_2()(xs in (f4_1(), f4_1())) -> (F4, F4) {
-    xs
+//
+// F4 values form a finite type.
+//
+impl Finite for F4 {
+
+  // The F4 values in declaration order.
+  fn elements() -> impl Iterator<Item = Self> {
+    IntoIterator::into_iter([F4::O, F4::I, F4::A, F4::B])
   }
 }
 
 
-prop_compose! {
-  // Generate 3 random F4 test values.
-  fn f4_3()(xs in (f4_1(), f4_1(), f4_1())) -> (F4, F4, F4) {
-    xs
-  }
-}
+// Exhaustive tests of F4 algebraic axioms and properties.
+#[cfg(test)]
+mod tests {
+  use super::*;
 
 
-// Generative tests of F4 algebraic axioms and properties.
-proptest! {
-  
   #[test]
-  fn axiom_add_closure((ref x, ref y) in f4_2()) {
-    AddMagma::axiom_add_closure((x, y))
+  fn axiom_left_add_identity() {
+    let result = exhaustive_1::<F4, _>(AddMonoid::axiom_left_add_identity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_mul_closure((ref x, ref y) in f4_2()) {
-    MulMagma::axiom_mul_closure((x, y))
+  fn axiom_right_add_identity() {
+    let result = exhaustive_1::<F4, _>(AddMonoid::axiom_right_add_identity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_mul_associativity((ref w, ref x, ref y) in f4_3()) {
-    MulSemigroup::axiom_mul_associativity((w, x, y))
+  fn axiom_left_mul_identity() {
+    let result = exhaustive_1::<F4, _>(MulMonoid::axiom_left_mul_identity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_add_associativity((ref w, ref x, ref y) in f4_3()) {
-    AddSemigroup::axiom_add_associativity((w, x, y))
+  fn axiom_right_mul_identity() {
+    let result = exhaustive_1::<F4, _>(MulMonoid::axiom_right_mul_identity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_left_add_identity(ref x in f4_1()) {
-    AddMonoid::axiom_left_add_identity(x)
+  fn axiom_left_negate() {
+    let result = exhaustive_1::<F4, _>(AddGroup::axiom_left_negate);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_right_add_identity(ref x in f4_1()) {
-    AddMonoid::axiom_left_add_identity(x)
+  fn axiom_right_negate() {
+    let result = exhaustive_1::<F4, _>(AddGroup::axiom_right_negate);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_left_mul_identity(ref x in f4_1()) {
-    MulMonoid::axiom_left_mul_identity(x)
+  fn prop_left_zero_absorb() {
+    let result = exhaustive_1::<F4, _>(Ring::prop_left_zero_absorb);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_right_mul_identity(ref x in f4_1()) {
-    MulMonoid::axiom_right_mul_identity(x)
+  fn prop_right_zero_absorb() {
+    let result = exhaustive_1::<F4, _>(Ring::prop_right_zero_absorb);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_left_negate(ref x in f4_1()) {
-    AddGroup::axiom_left_negate(x)
+  fn prop_left_one_negate() {
+    let result = exhaustive_1::<F4, _>(Ring::prop_left_one_negate);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_right_negate(ref x in f4_1()) {
-    AddGroup::axiom_right_negate(x)
+  fn prop_right_one_negate() {
+    let result = exhaustive_1::<F4, _>(Ring::prop_right_one_negate);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_left_invert(ref x in f4_1()) {
-    MulGroup::axiom_left_invert(x)
+  fn axiom_left_invert() {
+    let result = exhaustive_1(|x: &F4| {
+      implies(MulGroup::is_invertible(x), MulGroup::axiom_left_invert(x))
+    });
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_right_invert(ref x in f4_1()) {
-    MulGroup::axiom_right_invert(x)
+  fn axiom_right_invert() {
+    let result = exhaustive_1(|x: &F4| {
+      implies(MulGroup::is_invertible(x), MulGroup::axiom_right_invert(x))
+    });
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_add_commutivity((ref x, ref y) in f4_2()) {
-    AddComGroup::axiom_add_commutivity((x, y))
+  fn axiom_field_left_invert() {
+    let result = exhaustive_1(|x: &F4| {
+      implies(Field::is_invertible(x), Field::axiom_left_invert(x))
+    });
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_mul_commutivity((ref x, ref y) in f4_2()) {
-    MulComGroup::axiom_mul_commutivity((x, y))
+  fn axiom_field_right_invert() {
+    let result = exhaustive_1(|x: &F4| {
+      implies(Field::is_invertible(x), Field::axiom_right_invert(x))
+    });
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_left_distributivity((ref x, ref y, ref z) in f4_3()) {
-    Ring::axiom_left_distributivity((x, y, z))
+  fn axiom_add_closure() {
+    let result = exhaustive_2::<F4, _>(AddMagma::axiom_add_closure);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_right_distributivity((ref x, ref y, ref z) in f4_3()) {
-    Ring::axiom_right_distributivity((x, y, z))
+  fn axiom_mul_closure() {
+    let result = exhaustive_2::<F4, _>(MulMagma::axiom_mul_closure);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_left_zero_absorb(ref x in f4_1()) {
-    Ring::prop_left_zero_absorb(x)
+  fn axiom_add_commutivity() {
+    let result = exhaustive_2::<F4, _>(AddComGroup::axiom_add_commutivity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_right_zero_absorb(ref x in f4_1()) {
-    Ring::prop_right_zero_absorb(x)
+  fn axiom_mul_commutivity() {
+    let result = exhaustive_2::<F4, _>(MulComGroup::axiom_mul_commutivity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_left_mul_negate((ref x, ref y) in f4_2()) {
-    Ring::prop_left_mul_negate((x, y))
+  fn axiom_ring_mul_commutivity() {
+    let result = exhaustive_2::<F4, _>(ComRing::axiom_mul_commutivity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_right_mul_negate((ref x, ref y) in f4_2()) {
-    Ring::prop_right_mul_negate((x, y))
+  fn prop_left_mul_negate() {
+    let result = exhaustive_2::<F4, _>(Ring::prop_left_mul_negate);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_mul_negate((ref x, ref y) in f4_2()) {
-    Ring::prop_mul_negate((x, y))
+  fn prop_right_mul_negate() {
+    let result = exhaustive_2::<F4, _>(Ring::prop_right_mul_negate);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_left_one_negate(ref x in f4_1()) {
-    Ring::prop_left_one_negate(x)
+  fn prop_mul_negate() {
+    let result = exhaustive_2::<F4, _>(Ring::prop_mul_negate);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_right_one_negate(ref x in f4_1()) {
-    Ring::prop_right_one_negate(x)
+  fn prop_mul_zero() {
+    let result = exhaustive_2::<F4, _>(Field::prop_mul_zero);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_ring_mul_commutivity((ref x, ref y) in f4_2()) {
-    ComRing::axiom_mul_commutivity((x, y))
+  fn axiom_add_associativity() {
+    let result = exhaustive_3::<F4, _>(AddSemigroup::axiom_add_associativity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_field_left_invert(ref x in f4_1()) {
-    Field::axiom_left_invert(x)
+  fn axiom_mul_associativity() {
+    let result = exhaustive_3::<F4, _>(MulSemigroup::axiom_mul_associativity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn axiom_field_right_invert(ref x in f4_1()) {
-    Field::axiom_left_invert(x)
+  fn axiom_left_distributivity() {
+    let result = exhaustive_3::<F4, _>(Ring::axiom_left_distributivity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_mul_zero((ref x, ref y) in f4_2()) {
-    Field::prop_mul_zero((x, y))
+  fn axiom_right_distributivity() {
+    let result = exhaustive_3::<F4, _>(Ring::axiom_right_distributivity);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_add_cancel((ref w, ref x, ref y) in f4_3()) {
-    Field::prop_add_cancel((w, x, y))
+  fn prop_add_cancel() {
+    let result = exhaustive_3::<F4, _>(Field::prop_add_cancel);
+
+    assert_eq!(result, Ok(()));
   }
 
 
   #[test]
-  fn prop_mul_cancel((ref w, ref x, ref y) in f4_3()) {
-    Field::prop_mul_cancel((w, x, y))
+  fn prop_mul_cancel() {
+    let result = exhaustive_3::<F4, _>(Field::prop_mul_cancel);
+
+    assert_eq!(result, Ok(()));
   }
 }
 
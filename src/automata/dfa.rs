@@ -0,0 +1,221 @@
+//!
+//! _Deterministic_ _finite_ _automata_.
+//!
+//! A _deterministic_ _finite_ _automaton_ (DFA) has states `{0, 1,
+//! ..., n-1}`, letters `{0, 1, ..., k-1}`, a start state, a set of
+//! accepting states, and a transition function giving the next state
+//! for each state and letter. A DFA _accepts_ a word when reading the
+//! word from the start state ends in an accepting state, and the
+//! words it accepts form its (regular) _language_.
+//!
+//! Every regular language has a unique _minimal_ DFA, and the
+//! transition monoid of the minimal DFA is the _syntactic_ _monoid_
+//! of the language.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a DFA.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use std::collections::VecDeque;
+use prelude::*;
+
+
+///
+/// A deterministic finite automaton.
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Dfa {
+  start: usize,
+  accepting: Vec<bool>,
+  delta: Vec<Vec<usize>>,
+}
+
+
+impl Dfa {
+
+  /// A new DFA from its start state, the accepting flag of each
+  /// state, and the next state table of each letter, i.e.
+  /// `delta[a][q]` is the next state from state `q` with letter `a`.
+  /// Panics if a state is out of range.
+  pub fn new(start: usize, accepting: Vec<bool>, delta: Vec<Vec<usize>>)
+    -> Self {
+    let n = accepting.len();
+
+    assert!(start < n, "start state out of range");
+
+    for next in &delta {
+      assert!(next.len() == n && next.iter().all(|&q| q < n),
+        "transition state out of range");
+    }
+
+    Dfa { start, accepting, delta }
+  }
+
+
+  /// The number of states.
+  pub fn states(&self) -> usize {
+    self.accepting.len()
+  }
+
+
+  /// The number of letters.
+  pub fn letters(&self) -> usize {
+    self.delta.len()
+  }
+
+
+  /// The start state.
+  pub fn start(&self) -> usize {
+    self.start
+  }
+
+
+  /// Test a state is accepting.
+  pub fn is_accepting(&self, q: usize) -> bool {
+    self.accepting[q]
+  }
+
+
+  /// The state reached by reading a word from state `q`.
+  pub fn run(&self, q: usize, word: &[usize]) -> usize {
+    word.iter().fold(q, |q, &a| self.delta[a][q])
+  }
+
+
+  /// Test the DFA accepts a word.
+  pub fn accepts(&self, word: &[usize]) -> bool {
+    self.accepting[self.run(self.start, word)]
+  }
+
+
+  /// The state transition of a letter, as a transformation of `N`
+  /// points fixing the points from `states()` onwards. Panics if the
+  /// DFA has more than `N` states.
+  pub fn transition<const N: usize>(&self, a: usize) -> Transformation<N> {
+    assert!(self.states() <= N, "more states than transformation points");
+
+    let mut images = *Transformation::<N>::id().images();
+    images[..self.states()].copy_from_slice(&self.delta[a]);
+
+    Transformation::new(images)
+  }
+
+
+  /// The state transition of a word, as a transformation of `N`
+  /// points. Panics if the DFA has more than `N` states.
+  pub fn word_transition<const N: usize>(&self, word: &[usize])
+    -> Transformation<N> {
+    let id = Transformation::id();
+
+    word.iter().fold(id, |t, &a| t.op(&self.transition(a)))
+  }
+
+
+  /// The transition monoid of the DFA, as transformations of `N`
+  /// points. Panics if the DFA has more than `N` states.
+  pub fn transition_monoid<const N: usize>(&self) -> TransitionMonoid<N> {
+    let letters: Vec<Transformation<N>> =
+      (0..self.letters()).map(|a| self.transition(a)).collect();
+
+    TransitionMonoid::new(&letters)
+  }
+
+
+  /// The syntactic monoid of the DFA language, i.e. the transition
+  /// monoid of the minimal DFA, as transformations of `N` points.
+  /// Panics if the minimal DFA has more than `N` states.
+  pub fn syntactic_monoid<const N: usize>(&self) -> TransitionMonoid<N> {
+    self.minimize().transition_monoid()
+  }
+
+
+  /// Test the DFA language is star-free, i.e. its syntactic monoid is
+  /// aperiodic. Panics if the minimal DFA has more than `N` states.
+  pub fn is_star_free<const N: usize>(&self) -> bool {
+    self.syntactic_monoid::<N>().is_aperiodic()
+  }
+
+
+  /// The minimal DFA accepting the same language, with states
+  /// numbered in breadth-first order from the start state.
+  pub fn minimize(&self) -> Self {
+    let reachable = self.reachable();
+    let mut class: Vec<usize> = vec![0; self.states()];
+    let mut classes = 0;
+
+    for &q in &reachable {
+      class[q] = self.accepting[q] as usize;
+    }
+
+    // Refine the accepting partition until it is stable.
+    loop {
+      let mut signatures: Vec<Vec<usize>> = Vec::new();
+      let mut next = vec![0; self.states()];
+
+      for &q in &reachable {
+        let mut signature = vec![class[q]];
+        signature.extend(self.delta.iter().map(|d| class[d[q]]));
+
+        next[q] = match signatures.iter().position(|s| *s == signature) {
+          Some(c) => c,
+          None => {
+            signatures.push(signature);
+            signatures.len() - 1
+          }
+        };
+      }
+
+      class = next;
+
+      if signatures.len() == classes {
+        break;
+      }
+      classes = signatures.len();
+    }
+
+    let mut accepting = vec![false; classes];
+    let mut delta = vec![vec![0; classes]; self.letters()];
+
+    for &q in &reachable {
+      accepting[class[q]] = self.accepting[q];
+
+      for (a, d) in self.delta.iter().enumerate() {
+        delta[a][class[q]] = class[d[q]];
+      }
+    }
+
+    Dfa::new(class[self.start], accepting, delta)
+  }
+
+
+  /// The states reachable from the start state, in breadth-first
+  /// order.
+  fn reachable(&self) -> Vec<usize> {
+    let mut seen = vec![false; self.states()];
+    let mut order = vec![self.start];
+    let mut queue = VecDeque::new();
+
+    seen[self.start] = true;
+    queue.push_back(self.start);
+
+    while let Some(q) = queue.pop_front() {
+      for d in &self.delta {
+        if !seen[d[q]] {
+          seen[d[q]] = true;
+          order.push(d[q]);
+          queue.push_back(d[q]);
+        }
+      }
+    }
+
+    order
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "dfa_test.rs"]
+mod dfa_test;
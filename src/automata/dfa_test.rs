@@ -0,0 +1,126 @@
+use prelude::*;
+
+
+/// Words over `{a, b}` (letters `0` and `1`) containing `ab`.
+fn contains_ab() -> Dfa {
+  Dfa::new(0, vec![false, false, true], vec![vec![1, 1, 2], vec![0, 2, 2]])
+}
+
+
+/// Words over `{a, b}` in `(ab)*`.
+fn ab_star() -> Dfa {
+  Dfa::new(0, vec![true, false, false], vec![vec![1, 2, 2], vec![2, 0, 2]])
+}
+
+
+/// Words over `{a}` of even length, i.e. `(aa)*`.
+fn even() -> Dfa {
+  Dfa::new(0, vec![true, false], vec![vec![1, 0]])
+}
+
+
+/// All words over `letters` of at most `n` letters.
+fn words(letters: usize, n: usize) -> Vec<Vec<usize>> {
+  let mut words = vec![Vec::new()];
+
+  for len in 0..n {
+    let last: Vec<Vec<usize>> =
+      words.iter().filter(|w| w.len() == len).cloned().collect();
+
+    for word in last {
+      for a in 0..letters {
+        let mut next = word.clone();
+        next.push(a);
+        words.push(next);
+      }
+    }
+  }
+
+  words
+}
+
+
+#[test]
+fn acceptance() {
+  let dfa = contains_ab();
+
+  assert!(dfa.accepts(&[1, 0, 1, 1]));
+  assert!(!dfa.accepts(&[1, 1, 0, 0]));
+  assert!(ab_star().accepts(&[]));
+  assert!(ab_star().accepts(&[0, 1, 0, 1]));
+  assert!(!ab_star().accepts(&[0, 1, 1, 0]));
+}
+
+
+#[test]
+fn word_transitions() {
+  let dfa = contains_ab();
+
+  for word in words(2, 5) {
+    let t = dfa.word_transition::<3>(&word);
+
+    assert_eq!(t.apply(dfa.start()), dfa.run(dfa.start(), &word));
+  }
+}
+
+
+#[test]
+fn minimization() {
+  // A redundant DFA for `contains_ab`, with duplicated and
+  // unreachable states.
+  let dfa = Dfa::new(
+    0,
+    vec![false, false, true, true, false],
+    vec![vec![1, 1, 3, 2, 4], vec![0, 2, 3, 2, 0]],
+  );
+  let minimal = dfa.minimize();
+
+  assert_eq!(minimal.states(), 3);
+  assert_eq!(minimal, contains_ab());
+
+  for word in words(2, 6) {
+    assert_eq!(dfa.accepts(&word), minimal.accepts(&word));
+  }
+}
+
+
+#[test]
+fn syntactic_monoids() {
+  // The syntactic monoid of (ab)* is {1, a, b, ab, ba, 0}.
+  assert_eq!(ab_star().syntactic_monoid::<3>().len(), 6);
+  assert_eq!(even().syntactic_monoid::<2>().len(), 2);
+  assert_eq!(contains_ab().syntactic_monoid::<3>().len(), 5);
+}
+
+
+#[test]
+fn extra_points() {
+  // Points beyond the DFA states are fixed, so the monoid is the same.
+  let t = even().transition::<4>(0);
+
+  assert_eq!(t.images(), &[1, 0, 2, 3]);
+  assert_eq!(even().syntactic_monoid::<4>().len(), 2);
+  assert_eq!(even().word_transition::<4>(&[0, 0]), Transformation::id());
+}
+
+
+#[test]
+#[should_panic(expected = "more states than transformation points")]
+fn too_few_points() {
+  contains_ab().transition::<2>(0);
+}
+
+
+#[test]
+fn star_free_languages() {
+  assert!(contains_ab().is_star_free::<3>());
+  assert!(ab_star().is_star_free::<3>());
+  assert!(!even().is_star_free::<2>());
+}
+
+
+#[test]
+#[should_panic]
+fn state_out_of_range() {
+  Dfa::new(0, vec![true], vec![vec![1]]);
+}
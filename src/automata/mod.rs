@@ -0,0 +1,11 @@
+//!
+//! Finite _automata_ and their _transition_ _monoids_.
+//!
+//! A deterministic finite automaton (DFA) reading words over an
+//! alphabet acts on its states, with each word inducing a
+//! transformation of the states. These transformations form the
+//! automaton's _transition_ _monoid_, connecting regular languages to
+//! finite monoids.
+//!
+pub mod transition;
+pub mod dfa;
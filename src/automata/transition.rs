@@ -0,0 +1,130 @@
+//!
+//! _Transition_ _monoids_ of finite automata.
+//!
+//! The _transition_ _monoid_ of an automaton with states `Q` is the
+//! monoid of state transformations induced by words, i.e. the
+//! submonoid of all transformations of `Q` generated by the letter
+//! transformations. The identity is the transformation of the empty
+//! word.
+//!
+//! Transition monoids are submonoids of the full transformation
+//! monoids `T_N`, so their elements are `Transformation<N>` values.
+//! Transformations act on the _right_, so the product `s·t` applies
+//! `s` then `t`, and the transition of a word `uv` is the product of
+//! the transitions of `u` and `v`.
+//!
+//! A finite monoid is _aperiodic_ when every element `x` has some `n`
+//! with `x^n = x^(n+1)`, i.e. it contains no non-trivial groups. By
+//! _Schützenberger's_ _theorem_ a regular language is _star-free_
+//! exactly when its syntactic monoid is aperiodic.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a transition monoid.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use std::collections::VecDeque;
+use prelude::*;
+
+
+///
+/// The transition monoid generated by a set of letter transitions.
+///
+#[derive(Clone, Debug)]
+pub struct TransitionMonoid<const N: usize> {
+  elements: Vec<Transformation<N>>,
+  words: Vec<Vec<usize>>,
+}
+
+
+impl<const N: usize> TransitionMonoid<N> {
+
+  /// The transition monoid generated by letter transformations, with
+  /// letter `a` the transformation `letters[a]`.
+  pub fn new(letters: &[Transformation<N>]) -> Self {
+    let mut monoid = TransitionMonoid {
+      elements: vec![Transformation::id()],
+      words: vec![Vec::new()],
+    };
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    queue.push_back(0);
+
+    while let Some(i) = queue.pop_front() {
+      for (a, letter) in letters.iter().enumerate() {
+        let t = monoid.elements[i].op(letter);
+
+        if !monoid.contains(&t) {
+          let mut word = monoid.words[i].clone();
+          word.push(a);
+
+          monoid.elements.push(t);
+          monoid.words.push(word);
+          queue.push_back(monoid.elements.len() - 1);
+        }
+      }
+    }
+
+    monoid
+  }
+
+
+  /// The monoid elements, in shortlex order of their representative
+  /// words.
+  pub fn elements(&self) -> &[Transformation<N>] {
+    &self.elements
+  }
+
+
+  /// The number of elements.
+  pub fn len(&self) -> usize {
+    self.elements.len()
+  }
+
+
+  /// Test for an empty monoid (never true, as the identity is always
+  /// an element).
+  pub fn is_empty(&self) -> bool {
+    self.elements.is_empty()
+  }
+
+
+  /// Test a transformation is an element of the monoid.
+  pub fn contains(&self, t: &Transformation<N>) -> bool {
+    self.elements.contains(t)
+  }
+
+
+  /// The shortest (then least) word with a given transformation, if
+  /// the transformation is in the monoid.
+  pub fn word(&self, t: &Transformation<N>) -> Option<&[usize]> {
+    let i = self.elements.iter().position(|x| x == t)?;
+
+    Some(&self.words[i])
+  }
+
+
+  /// The idempotent elements.
+  pub fn idempotents(&self) -> Vec<Transformation<N>> {
+    self.elements.iter().filter(|t| t.is_idempotent()).cloned().collect()
+  }
+
+
+  /// Test every element is aperiodic, i.e. the monoid contains no
+  /// non-trivial groups.
+  pub fn is_aperiodic(&self) -> bool {
+    self.elements.iter().all(Transformation::is_aperiodic)
+  }
+
+
+  /// Green's relations of the monoid.
+  pub fn green(&self) -> Green<Transformation<N>> {
+    Green::of(self.elements.clone())
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "transition_test.rs"]
+mod transition_test;
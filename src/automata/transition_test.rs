@@ -0,0 +1,42 @@
+use prelude::*;
+
+
+#[test]
+fn full_transformation_monoid() {
+  let letters = [
+    Transformation::new([1, 2, 0]),
+    Transformation::new([1, 0, 2]),
+    Transformation::new([0, 0, 2]),
+  ];
+  let monoid = TransitionMonoid::new(&letters);
+
+  assert_eq!(monoid.len(), 27);
+  assert_eq!(monoid.idempotents().len(), 10);
+  assert!(!monoid.is_aperiodic());
+  assert_eq!(monoid.green().classes(GreenRelation::J).len(), 3);
+}
+
+
+#[test]
+fn representative_words() {
+  let letters = [Transformation::new([1, 2, 0])];
+  let monoid = TransitionMonoid::new(&letters);
+  let r = &letters[0];
+
+  assert_eq!(monoid.len(), 3);
+  assert_eq!(monoid.word(&Transformation::id()), Some(&[][..]));
+  assert_eq!(monoid.word(&r.op(r)), Some(&[0, 0][..]));
+  assert_eq!(monoid.word(&Transformation::constant(0)), None);
+}
+
+
+#[test]
+fn aperiodic_monoids() {
+  // The flip-flop monoid of two resets is aperiodic.
+  let resets = [Transformation::constant(0), Transformation::constant(1)];
+  let monoid = TransitionMonoid::<2>::new(&resets);
+
+  assert_eq!(monoid.len(), 3);
+  assert!(monoid.is_aperiodic());
+  assert!(monoid.green().is_band());
+}
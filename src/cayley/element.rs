@@ -0,0 +1,198 @@
+//!
+//! Algebraic trait implementations for _Cayley_ _table_ elements.
+//!
+//! The elements of a finite structure defined by a Cayley table are
+//! indexes into the table. The element type `Cayley<T, N>` forms a
+//! magma, and the structures of the table's kind (e.g. a semigroup,
+//! monoid and group for a group table), with the identity and
+//! inverses computed from the table `T` at compile time. Elements of
+//! quasigroup tables have divisions found from the table.
+//!
+//! Constructing elements checks the table defines its kind of
+//! structure, at compile time, so elements never violate the axioms
+//! of the structures they implement.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a Cayley table.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use prelude::*;
+
+
+///
+/// An element of a finite structure defined by a Cayley table `T`.
+///
+pub struct Cayley<T, const N: usize> {
+  index: usize,
+  table: PhantomData<T>,
+}
+
+
+impl<T: CayleyTable<N>, const N: usize> Cayley<T, N> {
+
+  /// The element with a given table index. Panics if the index is
+  /// out of range. Fails to compile if the table does not define its
+  /// kind of structure.
+  pub fn new(index: usize) -> Self {
+    let () = T::VALID;
+
+    assert!(index < N, "Cayley table index out of range");
+
+    Cayley { index, table: PhantomData }
+  }
+
+
+  /// The table index of the element.
+  pub fn index(&self) -> usize {
+    self.index
+  }
+}
+
+
+///
+/// Cayley table elements form a finite type.
+///
+impl<T: CayleyTable<N>, const N: usize> Finite for Cayley<T, N> {
+
+  /// The elements in table index order.
+  fn elements() -> impl Iterator<Item = Self> {
+    (0..N).map(Self::new)
+  }
+}
+
+
+// Trait implementations are written out to avoid derived bounds on
+// the table type `T`.
+impl<T, const N: usize> Clone for Cayley<T, N> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+
+impl<T, const N: usize> Copy for Cayley<T, N> {}
+
+
+impl<T, const N: usize> PartialEq for Cayley<T, N> {
+  fn eq(&self, other: &Self) -> bool {
+    self.index == other.index
+  }
+}
+
+
+impl<T, const N: usize> Eq for Cayley<T, N> {}
+
+
+impl<T, const N: usize> Hash for Cayley<T, N> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.index.hash(state);
+  }
+}
+
+
+impl<T, const N: usize> fmt::Debug for Cayley<T, N> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "Cayley({})", self.index)
+  }
+}
+
+
+///
+/// Cayley table elements form a magma.
+///
+impl<T: CayleyTable<N>, const N: usize> Magma for Cayley<T, N> {
+
+  /// The operation is a table lookup.
+  fn op(&self, other: &Self) -> Self {
+    Self::new(T::OP[self.index][other.index])
+  }
+}
+
+
+///
+/// Cayley table elements form a semigroup.
+///
+impl<T, const N: usize> Semigroup for Cayley<T, N>
+  where T: CayleyTable<N>, T::Kind: IsSemigroup {}
+
+
+///
+/// Cayley table elements form a monoid.
+///
+impl<T, const N: usize> Monoid for Cayley<T, N>
+  where T: CayleyTable<N>, T::Kind: IsMonoid {
+
+  /// The identity is found from the table.
+  fn id() -> Self {
+    Self::new(T::IDENTITY.expect("validated table has an identity"))
+  }
+}
+
+
+///
+/// Cayley table elements form a group.
+///
+impl<T, const N: usize> Group for Cayley<T, N>
+  where T: CayleyTable<N>, T::Kind: IsGroup {
+
+  /// The inverse is found from the table.
+  fn inverse(&self) -> Self {
+    let inv = T::INVERSES[self.index];
+
+    Self::new(inv.expect("validated table elements have inverses"))
+  }
+}
+
+
+///
+/// Commutative Cayley table elements form a commutative group.
+///
+impl<T, const N: usize> ComGroup for Cayley<T, N>
+  where T: CayleyTable<N>, T::Kind: IsComGroup {}
+
+
+///
+/// Cayley table elements form a quasigroup.
+///
+impl<T, const N: usize> Quasigroup for Cayley<T, N>
+  where T: CayleyTable<N>, T::Kind: IsQuasigroup {
+
+  /// Left division is found from the table row.
+  fn left_div(&self, other: &Self) -> Self {
+    let z = (0..N).find(|&z| T::OP[self.index][z] == other.index);
+
+    Self::new(z.expect("validated table rows are permutations"))
+  }
+
+
+  /// Right division is found from the table column.
+  fn right_div(&self, other: &Self) -> Self {
+    let z = (0..N).find(|&z| T::OP[z][other.index] == self.index);
+
+    Self::new(z.expect("validated table columns are permutations"))
+  }
+}
+
+
+///
+/// Cayley table elements form a loop.
+///
+impl<T, const N: usize> Loop for Cayley<T, N>
+  where T: CayleyTable<N>, T::Kind: IsLoop {
+
+  /// The identity is found from the table.
+  fn identity() -> Self {
+    Self::new(T::IDENTITY.expect("validated table has an identity"))
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "element_test.rs"]
+mod element_test;
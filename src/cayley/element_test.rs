@@ -0,0 +1,65 @@
+use prelude::*;
+use tests::prelude::*;
+
+
+/// Addition modulo 4.
+struct Z4;
+
+impl CayleyTable<4> for Z4 {
+  type Kind = ComGroups;
+
+  const OP: Table<4> = [[0, 1, 2, 3], [1, 2, 3, 0], [2, 3, 0, 1], [3, 0, 1, 2]];
+}
+
+
+type C4 = Cayley<Z4, 4>;
+
+
+#[test]
+fn group_axioms_z4() {
+  for x in C4::elements() {
+    assert_eq!(C4::id().op(&x), x);
+    assert_eq!(x.op(&C4::id()), x);
+    assert!(x.axiom_left_inverse());
+    assert!(x.axiom_right_inverse());
+
+    for y in C4::elements() {
+      assert!(ComGroup::axiom_commutivity((&x, &y)));
+
+      for z in C4::elements() {
+        assert!(Semigroup::axiom_associativity((&x, &y, &z)));
+      }
+    }
+  }
+}
+
+
+#[test]
+fn group_axioms_sym3() {
+  for x in S3::elements() {
+    assert!(x.axiom_left_inverse());
+    assert!(x.axiom_right_inverse());
+
+    for y in S3::elements() {
+      for z in S3::elements() {
+        assert!(Semigroup::axiom_associativity((&x, &y, &z)));
+      }
+    }
+  }
+}
+
+
+#[test]
+fn identity_and_inverse() {
+  assert_eq!(C4::id(), C4::new(0));
+  assert_eq!(C4::new(1).inverse(), C4::new(3));
+  assert_eq!(S3::new(3).inverse(), S3::new(4));
+  assert_ne!(S3::new(1).op(&S3::new(2)), S3::new(2).op(&S3::new(1)));
+}
+
+
+#[test]
+#[should_panic]
+fn out_of_range() {
+  C4::new(4);
+}
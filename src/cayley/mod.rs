@@ -0,0 +1,36 @@
+//!
+//! Finite algebraic structures defined by _Cayley_ _tables_.
+//!
+//! The `cayley` module lets finite magmas, monoids, groups and rings
+//! be defined by their operation tables, instead of by exhaustive
+//! `match` expressions over each pair of elements.
+//!
+//! A table is supplied by a (usually unit) type implementing the
+//! [`CayleyTable`] or [`RingTable`] traits, with elements indexed
+//! `0..N`. Identities and inverses are computed from the tables, and
+//! each table names the kind of structure it defines. Tables are
+//! validated against their kind at compile time, rejecting tables
+//! violating structure axioms such as closure or invertibility.
+//!
+//! [`CayleyTable`]: table/trait.CayleyTable.html
+//! [`RingTable`]: ring_table/trait.RingTable.html
+//!
+
+
+///
+/// The `?` operator for `const` functions returning `Result`s.
+///
+macro_rules! const_try {
+  ($result:expr) => {
+    if let Err(e) = $result {
+      return Err(e);
+    }
+  };
+}
+
+
+pub mod table;
+
+pub mod element;
+
+pub mod ring_table;
@@ -0,0 +1,428 @@
+//!
+//! Algebraic _ring_ _table_ traits and implementations.
+//!
+//! A finite _ring_ can be defined by a pair of Cayley tables, one for
+//! addition `+` and one for multiplication `*`, over the element
+//! indexes `0..N`. The ring zero, one and additive inverses are
+//! computed from the tables, as are multiplicative inverses for
+//! tables defining a _field_.
+//!
+//! The element type `CayleyRing<T, N>` forms a ring, and a
+//! commutative ring or field when the tables `T` are of that kind.
+//! Constructing elements checks the tables define their kind of ring,
+//! at compile time, so elements never violate the ring axioms.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a ring.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use prelude::*;
+use cayley::table::*;
+
+
+/// Check multiplication distributes over addition in (closed) tables.
+pub const fn distributivity<const N: usize>(add: &Table<N>, mul: &Table<N>)
+  -> Result<(), TableError> {
+  let mut x = 0;
+
+  while x < N {
+    let mut y = 0;
+
+    while y < N {
+      let mut z = 0;
+
+      while z < N {
+        let left = mul[x][add[y][z]] == add[mul[x][y]][mul[x][z]];
+        let right = mul[add[y][z]][x] == add[mul[y][x]][mul[z][x]];
+
+        if !(left && right) {
+          return Err(TableError::NotDistributive(x, y, z));
+        }
+        z += 1;
+      }
+      y += 1;
+    }
+    x += 1;
+  }
+
+  Ok(())
+}
+
+
+///
+/// The kinds of ring a pair of Cayley tables can define.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RingStructure {
+
+  /// A ring with one.
+  Ring,
+
+  /// A commutative ring.
+  ComRing,
+
+  /// A commutative ring where non-zero elements have inverses.
+  Field,
+}
+
+
+/// Check a pair of tables defines a given kind of ring.
+pub const fn validate_tables<const N: usize>(
+  add: &Table<N>,
+  mul: &Table<N>,
+  kind: RingStructure,
+) -> Result<(), TableError> {
+  match kind {
+    RingStructure::Ring => {
+      const_try!(validate(add, TableStructure::ComGroup));
+      const_try!(validate(mul, TableStructure::Monoid));
+
+      distributivity(add, mul)
+    },
+    RingStructure::ComRing => {
+      const_try!(validate_tables(add, mul, RingStructure::Ring));
+
+      commutativity(mul)
+    },
+    RingStructure::Field => {
+      const_try!(validate_tables(add, mul, RingStructure::ComRing));
+
+      match (identity(add), identity(mul)) {
+        (Some(zero), Some(one)) => {
+          let mut x = 0;
+
+          while x < N {
+            if x != zero && inverse(mul, one, x).is_none() {
+              return Err(TableError::NotInvertible(x));
+            }
+            x += 1;
+          }
+
+          Ok(())
+        },
+        _ => Err(TableError::NoIdentity),
+      }
+    },
+  }
+}
+
+
+///
+/// A type naming the kind of ring a pair of Cayley tables defines.
+///
+/// Ring table kinds are the unit types `Rings`, `ComRings` and
+/// `Fields`, and the structure traits a table's elements implement
+/// are selected by the `Is*` traits of its kind.
+///
+pub trait RingTableKind {
+
+  /// The kind of ring.
+  const STRUCTURE: RingStructure;
+}
+
+
+/// Ring table kinds whose elements form a commutative ring.
+pub trait IsComRing: RingTableKind {}
+
+
+/// Ring table kinds whose elements form a field.
+pub trait IsField: IsComRing {}
+
+
+/// Tables defining a ring.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Rings;
+
+impl RingTableKind for Rings {
+  const STRUCTURE: RingStructure = RingStructure::Ring;
+}
+
+
+/// Tables defining a commutative ring.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ComRings;
+
+impl RingTableKind for ComRings {
+  const STRUCTURE: RingStructure = RingStructure::ComRing;
+}
+
+impl IsComRing for ComRings {}
+
+
+/// Tables defining a field.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Fields;
+
+impl RingTableKind for Fields {
+  const STRUCTURE: RingStructure = RingStructure::Field;
+}
+
+impl IsComRing for Fields {}
+
+impl IsField for Fields {}
+
+
+///
+/// A pair of Cayley tables defining a finite ring.
+///
+/// Elements of the ring are the [`CayleyRing`] values indexed by
+/// `0..N`. Each pair of tables names the kind of ring it defines,
+/// and the tables are checked against their kind at _compile_ _time_
+/// when their elements are constructed.
+///
+/// [`CayleyRing`]: struct.CayleyRing.html
+///
+pub trait RingTable<const N: usize> {
+
+  /// The kind of ring the tables define.
+  type Kind: RingTableKind;
+
+
+  /// The addition table, where `ADD[x][y]` is the index of `x+y`.
+  const ADD: Table<N>;
+
+
+  /// The multiplication table, where `MUL[x][y]` is the index of
+  /// `x*y`.
+  const MUL: Table<N>;
+
+
+  /// The ring zero, if any.
+  const ZERO: Option<usize> = identity(&Self::ADD);
+
+
+  /// The ring one, if any.
+  const ONE: Option<usize> = identity(&Self::MUL);
+
+
+  /// The additive inverses of the elements, if any.
+  const NEGATIONS: [Option<usize>; N] = inverses(&Self::ADD);
+
+
+  /// The multiplicative inverses of the elements, if any.
+  const INVERSES: [Option<usize>; N] = inverses(&Self::MUL);
+
+
+  /// Compile time check that the tables define their kind of ring.
+  const VALID: () = assert!(
+    validate_tables(
+      &Self::ADD, &Self::MUL, <Self::Kind as RingTableKind>::STRUCTURE
+    ).is_ok(),
+    "ring tables do not define their kind of ring"
+  );
+
+
+  /// Validate the tables define a ring.
+  fn validate_ring() -> Result<(), TableError> {
+    validate_tables(&Self::ADD, &Self::MUL, RingStructure::Ring)
+  }
+
+
+  /// Validate the tables define a commutative ring.
+  fn validate_com_ring() -> Result<(), TableError> {
+    validate_tables(&Self::ADD, &Self::MUL, RingStructure::ComRing)
+  }
+
+
+  /// Validate the tables define a field.
+  fn validate_field() -> Result<(), TableError> {
+    validate_tables(&Self::ADD, &Self::MUL, RingStructure::Field)
+  }
+}
+
+
+///
+/// An element of a finite ring defined by ring tables `T`.
+///
+pub struct CayleyRing<T, const N: usize> {
+  index: usize,
+  table: PhantomData<T>,
+}
+
+
+impl<T: RingTable<N>, const N: usize> CayleyRing<T, N> {
+
+  /// The element with a given table index. Panics if the index is
+  /// out of range. Fails to compile if the tables do not define
+  /// their kind of ring.
+  pub fn new(index: usize) -> Self {
+    let () = T::VALID;
+
+    assert!(index < N, "ring table index out of range");
+
+    CayleyRing { index, table: PhantomData }
+  }
+
+
+  /// The table index of the element.
+  pub fn index(&self) -> usize {
+    self.index
+  }
+}
+
+
+///
+/// Ring table elements form a finite type.
+///
+impl<T: RingTable<N>, const N: usize> Finite for CayleyRing<T, N> {
+
+  /// The elements in table index order.
+  fn elements() -> impl Iterator<Item = Self> {
+    (0..N).map(Self::new)
+  }
+}
+
+
+// Trait implementations are written out to avoid derived bounds on
+// the table type `T`.
+impl<T, const N: usize> Clone for CayleyRing<T, N> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+
+impl<T, const N: usize> Copy for CayleyRing<T, N> {}
+
+
+impl<T, const N: usize> PartialEq for CayleyRing<T, N> {
+  fn eq(&self, other: &Self) -> bool {
+    self.index == other.index
+  }
+}
+
+
+impl<T, const N: usize> Eq for CayleyRing<T, N> {}
+
+
+impl<T, const N: usize> Hash for CayleyRing<T, N> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.index.hash(state);
+  }
+}
+
+
+impl<T, const N: usize> fmt::Debug for CayleyRing<T, N> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "CayleyRing({})", self.index)
+  }
+}
+
+
+///
+/// Ring table elements form an additive magma.
+///
+impl<T: RingTable<N>, const N: usize> AddMagma for CayleyRing<T, N> {
+
+  /// Addition is an addition table lookup.
+  fn add(&self, other: &Self) -> Self {
+    Self::new(T::ADD[self.index][other.index])
+  }
+}
+
+
+///
+/// Ring table elements form an additive semigroup.
+///
+impl<T: RingTable<N>, const N: usize> AddSemigroup for CayleyRing<T, N> {}
+
+
+///
+/// Ring table elements form an additive monoid.
+///
+impl<T: RingTable<N>, const N: usize> AddMonoid for CayleyRing<T, N> {
+
+  /// Zero is found from the addition table.
+  fn zero() -> Self {
+    Self::new(T::ZERO.expect("validated ring table has a zero"))
+  }
+}
+
+
+///
+/// Ring table elements form an additive group.
+///
+impl<T: RingTable<N>, const N: usize> AddGroup for CayleyRing<T, N> {
+
+  /// Negation is found from the addition table.
+  fn negate(&self) -> Self {
+    let neg = T::NEGATIONS[self.index];
+
+    Self::new(neg.expect("validated ring table elements have negations"))
+  }
+}
+
+
+///
+/// Ring table elements form an additive commutative group.
+///
+impl<T: RingTable<N>, const N: usize> AddComGroup for CayleyRing<T, N> {}
+
+
+///
+/// Ring table elements form a multiplicative magma.
+///
+impl<T: RingTable<N>, const N: usize> MulMagma for CayleyRing<T, N> {
+
+  /// Multiplication is a multiplication table lookup.
+  fn mul(&self, other: &Self) -> Self {
+    Self::new(T::MUL[self.index][other.index])
+  }
+}
+
+
+///
+/// Ring table elements form a multiplicative semigroup.
+///
+impl<T: RingTable<N>, const N: usize> MulSemigroup for CayleyRing<T, N> {}
+
+
+///
+/// Ring table elements form a multiplicative monoid.
+///
+impl<T: RingTable<N>, const N: usize> MulMonoid for CayleyRing<T, N> {
+
+  /// One is found from the multiplication table.
+  fn one() -> Self {
+    Self::new(T::ONE.expect("validated ring table has a one"))
+  }
+}
+
+
+///
+/// Ring table elements form a ring.
+///
+impl<T: RingTable<N>, const N: usize> Ring for CayleyRing<T, N> {}
+
+
+///
+/// Commutative ring table elements form a commutative ring.
+///
+impl<T, const N: usize> ComRing for CayleyRing<T, N>
+  where T: RingTable<N>, T::Kind: IsComRing {}
+
+
+///
+/// Field table elements form a field.
+///
+impl<T, const N: usize> Field for CayleyRing<T, N>
+  where T: RingTable<N>, T::Kind: IsField {
+
+  /// Inversion is found from the multiplication table.
+  fn invert(&self) -> Self {
+    let inv = T::INVERSES[self.index];
+
+    Self::new(inv.expect("zero has no inverse"))
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "ring_table_test.rs"]
+mod ring_table_test;
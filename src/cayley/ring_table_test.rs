@@ -0,0 +1,106 @@
+use prelude::*;
+
+
+/// The finite field with four elements `{0, 1, a, a+1}`.
+struct Gf4;
+
+impl RingTable<4> for Gf4 {
+  type Kind = Fields;
+
+  const ADD: Table<4> = [
+    [0, 1, 2, 3], [1, 0, 3, 2], [2, 3, 0, 1], [3, 2, 1, 0]
+  ];
+
+  const MUL: Table<4> = [
+    [0, 0, 0, 0], [0, 1, 2, 3], [0, 2, 3, 1], [0, 3, 1, 2]
+  ];
+}
+
+
+/// The integers modulo 4 (a commutative ring, not a field).
+struct Z4;
+
+impl RingTable<4> for Z4 {
+  type Kind = ComRings;
+
+  const ADD: Table<4> = [
+    [0, 1, 2, 3], [1, 2, 3, 0], [2, 3, 0, 1], [3, 0, 1, 2]
+  ];
+
+  const MUL: Table<4> = [
+    [0, 0, 0, 0], [0, 1, 2, 3], [0, 2, 0, 2], [0, 3, 2, 1]
+  ];
+}
+
+
+/// Tables where multiplication does not distribute over addition.
+struct Skew;
+
+impl RingTable<2> for Skew {
+  type Kind = Rings;
+
+  const ADD: Table<2> = [[0, 1], [1, 0]];
+  const MUL: Table<2> = [[1, 0], [0, 1]];
+}
+
+
+type F4 = CayleyRing<Gf4, 4>;
+type R4 = CayleyRing<Z4, 4>;
+
+
+#[test]
+fn validate_tables() {
+  assert_eq!(Gf4::validate_field(), Ok(()));
+  assert_eq!(Z4::validate_com_ring(), Ok(()));
+  assert_eq!(Z4::validate_field(), Err(TableError::NotInvertible(2)));
+  assert_eq!(Skew::validate_ring(), Err(TableError::NotDistributive(0, 0, 0)));
+}
+
+
+#[test]
+fn field_axioms_gf4() {
+  assert!(F4::axiom_identities());
+
+  for x in F4::elements() {
+    assert!(AddGroup::axiom_left_negate(&x));
+    assert!(AddMonoid::axiom_left_add_identity(&x));
+
+    if x.is_invertible() {
+      assert!(Field::axiom_left_invert(&x));
+      assert!(Field::axiom_right_invert(&x));
+    }
+
+    for y in F4::elements() {
+      assert!(ComRing::axiom_mul_commutivity((&x, &y)));
+      assert!(Field::prop_mul_zero((&x, &y)));
+
+      for z in F4::elements() {
+        assert!(Ring::axiom_left_distributivity((&x, &y, &z)));
+        assert!(MulSemigroup::axiom_mul_associativity((&x, &y, &z)));
+      }
+    }
+  }
+}
+
+
+#[test]
+fn ring_axioms_z4() {
+  for x in R4::elements() {
+    for y in R4::elements() {
+      for z in R4::elements() {
+        assert!(Ring::axiom_right_distributivity((&x, &y, &z)));
+        assert!(AddSemigroup::axiom_add_associativity((&x, &y, &z)));
+      }
+    }
+  }
+}
+
+
+#[test]
+fn identities_and_inverses() {
+  assert_eq!(F4::zero(), F4::new(0));
+  assert_eq!(F4::one(), F4::new(1));
+  assert_eq!(F4::new(2).invert(), F4::new(3));
+  assert_eq!(R4::new(1).negate(), R4::new(3));
+  assert_eq!(R4::new(2).mul(&R4::new(2)), R4::zero());
+}
@@ -0,0 +1,510 @@
+//!
+//! Algebraic _Cayley_ _table_ traits.
+//!
+//! A _Cayley_ _table_ for a finite set `S = {0, 1, ..., N-1}` lists
+//! the result of a binary operation `·` for each pair of elements,
+//! i.e. the table entry in row `x` and column `y` is `x·y`.
+//!
+//! A table defines a valid magma when it is _closed_, i.e. all
+//! entries are elements of `S`. Monoids and groups additionally
+//! require associativity, an identity element, and (for groups)
+//! inverses, all of which can be checked from the table alone. The
+//! checks are `const` functions, so tables are validated at compile
+//! time.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a Cayley table.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use std::fmt;
+use std::error::Error;
+
+
+///
+/// An `N` by `N` operation table over element indexes `0..N`.
+///
+pub type Table<const N: usize> = [[usize; N]; N];
+
+
+///
+/// The reasons a Cayley table can fail validation.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TableError {
+
+  /// The table entry for a pair of elements is out of range.
+  NotClosed(usize, usize),
+
+  /// The operation is not associative for a triple of elements.
+  NotAssociative(usize, usize, usize),
+
+  /// The operation is not commutative for a pair of elements.
+  NotCommutative(usize, usize),
+
+  /// The operation has no identity element.
+  NoIdentity,
+
+  /// An element has no inverse.
+  NotInvertible(usize),
+
+  /// Multiplication does not distribute over addition for a triple
+  /// of elements.
+  NotDistributive(usize, usize, usize),
+
+  /// A table row is not a permutation of the elements.
+  NotLatinRow(usize),
+
+  /// A table column is not a permutation of the elements.
+  NotLatinColumn(usize),
+}
+
+
+impl fmt::Display for TableError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      TableError::NotClosed(x, y) =>
+        write!(f, "table entry for ({}, {}) is out of range", x, y),
+      TableError::NotAssociative(x, y, z) =>
+        write!(f, "operation is not associative for ({}, {}, {})", x, y, z),
+      TableError::NotCommutative(x, y) =>
+        write!(f, "operation is not commutative for ({}, {})", x, y),
+      TableError::NoIdentity =>
+        write!(f, "operation has no identity element"),
+      TableError::NotInvertible(x) =>
+        write!(f, "element {} has no inverse", x),
+      TableError::NotDistributive(x, y, z) =>
+        write!(f, "operations are not distributive for ({}, {}, {})", x, y, z),
+      TableError::NotLatinRow(x) =>
+        write!(f, "row {} is not a permutation", x),
+      TableError::NotLatinColumn(x) =>
+        write!(f, "column {} is not a permutation", x),
+    }
+  }
+}
+
+
+impl Error for TableError {}
+
+
+/// Check a table is closed.
+pub const fn closure<const N: usize>(table: &Table<N>)
+  -> Result<(), TableError> {
+  let mut x = 0;
+
+  while x < N {
+    let mut y = 0;
+
+    while y < N {
+      if table[x][y] >= N {
+        return Err(TableError::NotClosed(x, y));
+      }
+      y += 1;
+    }
+    x += 1;
+  }
+
+  Ok(())
+}
+
+
+/// Check a (closed) table is associative.
+pub const fn associativity<const N: usize>(table: &Table<N>)
+  -> Result<(), TableError> {
+  let mut x = 0;
+
+  while x < N {
+    let mut y = 0;
+
+    while y < N {
+      let mut z = 0;
+
+      while z < N {
+        if table[table[x][y]][z] != table[x][table[y][z]] {
+          return Err(TableError::NotAssociative(x, y, z));
+        }
+        z += 1;
+      }
+      y += 1;
+    }
+    x += 1;
+  }
+
+  Ok(())
+}
+
+
+/// Check a (closed) table is commutative.
+pub const fn commutativity<const N: usize>(table: &Table<N>)
+  -> Result<(), TableError> {
+  let mut x = 0;
+
+  while x < N {
+    let mut y = 0;
+
+    while y < x {
+      if table[x][y] != table[y][x] {
+        return Err(TableError::NotCommutative(x, y));
+      }
+      y += 1;
+    }
+    x += 1;
+  }
+
+  Ok(())
+}
+
+
+/// Check a (closed) table is a Latin square, i.e. every row and
+/// column is a permutation of the elements.
+pub const fn latin_square<const N: usize>(table: &Table<N>)
+  -> Result<(), TableError> {
+  let mut x = 0;
+
+  while x < N {
+    if !is_permutation(table, x, true) {
+      return Err(TableError::NotLatinRow(x));
+    }
+    if !is_permutation(table, x, false) {
+      return Err(TableError::NotLatinColumn(x));
+    }
+    x += 1;
+  }
+
+  Ok(())
+}
+
+
+/// Test a (closed) table row or column is a permutation of the
+/// elements.
+const fn is_permutation<const N: usize>(table: &Table<N>, x: usize, row: bool)
+  -> bool {
+  let mut seen = [false; N];
+  let mut y = 0;
+
+  while y < N {
+    let z = if row { table[x][y] } else { table[y][x] };
+
+    if seen[z] {
+      return false;
+    }
+    seen[z] = true;
+    y += 1;
+  }
+
+  true
+}
+
+
+/// The (unique) identity element of a table, if any.
+pub const fn identity<const N: usize>(table: &Table<N>) -> Option<usize> {
+  let mut e = 0;
+
+  while e < N {
+    let mut x = 0;
+
+    while x < N && table[e][x] == x && table[x][e] == x {
+      x += 1;
+    }
+
+    if x == N {
+      return Some(e);
+    }
+    e += 1;
+  }
+
+  None
+}
+
+
+/// The (two-sided) inverse of an element of a table with identity
+/// `e`, if any.
+pub const fn inverse<const N: usize>(table: &Table<N>, e: usize, x: usize)
+  -> Option<usize> {
+  let mut y = 0;
+
+  while y < N {
+    if table[x][y] == e && table[y][x] == e {
+      return Some(y);
+    }
+    y += 1;
+  }
+
+  None
+}
+
+
+/// The inverses of all elements of a table, if the table has an
+/// identity.
+pub const fn inverses<const N: usize>(table: &Table<N>)
+  -> [Option<usize>; N] {
+  let mut inverses = [None; N];
+
+  if let Some(e) = identity(table) {
+    let mut x = 0;
+
+    while x < N {
+      inverses[x] = inverse(table, e, x);
+      x += 1;
+    }
+  }
+
+  inverses
+}
+
+
+/// Check every element of a table with identity `e` is invertible.
+pub const fn invertibility<const N: usize>(table: &Table<N>, e: usize)
+  -> Result<(), TableError> {
+  let mut x = 0;
+
+  while x < N {
+    if inverse(table, e, x).is_none() {
+      return Err(TableError::NotInvertible(x));
+    }
+    x += 1;
+  }
+
+  Ok(())
+}
+
+
+/// Check a table has an identity element.
+const fn has_identity<const N: usize>(table: &Table<N>)
+  -> Result<(), TableError> {
+  match identity(table) {
+    Some(_) => Ok(()),
+    None => Err(TableError::NoIdentity),
+  }
+}
+
+
+///
+/// The kinds of structure a Cayley table can define.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TableStructure {
+
+  /// A closed table.
+  Magma,
+
+  /// A closed Latin square.
+  Quasigroup,
+
+  /// A quasigroup with an identity element.
+  Loop,
+
+  /// A closed associative table.
+  Semigroup,
+
+  /// A semigroup with an identity element.
+  Monoid,
+
+  /// A monoid where every element has an inverse.
+  Group,
+
+  /// A commutative group.
+  ComGroup,
+}
+
+
+/// Check a table defines a given kind of structure.
+pub const fn validate<const N: usize>(table: &Table<N>, kind: TableStructure)
+  -> Result<(), TableError> {
+  match kind {
+    TableStructure::Magma => closure(table),
+    TableStructure::Quasigroup => {
+      const_try!(validate(table, TableStructure::Magma));
+
+      latin_square(table)
+    },
+    TableStructure::Loop => {
+      const_try!(validate(table, TableStructure::Quasigroup));
+
+      has_identity(table)
+    },
+    TableStructure::Semigroup => {
+      const_try!(validate(table, TableStructure::Magma));
+
+      associativity(table)
+    },
+    TableStructure::Monoid => {
+      const_try!(validate(table, TableStructure::Semigroup));
+
+      has_identity(table)
+    },
+    TableStructure::Group => {
+      const_try!(validate(table, TableStructure::Monoid));
+
+      match identity(table) {
+        Some(e) => invertibility(table, e),
+        None => Err(TableError::NoIdentity),
+      }
+    },
+    TableStructure::ComGroup => {
+      const_try!(validate(table, TableStructure::Group));
+
+      commutativity(table)
+    },
+  }
+}
+
+
+///
+/// A type naming the kind of structure a Cayley table defines.
+///
+/// Table kinds are the unit types `Magmas`, `Quasigroups`, `Loops`,
+/// `Semigroups`, `Monoids`, `Groups` and `ComGroups`, and the
+/// structure traits a table's elements implement are selected by the
+/// `Is*` traits of its kind.
+///
+pub trait TableKind {
+
+  /// The kind of structure.
+  const STRUCTURE: TableStructure;
+}
+
+
+/// Table kinds whose elements form a quasigroup.
+pub trait IsQuasigroup: TableKind {}
+
+
+/// Table kinds whose elements form a loop.
+pub trait IsLoop: IsQuasigroup {}
+
+
+/// Table kinds whose elements form a semigroup.
+pub trait IsSemigroup: TableKind {}
+
+
+/// Table kinds whose elements form a monoid.
+pub trait IsMonoid: IsSemigroup {}
+
+
+/// Table kinds whose elements form a group.
+pub trait IsGroup: IsMonoid + IsLoop {}
+
+
+/// Table kinds whose elements form a commutative group.
+pub trait IsComGroup: IsGroup {}
+
+
+///
+/// Trait implementation macro for table kinds.
+///
+/// A macro used to avoid writing repetitive, boilerplate unit type
+/// and `TableKind` implementations for each kind of table.
+///
+macro_rules! table_kind {
+  ($kind:ident, $structure:ident, $doc:expr; $($is:ident),*) => {
+    #[doc = $doc]
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub struct $kind;
+
+    impl TableKind for $kind {
+      const STRUCTURE: TableStructure = TableStructure::$structure;
+    }
+
+    $(impl $is for $kind {})*
+  };
+}
+
+table_kind!(Magmas, Magma, "Tables defining a magma.";);
+table_kind!(Quasigroups, Quasigroup, "Tables defining a quasigroup.";
+  IsQuasigroup);
+table_kind!(Loops, Loop, "Tables defining a loop.";
+  IsQuasigroup, IsLoop);
+table_kind!(Semigroups, Semigroup, "Tables defining a semigroup.";
+  IsSemigroup);
+table_kind!(Monoids, Monoid, "Tables defining a monoid.";
+  IsSemigroup, IsMonoid);
+table_kind!(Groups, Group, "Tables defining a group.";
+  IsQuasigroup, IsLoop, IsSemigroup, IsMonoid, IsGroup);
+table_kind!(ComGroups, ComGroup, "Tables defining a commutative group.";
+  IsQuasigroup, IsLoop, IsSemigroup, IsMonoid, IsGroup, IsComGroup);
+
+
+///
+/// A Cayley table defining a finite (multiplicative) structure.
+///
+/// Elements of the structure are the [`Cayley`] values indexed by
+/// `0..N`. Each table names the kind of structure it defines, and
+/// the table is checked against its kind at _compile_ _time_ when
+/// its elements are constructed, so invalid tables are rejected
+/// before they can be used.
+///
+/// [`Cayley`]: ../element/struct.Cayley.html
+///
+pub trait CayleyTable<const N: usize> {
+
+  /// The kind of structure the table defines.
+  type Kind: TableKind;
+
+
+  /// The operation table, where `OP[x][y]` is the index of `x·y`.
+  const OP: Table<N>;
+
+
+  /// The table identity element, if any.
+  const IDENTITY: Option<usize> = identity(&Self::OP);
+
+
+  /// The table inverses of the elements, if any.
+  const INVERSES: [Option<usize>; N] = inverses(&Self::OP);
+
+
+  /// Compile time check that the table defines its kind of structure.
+  const VALID: () = assert!(
+    validate(&Self::OP, <Self::Kind as TableKind>::STRUCTURE).is_ok(),
+    "Cayley table does not define its kind of structure"
+  );
+
+
+  /// Validate the table defines a magma.
+  fn validate_magma() -> Result<(), TableError> {
+    validate(&Self::OP, TableStructure::Magma)
+  }
+
+
+  /// Validate the table defines a quasigroup.
+  fn validate_quasigroup() -> Result<(), TableError> {
+    validate(&Self::OP, TableStructure::Quasigroup)
+  }
+
+
+  /// Validate the table defines a loop.
+  fn validate_loop() -> Result<(), TableError> {
+    validate(&Self::OP, TableStructure::Loop)
+  }
+
+
+  /// Validate the table defines a semigroup.
+  fn validate_semigroup() -> Result<(), TableError> {
+    validate(&Self::OP, TableStructure::Semigroup)
+  }
+
+
+  /// Validate the table defines a monoid.
+  fn validate_monoid() -> Result<(), TableError> {
+    validate(&Self::OP, TableStructure::Monoid)
+  }
+
+
+  /// Validate the table defines a group.
+  fn validate_group() -> Result<(), TableError> {
+    validate(&Self::OP, TableStructure::Group)
+  }
+
+
+  /// Validate the table defines a commutative group.
+  fn validate_com_group() -> Result<(), TableError> {
+    validate(&Self::OP, TableStructure::ComGroup)
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "table_test.rs"]
+mod table_test;
@@ -0,0 +1,148 @@
+use cayley::table::*;
+use tests::fixtures::Sym3;
+
+
+/// Addition modulo 3.
+struct Z3;
+
+impl CayleyTable<3> for Z3 {
+  type Kind = ComGroups;
+
+  const OP: Table<3> = [[0, 1, 2], [1, 2, 0], [2, 0, 1]];
+}
+
+
+/// Multiplication modulo 3 (a monoid, not a group).
+struct M3;
+
+impl CayleyTable<3> for M3 {
+  type Kind = Monoids;
+
+  const OP: Table<3> = [[0, 0, 0], [0, 1, 2], [0, 2, 1]];
+}
+
+
+/// Subtraction modulo 3 (a magma, not a semigroup).
+struct S3;
+
+impl CayleyTable<3> for S3 {
+  type Kind = Quasigroups;
+
+  const OP: Table<3> = [[0, 2, 1], [1, 0, 2], [2, 1, 0]];
+}
+
+
+/// Left projection (a semigroup without identity).
+struct L2;
+
+impl CayleyTable<2> for L2 {
+  type Kind = Semigroups;
+
+  const OP: Table<2> = [[0, 0], [1, 1]];
+}
+
+
+/// A table with an out of range entry.
+struct Open;
+
+impl CayleyTable<2> for Open {
+  type Kind = Magmas;
+
+  const OP: Table<2> = [[0, 1], [1, 2]];
+}
+
+
+/// The smallest non-associative loop.
+struct Loop5;
+
+impl CayleyTable<5> for Loop5 {
+  type Kind = Loops;
+
+  const OP: Table<5> = [
+    [0, 1, 2, 3, 4],
+    [1, 0, 3, 4, 2],
+    [2, 4, 0, 1, 3],
+    [3, 2, 4, 0, 1],
+    [4, 3, 1, 2, 0],
+  ];
+}
+
+
+#[test]
+fn validate_valid_tables() {
+  assert_eq!(Z3::validate_com_group(), Ok(()));
+  assert_eq!(M3::validate_monoid(), Ok(()));
+  assert_eq!(S3::validate_magma(), Ok(()));
+  assert_eq!(L2::validate_semigroup(), Ok(()));
+  assert_eq!(Sym3::validate_group(), Ok(()));
+  assert_eq!(S3::validate_quasigroup(), Ok(()));
+  assert_eq!(Loop5::validate_loop(), Ok(()));
+}
+
+
+#[test]
+fn validate_invalid_tables() {
+  use cayley::table::TableError::*;
+
+  assert_eq!(Open::validate_magma(), Err(NotClosed(1, 1)));
+  assert_eq!(S3::validate_semigroup(), Err(NotAssociative(0, 0, 1)));
+  assert_eq!(L2::validate_monoid(), Err(NoIdentity));
+  assert_eq!(M3::validate_group(), Err(NotInvertible(0)));
+  assert_eq!(Sym3::validate_com_group(), Err(NotCommutative(2, 1)));
+  assert_eq!(M3::validate_quasigroup(), Err(NotLatinRow(0)));
+  assert_eq!(S3::validate_loop(), Err(NoIdentity));
+  assert_eq!(Loop5::validate_semigroup(), Err(NotAssociative(1, 1, 2)));
+}
+
+
+#[test]
+fn latin_squares() {
+  let columns = [[0, 1], [0, 1]];
+
+  assert_eq!(latin_square(&Z3::OP), Ok(()));
+  assert_eq!(latin_square(&L2::OP), Err(TableError::NotLatinRow(0)));
+  assert_eq!(latin_square(&columns), Err(TableError::NotLatinColumn(0)));
+}
+
+
+#[test]
+fn identities_and_inverses() {
+  assert_eq!(identity(&Z3::OP), Some(0));
+  assert_eq!(identity(&M3::OP), Some(1));
+  assert_eq!(identity(&L2::OP), None);
+
+  assert_eq!(inverse(&Z3::OP, 0, 1), Some(2));
+  assert_eq!(inverse(&M3::OP, 1, 0), None);
+  assert_eq!(inverse(&Sym3::OP, 0, 3), Some(4));
+}
+
+
+#[test]
+fn cached_identities_and_inverses() {
+  let sym3 = [Some(0), Some(1), Some(2), Some(4), Some(3), Some(5)];
+
+  assert_eq!(Sym3::IDENTITY, Some(0));
+  assert_eq!(Sym3::INVERSES, sym3);
+  assert_eq!(L2::IDENTITY, None);
+  assert_eq!(M3::INVERSES, [None, Some(1), Some(2)]);
+}
+
+
+#[test]
+fn compile_time_validation() {
+  const GROUP: Result<(), TableError> =
+    validate(&Sym3::OP, TableStructure::Group);
+  const COM_GROUP: Result<(), TableError> =
+    validate(&Sym3::OP, TableStructure::ComGroup);
+
+  assert_eq!(GROUP, Ok(()));
+  assert_eq!(COM_GROUP, Err(TableError::NotCommutative(2, 1)));
+}
+
+
+#[test]
+fn error_messages() {
+  let error = TableError::NotInvertible(2);
+
+  assert_eq!(error.to_string(), "element 2 has no inverse");
+}
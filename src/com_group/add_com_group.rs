@@ -15,10 +15,12 @@
 //! See [references] for a formal definition of an additive
 //! commutative group.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use types::*;
 use group::add_group::*;
+use numeric::equal::*;
+use numeric::equivalence::*;
 
 
 ///
@@ -28,9 +30,16 @@ pub trait AddComGroup: AddGroup {
 
   /// Test the axiom of commutivity.
   fn axiom_add_commutivity(xs: Pair<Self>) -> bool {
+    Self::axiom_add_commutivity_by(xs, &Exact)
+  }
+
+
+  /// Test the axiom of commutivity up to an equivalence.
+  fn axiom_add_commutivity_by<E>(xs: Pair<Self>, eq: &E) -> bool
+    where E: Equivalence<Self> {
     let (x, y) = xs;
 
-    x.add(y) == y.add(x)
+    eq.equiv(&x.add(y), &y.add(x))
   }
 }
 
@@ -42,17 +51,24 @@ pub trait AddComGroup: AddGroup {
 /// commutative groups when "numeric" comparisons are used, e.g.
 /// floating point types.
 ///
-pub trait NumAddComGroup: NumAddGroup {
+pub trait NumAddComGroup: AddComGroup + NumAddGroup {
 
- /// Numerically test the axiom of commutivity.
- fn axiom_add_commutivity(xs: NumPair<Self>) -> bool {
-     let (x, y, eps) = xs;
+  /// Numerically test the axiom of commutivity.
+  fn axiom_add_commutivity(xs: NumPair<Self>) -> bool {
+    let (x, y, eps) = xs;
 
-     x.add(y).num_eq(&y.add(x), eps)
-   }
+    Self::axiom_add_commutivity_by((x, y), &Within(eps))
+  }
 }
 
 
+///
+/// Additive commutative groups with numeric equality form numeric
+/// additive commutative groups.
+///
+impl<T: AddComGroup + NumEq> NumAddComGroup for T {}
+
+
 ///
 /// Trait implementation macro for integer types.
 ///
@@ -79,17 +95,17 @@ integer_add_com_group! {
 
 
 ///
-/// IEEE 32 bit floating point types only form a _numeric_ additive
-/// commutative group.
+/// IEEE 32 bit floating point types form an additive commutative
+/// group.
 ///
-impl NumAddComGroup for f32 {}
+impl AddComGroup for f32 {}
 
 
 ///
-/// IEEE 64 bit floating point types only form a _numeric_ additive
-/// commutative group.
+/// IEEE 64 bit floating point types form an additive commutative
+/// group.
 ///
-impl NumAddComGroup for f64 {}
+impl AddComGroup for f64 {}
 
 
 // Module unit tests are in a separate file.
@@ -14,7 +14,7 @@
 //!
 //! See [references] for a formal definition of a commutative group.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use types::*;
 use group::group::*;
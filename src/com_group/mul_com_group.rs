@@ -15,10 +15,12 @@
 //! See [references] for a formal definition of a multiplicative
 //! commutative group.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use types::*;
 use group::mul_group::*;
+use numeric::equal::*;
+use numeric::equivalence::*;
 
 
 ///
@@ -28,9 +30,16 @@ pub trait MulComGroup: MulGroup {
 
   /// Test the axiom of commutivity.
   fn axiom_mul_commutivity(xs: Pair<Self>) -> bool {
+    Self::axiom_mul_commutivity_by(xs, &Exact)
+  }
+
+
+  /// Test the axiom of commutivity up to an equivalence.
+  fn axiom_mul_commutivity_by<E>(xs: Pair<Self>, eq: &E) -> bool
+    where E: Equivalence<Self> {
     let (x, y) = xs;
 
-    x.mul(y) == y.mul(x)
+    eq.equiv(&x.mul(y), &y.mul(x))
   }
 }
 
@@ -42,29 +51,36 @@ pub trait MulComGroup: MulGroup {
 /// commutative groups when "numeric" comparisons are used, e.g.
 /// floating point types.
 ///
-pub trait NumMulComGroup: NumMulGroup {
+pub trait NumMulComGroup: MulComGroup + NumMulGroup {
 
   /// Numerically test the axiom of commutivity.
   fn axiom_mul_commutivity(xs: NumPair<Self>) -> bool {
-     let (x, y, eps) = xs;
+    let (x, y, eps) = xs;
 
-     x.mul(y).num_eq(&y.mul(x), eps)
-   }
+    Self::axiom_mul_commutivity_by((x, y), &Within(eps))
+  }
 }
 
 
 ///
-/// IEEE 64 bit floating point types only form a _numeric_
-/// multiplicative commutative group.
+/// Multiplicative commutative groups with numeric equality form numeric
+/// multiplicative commutative groups.
+///
+impl<T: MulComGroup + NumEq> NumMulComGroup for T {}
+
+
+///
+/// IEEE 32 bit floating point types form a multiplicative
+/// commutative group.
 ///
-impl NumMulComGroup for f32 {}
+impl MulComGroup for f32 {}
 
 
 ///
-/// IEEE 64 bit floating point types only form a _numeric_
-/// multiplicative commutative group.
+/// IEEE 64 bit floating point types form a multiplicative
+/// commutative group.
 ///
-impl NumMulComGroup for f64 {}
+impl MulComGroup for f64 {}
 
 
 // Module unit tests are in a separate file.
@@ -13,10 +13,12 @@
 //!
 //! See [references] for a formal definition of a commutative ring.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use types::*;
 use ring::ring::*;
+use numeric::equal::*;
+use numeric::equivalence::*;
 
 
 ///
@@ -26,9 +28,16 @@ pub trait ComRing: Ring {
 
   /// Test the axiom of multiplicative commutivity.
   fn axiom_mul_commutivity(xs: Pair<Self>) -> bool {
+    Self::axiom_mul_commutivity_by(xs, &Exact)
+  }
+
+
+  /// Test the axiom of multiplicative commutivity up to an equivalence.
+  fn axiom_mul_commutivity_by<E>(xs: Pair<Self>, eq: &E) -> bool
+    where E: Equivalence<Self> {
     let (x, y) = xs;
 
-    x.mul(y) == y.mul(x)
+    eq.equiv(&x.mul(y), &y.mul(x))
   }
 }
 
@@ -39,17 +48,24 @@ pub trait ComRing: Ring {
 /// `NumComRing` trait is for types that only form commutative rings
 /// when "numeric" comparisons are used, e.g. floating point types.
 ///
-pub trait NumComRing: NumRing {
+pub trait NumComRing: ComRing + NumRing {
 
   /// Numerically test the axiom of commutivity.
   fn axiom_mul_commutivity(xs: NumPair<Self>) -> bool {
-     let (x, y, eps) = xs;
+    let (x, y, eps) = xs;
 
-     x.mul(y).num_eq(&y.mul(x), eps)
-   }
+    Self::axiom_mul_commutivity_by((x, y), &Within(eps))
+  }
 }
 
 
+///
+/// Commutative rings with numeric equality form numeric
+/// commutative rings.
+///
+impl<T: ComRing + NumEq> NumComRing for T {}
+
+
 ///
 /// Trait implementation macro for integer types.
 ///
@@ -76,17 +92,15 @@ integer_com_ring! {
 
 
 ///
-/// IEEE 32 bit floating point types only form a _numeric_ commutative
-/// ring.
+/// IEEE 32 bit floating point types form a commutative ring.
 ///
-impl NumComRing for f32 {}
+impl ComRing for f32 {}
 
 
 ///
-/// IEEE 64 bit floating point types only form a _numeric_ commutative
-/// ring.
+/// IEEE 64 bit floating point types form a commutative ring.
 ///
-impl NumComRing for f64 {}
+impl ComRing for f64 {}
 
 
 // Module unit tests are in a separate file.
@@ -1,12 +1,12 @@
-use ring::ring::NumRing;
-use monoid::mul_monoid::NumMulMonoid;
-use com_group::add_com_group::NumAddComGroup;
-use group::add_group::NumAddGroup;
-use monoid::add_monoid::NumAddMonoid;
-use semigroup::add_semigroup::NumAddSemigroup;
-use magma::add_magma::NumAddMagma;
-use semigroup::mul_semigroup::NumMulSemigroup;
-use magma::mul_magma::NumMulMagma;
+use ring::ring::{Ring, NumRing};
+use monoid::mul_monoid::MulMonoid;
+use com_group::add_com_group::AddComGroup;
+use group::add_group::AddGroup;
+use monoid::add_monoid::AddMonoid;
+use semigroup::add_semigroup::AddSemigroup;
+use magma::add_magma::AddMagma;
+use semigroup::mul_semigroup::MulSemigroup;
+use magma::mul_magma::MulMagma;
 
 use numeric::equal::NumEq;
 use std::cmp::PartialEq;
@@ -71,6 +71,11 @@ impl<T> CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
             pair: pair as _,
         }
     }
+
+    /// The pair of components.
+    pub fn pair(&self) -> &(T, T) {
+        &self.pair
+    }
 }
 
 impl<T> CayleyDicksonAlgebra for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
@@ -86,43 +91,43 @@ impl<T> Clone for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra + Clone {
     }
 }
 
-impl<T> NumRing for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
+impl<T> Ring for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
 }
 
-impl<T> NumMulMonoid for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
+impl<T> MulMonoid for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
     fn one() -> Self {
         CayleyDicksonPair::new((T::one(), T::zero()))
     }
 }
 
-impl<T> NumAddComGroup for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
+impl<T> AddComGroup for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
 }
 
-impl<T> NumAddGroup for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
+impl<T> AddGroup for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
     fn negate(&self) -> Self {
         Functor::fmap(&self, T::negate)
     }
 }
 
-impl<T> NumAddMonoid for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
+impl<T> AddMonoid for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
     fn zero() -> Self {
         CayleyDicksonPair::new((T::zero(), T::zero()))
     }
 }
 
-impl<T> NumAddSemigroup for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
+impl<T> AddSemigroup for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
 }
 
-impl<T> NumAddMagma for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
+impl<T> AddMagma for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
     fn add(&self, other: &Self) -> Self {
         BiFunctor::fmap(&self, &other, T::add)
     }
 }
 
-impl<T> NumMulSemigroup for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
+impl<T> MulSemigroup for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
 }
 
-impl<T> NumMulMagma for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
+impl<T> MulMagma for CayleyDicksonPair<T> where T: CayleyDicksonAlgebra {
     fn mul(&self, other: &Self) -> Self {
         let (ref a, ref b) = self.pair;
         let (ref c, ref d) = other.pair;
@@ -190,10 +195,10 @@ mod test {
     use super::CayleyDicksonPair;
     use super::LinearMaps;
 
-    use monoid::add_monoid::NumAddMonoid;
-    use monoid::mul_monoid::NumMulMonoid;
-    use magma::add_magma::NumAddMagma;
-    use magma::mul_magma::NumMulMagma;
+    use monoid::add_monoid::AddMonoid;
+    use monoid::mul_monoid::MulMonoid;
+    use magma::add_magma::AddMagma;
+    use magma::mul_magma::MulMagma;
     use numeric::equal::NumEq;
 
     #[test]
@@ -10,7 +10,7 @@
 //! library, the complex number type used here is from the very handy
 //! [num] crate.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use prelude::*;
 use std::ops::*;
@@ -46,10 +46,10 @@ impl<T: Float + NumField + NumEq> Real for T {}
 
 
 ///
-/// Complex numbers (with real components) form a numeric additive
+/// Complex numbers (with real components) form an additive
 /// magma.
 ///
-impl<T: Real> NumAddMagma for Complex<T> {
+impl<T: Real> AddMagma for Complex<T> {
 
   /// Addition is just complex addition.
   fn add(&self, other: &Self) -> Self {
@@ -59,17 +59,17 @@ impl<T: Real> NumAddMagma for Complex<T> {
 
 
 ///
-/// Complex numbers (with real components) form a numeric additive
+/// Complex numbers (with real components) form an additive
 /// semigroup.
 ///
-impl<T: Real> NumAddSemigroup for Complex<T> {}
+impl<T: Real> AddSemigroup for Complex<T> {}
 
 
 ///
-/// Complex numbers (with real components) form a numeric additive
+/// Complex numbers (with real components) form an additive
 /// monoid.
 ///
-impl<T: Real> NumAddMonoid for Complex<T> {
+impl<T: Real> AddMonoid for Complex<T> {
 
   /// Zero is just complex _0 + 0i_.
   fn zero() -> Self {
@@ -79,10 +79,10 @@ impl<T: Real> NumAddMonoid for Complex<T> {
 
 
 ///
-/// Complex numbers (with real components) form a numeric additive
+/// Complex numbers (with real components) form an additive
 /// group.
 ///
-impl<T: Real> NumAddGroup for Complex<T> {
+impl<T: Real> AddGroup for Complex<T> {
 
   /// Negation is just complex negation.
   fn negate(&self) -> Self {
@@ -92,17 +92,17 @@ impl<T: Real> NumAddGroup for Complex<T> {
 
 
 ///
-/// Complex numbers (with real components) form a numeric additive
+/// Complex numbers (with real components) form an additive
 /// commutative group.
 ///
-impl<T: Real> NumAddComGroup for Complex<T> {}
+impl<T: Real> AddComGroup for Complex<T> {}
 
 
 ///
-/// Complex numbers (with real components) form a numeric
+/// Complex numbers (with real components) form a
 /// multiplicative magma.
 ///
-impl<T: Real> NumMulMagma for Complex<T> {
+impl<T: Real> MulMagma for Complex<T> {
 
   /// Multiplication is just complex multiplication.
   fn mul(&self, other: &Self) -> Self {
@@ -112,17 +112,17 @@ impl<T: Real> NumMulMagma for Complex<T> {
 
 
 ///
-/// Complex numbers (with real components) form a numeric
+/// Complex numbers (with real components) form a
 /// multiplicative semigroup.
 ///
-impl<T: Real> NumMulSemigroup for Complex<T> {}
+impl<T: Real> MulSemigroup for Complex<T> {}
 
 
 ///
-/// Complex numbers (with real components) form a numeric
+/// Complex numbers (with real components) form a
 /// multiplicative monoid.
 ///
-impl<T: Real> NumMulMonoid for Complex<T> {
+impl<T: Real> MulMonoid for Complex<T> {
 
   /// One is just complex _1 + 0i_.
   fn one() -> Self {
@@ -133,9 +133,9 @@ impl<T: Real> NumMulMonoid for Complex<T> {
 
 ///
 /// Non-zero complex numbers (with real components) form a
-/// numeric multiplicative group.
+/// multiplicative group.
 ///
-impl<T: Real> NumMulGroup for Complex<T> {
+impl<T: Real> MulGroup for Complex<T> {
 
   /// Inversion is just complex inversion.
   fn invert(&self) -> Self {
@@ -152,29 +152,29 @@ impl<T: Real> NumMulGroup for Complex<T> {
 
 ///
 /// Non-zero complex numbers (with real components) form a
-/// numeric multiplicative commutative group.
+/// multiplicative commutative group.
 ///
-impl<T: Real> NumMulComGroup for Complex<T> {}
+impl<T: Real> MulComGroup for Complex<T> {}
 
 
 ///
-/// Complex numbers (with real components) form a numeric ring.
+/// Complex numbers (with real components) form a ring.
 ///
-impl<T: Real> NumRing for Complex<T> {}
+impl<T: Real> Ring for Complex<T> {}
 
 
 ///
-/// Complex numbers (with real components) form a numeric commutative
+/// Complex numbers (with real components) form a commutative
 /// ring.
 ///
-impl<T: Real> NumComRing for Complex<T> {}
+impl<T: Real> ComRing for Complex<T> {}
 
 
 ///
-/// Non-zero Complex numbers (with real components) form a numeric
+/// Non-zero Complex numbers (with real components) form a
 /// field.
 ///
-impl<T: Real> NumField for Complex<T> {
+impl<T: Real> Field for Complex<T> {
 
   /// Inversion is just complex inversion.
   fn invert(&self) -> Self {
@@ -10,7 +10,7 @@
 //! directory, but Rust's trait coherence restrictions make it much
 //! simpler to include it in the crate source.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 pub mod complex;
 pub mod cayley_dickson_process;
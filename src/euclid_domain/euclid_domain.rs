@@ -0,0 +1,326 @@
+//!
+//! Algebraic _Euclidean domain_ traits.
+//!
+//! An algebraic _Euclidean domain_ `S` is a _commutative_ _ring_
+//! with a _Euclidean function_ `δ: S\{0} → ℕ` (called _degree_
+//! here) and a _division with remainder_ operation that makes the
+//! remainder "smaller" than the divisor.
+//!
+//! Euclidean division gives every Euclidean domain _greatest common
+//! divisors_, computable with the (extended) _Euclidean algorithm_.
+//! This in turn means every _ideal_ in a Euclidean domain is a
+//! _principal_ ideal, generated by a single element.
+//!
+//! # Axioms
+//!
+//! 1. Ring: commutative ring axioms hold.
+//! 2. Division: ∀x, y ∈ S, y ≠ 0, ∃q, r ∈ S, x = q\*y + r.
+//! 3. Remainder: r = 0 or δ(r) < δ(y).
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a Euclidean domain.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use types::*;
+use com_ring::com_ring::*;
+use std::convert::TryFrom;
+
+
+///
+/// An algebraic _Euclidean domain_.
+///
+pub trait EuclidDomain: ComRing + Clone {
+
+  /// Euclidean division with remainder, returning the pair
+  /// `(quotient, remainder)`. Division is only defined for non-zero
+  /// divisors.
+  fn div_rem(&self, other: &Self) -> (Self, Self);
+
+
+  /// The Euclidean function (degree) of a non-zero element.
+  fn degree(&self) -> usize;
+
+
+  /// The canonical _associate_ of an element, e.g. the absolute value
+  /// of an integer or the monic multiple of a polynomial.
+  fn normal(&self) -> Self;
+
+
+  /// The Euclidean quotient of two elements.
+  fn quot(&self, other: &Self) -> Self {
+    self.div_rem(other).0
+  }
+
+
+  /// The Euclidean remainder of two elements.
+  fn rem(&self, other: &Self) -> Self {
+    self.div_rem(other).1
+  }
+
+
+  /// The Euclidean remainder of the sum of two elements. Remainders
+  /// are only defined for non-zero divisors.
+  fn add_rem(&self, other: &Self, divisor: &Self) -> Self {
+    self.add(other).rem(divisor)
+  }
+
+
+  /// The Euclidean remainder of the product of two elements.
+  /// Remainders are only defined for non-zero divisors.
+  fn mul_rem(&self, other: &Self, divisor: &Self) -> Self {
+    self.mul(other).rem(divisor)
+  }
+
+
+  /// Test if an element divides another. Zero only divides zero.
+  fn divides(&self, other: &Self) -> bool {
+    if self.is_zero() {
+      other.is_zero()
+    }
+    else {
+      other.rem(self).is_zero()
+    }
+  }
+
+
+  /// Test for a _unit_ (invertible) element.
+  fn is_unit(&self) -> bool {
+    self.divides(&Self::one())
+  }
+
+
+  /// The (normalized) greatest common divisor of two elements.
+  fn gcd(&self, other: &Self) -> Self {
+    self.ext_gcd(other).0
+  }
+
+
+  /// The (normalized) least common multiple of two elements.
+  fn lcm(&self, other: &Self) -> Self {
+    if self.is_zero() || other.is_zero() {
+      return Self::zero();
+    }
+
+    self.quot(&self.gcd(other)).mul(other).normal()
+  }
+
+
+  /// The extended Euclidean algorithm. Returns a triple `(g, s, t)`
+  /// where `g` is the normalized greatest common divisor and `s`,
+  /// `t` are _Bézout_ coefficients with `s*self + t*other = g`.
+  fn ext_gcd(&self, other: &Self) -> (Self, Self, Self) {
+    let (mut r0, mut r1) = (self.clone(), other.clone());
+    let (mut s0, mut s1) = (Self::one(), Self::zero());
+    let (mut t0, mut t1) = (Self::zero(), Self::one());
+
+    while !r1.is_zero() {
+      let (q, r2) = r0.div_rem(&r1);
+      let s2 = s0.sub(&q.mul(&s1));
+      let t2 = t0.sub(&q.mul(&t1));
+
+      r0 = r1; r1 = r2;
+      s0 = s1; s1 = s2;
+      t0 = t1; t1 = t2;
+    }
+
+    if r0.is_zero() {
+      return (r0, s0, t0);
+    }
+
+    // Scale the result by the unit taking the gcd to its normal form.
+    let unit = r0.normal().quot(&r0);
+
+    (r0.mul(&unit), s0.mul(&unit), t0.mul(&unit))
+  }
+
+
+  /// Test the axiom of division. Division by zero is undefined.
+  fn axiom_division(xs: Pair<Self>) -> bool {
+    let (x, y) = xs;
+
+    if y.is_zero() {
+      return true;
+    }
+
+    let (q, r) = x.div_rem(y);
+
+    q.mul(y).add(&r) == *x
+  }
+
+
+  /// Test the axiom of the remainder. Division by zero is undefined.
+  fn axiom_remainder(xs: Pair<Self>) -> bool {
+    let (x, y) = xs;
+
+    if y.is_zero() {
+      return true;
+    }
+
+    let r = x.rem(y);
+
+    r.is_zero() || r.degree() < y.degree()
+  }
+
+
+  /// Test the property of common divisors.
+  fn prop_gcd_divides(xs: Pair<Self>) -> bool {
+    let (x, y) = xs;
+    let g = x.gcd(y);
+
+    g.divides(x) && g.divides(y)
+  }
+
+
+  /// Test the property of Bézout coefficients.
+  fn prop_bezout(xs: Pair<Self>) -> bool {
+    let (x, y) = xs;
+    let (g, s, t) = x.ext_gcd(y);
+
+    s.mul(x).add(&t.mul(y)) == g
+  }
+}
+
+
+///
+/// Euclidean domain elements with a test for _irreducibility_.
+///
+/// An element is irreducible when it is neither zero nor a unit and
+/// it has no factors other than units and its own associates. In a
+/// Euclidean domain irreducible elements are also _prime_.
+///
+pub trait Irreducible: EuclidDomain {
+
+  /// Test for an irreducible element.
+  fn is_irreducible(&self) -> bool;
+}
+
+
+///
+/// The product of two remainders modulo `m`. Products that overflow
+/// `u128` (only possible for `i128` remainders) use "double and add"
+/// multiplication, reducing after each doubling and addition.
+///
+fn mul_mod(x: u128, y: u128, m: u128) -> u128 {
+  if let Some(p) = x.checked_mul(y) {
+    return p % m;
+  }
+
+  // Modular addition of remainders, without overflow.
+  let add = |a: u128, b: u128| {
+    if a >= m - b {
+      a - (m - b)
+    }
+    else {
+      a + b
+    }
+  };
+
+  (0..128).rev().fold(0, |r, bit| {
+    let r = add(r, r);
+
+    if (y >> bit) & 1 == 1 {
+      add(r, x)
+    }
+    else {
+      r
+    }
+  })
+}
+
+
+///
+/// Trait implementation macro for integer types.
+///
+/// A macro used to avoid writing repetitive, boilerplate
+/// `EuclidDomain` implementations for built-in signed integer types.
+/// Probably not needed if Rust had a signed `Integer` super-trait.
+///
+macro_rules! integer_euclid_domain {
+  ($type:ty) => {
+    impl EuclidDomain for $type {
+
+      /// Division uses "wrapping" Euclidean division, so remainders
+      /// are never negative.
+      fn div_rem(&self, other: &Self) -> (Self, Self) {
+        (self.wrapping_div_euclid(*other), self.wrapping_rem_euclid(*other))
+      }
+
+
+      /// Sums of remainders are widened, so they never overflow.
+      fn add_rem(&self, other: &Self, divisor: &Self) -> Self {
+        let m = divisor.unsigned_abs() as u128;
+        let x = self.wrapping_rem_euclid(*divisor) as u128;
+        let y = other.wrapping_rem_euclid(*divisor) as u128;
+
+        ((x + y) % m) as Self
+      }
+
+
+      /// Products of remainders are widened, so they never overflow.
+      fn mul_rem(&self, other: &Self, divisor: &Self) -> Self {
+        let m = divisor.unsigned_abs() as u128;
+        let x = self.wrapping_rem_euclid(*divisor) as u128;
+        let y = other.wrapping_rem_euclid(*divisor) as u128;
+
+        mul_mod(x, y, m) as Self
+      }
+
+
+      /// Degree is the (saturated) absolute value.
+      fn degree(&self) -> usize {
+        usize::try_from(self.unsigned_abs()).unwrap_or(usize::MAX)
+      }
+
+
+      /// The normal form is the absolute value.
+      fn normal(&self) -> Self {
+        self.wrapping_abs()
+      }
+    }
+
+
+    impl Irreducible for $type {
+
+      /// Integer irreducibility is primality, tested by trial
+      /// division.
+      fn is_irreducible(&self) -> bool {
+        let n = self.unsigned_abs();
+
+        if n < 2 {
+          return false;
+        }
+
+        let mut d = 2;
+
+        while d <= n / d {
+          if n % d == 0 {
+            return false;
+          }
+
+          d += 1;
+        }
+
+        true
+      }
+    }
+  };
+
+  ($type:ty, $($others:ty),+) => {
+    integer_euclid_domain! {$type}
+    integer_euclid_domain! {$($others),+}
+  };
+}
+
+
+// Only signed integers form a Euclidean domain.
+integer_euclid_domain! {
+  i8, i16, i32, i64, i128, isize
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "euclid_domain_test.rs"]
+mod euclid_domain_test;
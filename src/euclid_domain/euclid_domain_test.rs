@@ -0,0 +1,71 @@
+use tests::prelude::*;
+use euclid_domain::euclid_domain::*;
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_division_i32((ref x, ref y) in i32_2()) {
+    EuclidDomain::axiom_division((x, y))
+  }
+
+
+  #[test]
+  fn axiom_division_i64((ref x, ref y) in i64_2()) {
+    EuclidDomain::axiom_division((x, y))
+  }
+
+
+  #[test]
+  fn axiom_remainder_i16((ref x, ref y) in i16_2()) {
+    EuclidDomain::axiom_remainder((x, y))
+  }
+
+
+  #[test]
+  fn axiom_remainder_isize((ref x, ref y) in isize_2()) {
+    EuclidDomain::axiom_remainder((x, y))
+  }
+
+
+  #[test]
+  fn prop_gcd_divides_i32((ref x, ref y) in i32_2()) {
+    EuclidDomain::prop_gcd_divides((x, y))
+  }
+
+
+  #[test]
+  fn prop_bezout_i64((ref x, ref y) in i64_2()) {
+    EuclidDomain::prop_bezout((x, y))
+  }
+
+
+  #[test]
+  fn prop_add_rem_i64((x, y, z) in i64_3()) {
+    prop_assume!(z != 0);
+
+    let sum = (i128::from(x) + i128::from(y)).rem_euclid(z.into());
+
+    prop_assert_eq!(i128::from(x.add_rem(&y, &z)), sum);
+  }
+
+
+  #[test]
+  fn prop_mul_rem_i64((x, y, z) in i64_3()) {
+    prop_assume!(z != 0);
+
+    let product = (i128::from(x) * i128::from(y)).rem_euclid(z.into());
+
+    prop_assert_eq!(i128::from(x.mul_rem(&y, &z)), product);
+  }
+
+
+  #[test]
+  fn prop_irreducible_factors(ref x in 2..10_000i32) {
+    let factors = (2..*x).filter(|d| d.divides(x)).count();
+
+    prop_assert_eq!(x.is_irreducible(), factors == 0);
+  }
+}
@@ -0,0 +1,8 @@
+//!
+//! Algebraic _Euclidean domain_ traits and implementations.
+//!
+//! The `euclid_domain` module provides support for commutative rings
+//! with a _Euclidean division_ operation, including greatest common
+//! divisors and the extended Euclidean algorithm.
+//!
+pub mod euclid_domain;
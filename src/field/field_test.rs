@@ -39,3 +39,12 @@ proptest! {
     NumField::prop_mul_cancel((x, y, z, &F32_EPS))
   }
 }
+
+
+#[test]
+fn exact_float_axioms() {
+  let x = 49.0f64;
+
+  assert!(!Field::axiom_left_invert(&x), "49·(1/49) rounds below 1");
+  assert!(x.axiom_left_invert_by(&Within(&F64_EPS)));
+}
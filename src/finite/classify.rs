@@ -0,0 +1,321 @@
+//!
+//! Structure classification for finite magmas.
+//!
+//! A finite type with a binary operation `·` forms a _magma_. By
+//! checking structure axioms exhaustively, the strongest of the
+//! magma structures `·` forms can be determined, e.g. a type may form
+//! a semigroup but not a monoid. Classifying a finite magma reports
+//! that strongest structure, along with the violated axioms (and
+//! counterexamples) of the structure(s) at the next level up.
+//!
+//! Classification runs the structure traits' own axiom predicates
+//! (e.g. `Semigroup::axiom_associativity`) with the exhaustive
+//! checkers, on a wrapper type whose identity, inverses and divisions
+//! are found by searching the magma's elements. Wrapper types such as
+//! `Sum<T>` select the operation of types with more than one.
+//!
+//! The classified structures are ordered from weakest to strongest:
+//!
+//! 1. Magma: no axioms beyond closure.
+//! 2. Quasigroup: ∀a, b ∈ S, a·x = b and y·a = b have unique solutions.
+//! 3. Loop: quasigroup, and ∃e ∈ S, ∀x ∈ S, e·x = x·e = x.
+//! 4. Semigroup: ∀x, y, z ∈ S, (x·y)·z = x·(y·z).
+//! 5. Monoid: ∃e ∈ S, ∀x ∈ S, e·x = x·e = x.
+//! 6. Group: ∀x ∈ S, ∃x^-1 ∈ S, x·x^-1 = x^-1·x = e.
+//! 7. Commutative group: ∀x, y ∈ S, x·y = y·x.
+//!
+//! Quasigroups and semigroups are _separate_ refinements of a magma,
+//! so a magma's next levels up are both structures. An associative
+//! quasigroup is a group (for non-empty types), so quasigroups and
+//! loops are classified with their associativity violation, and
+//! quasigroups with their identity violation.
+//!
+//! # References
+//!
+//! See [references] for formal definitions of these structures.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use std::fmt;
+use prelude::*;
+
+
+///
+/// The magma structures of a classification.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Structure {
+
+  /// A magma (closure only).
+  Magma,
+
+  /// A quasigroup (division is always possible).
+  Quasigroup,
+
+  /// A loop (quasigroup with identity).
+  Loop,
+
+  /// A semigroup (associativity).
+  Semigroup,
+
+  /// A monoid (associativity and identity).
+  Monoid,
+
+  /// A group (associativity, identity and inverses).
+  Group,
+
+  /// A commutative group (group and commutativity).
+  ComGroup,
+}
+
+
+impl fmt::Display for Structure {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let name = match self {
+      Structure::Magma => "magma",
+      Structure::Quasigroup => "quasigroup",
+      Structure::Loop => "loop",
+      Structure::Semigroup => "semigroup",
+      Structure::Monoid => "monoid",
+      Structure::Group => "group",
+      Structure::ComGroup => "commutative group",
+    };
+
+    write!(f, "{}", name)
+  }
+}
+
+
+///
+/// A violated structure axiom, with a counterexample.
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Violation<T> {
+
+  /// The operation is not associative for a triple of elements.
+  Associativity(T, T, T),
+
+  /// The left or right division of a pair of elements `(a, b)`,
+  /// i.e. the solution of `a·x = b` or `y·a = b`, is not unique.
+  Division(T, T),
+
+  /// The operation has no identity element.
+  Identity,
+
+  /// An element has no inverse.
+  Inverse(T),
+
+  /// The operation is not commutative for a pair of elements.
+  Commutativity(T, T),
+}
+
+
+impl<T: fmt::Debug> fmt::Display for Violation<T> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Violation::Associativity(x, y, z) =>
+        write!(f, "associativity fails for ({:?}, {:?}, {:?})", x, y, z),
+      Violation::Division(a, b) =>
+        write!(f, "division has no unique solution for ({:?}, {:?})", a, b),
+      Violation::Identity =>
+        write!(f, "no identity element"),
+      Violation::Inverse(x) =>
+        write!(f, "no inverse for {:?}", x),
+      Violation::Commutativity(x, y) =>
+        write!(f, "commutativity fails for ({:?}, {:?})", x, y),
+    }
+  }
+}
+
+
+///
+/// The classification of a finite magma.
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Classification<T> {
+  structure: Structure,
+  violations: Vec<Violation<T>>,
+}
+
+
+impl<T> Classification<T> {
+
+  /// The strongest structure the magma forms.
+  pub fn structure(&self) -> Structure {
+    self.structure
+  }
+
+
+  /// The violated axioms of the structure(s) at the next level up.
+  pub fn violations(&self) -> &[Violation<T>] {
+    &self.violations
+  }
+}
+
+
+impl<T: fmt::Debug> fmt::Display for Classification<T> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.structure)?;
+
+    for violation in &self.violations {
+      write!(f, "; {}", violation)?;
+    }
+
+    Ok(())
+  }
+}
+
+
+///
+/// A magma element, with the monoid identity, group inverses and
+/// quasigroup divisions found by searching the magma's elements. The
+/// search falls back to the element itself when no identity, inverse
+/// or division exists, which then fails the relevant axiom.
+///
+#[derive(Clone, PartialEq, Debug)]
+struct Probe<T>(T);
+
+
+impl<T: Magma + Finite> Probe<T> {
+
+  /// The magma identity element, if any.
+  fn identity() -> Option<Self> {
+    T::elements().map(Probe).find(|e| {
+      exhaustive_1(|x: &Self| e.op(x) == *x && x.op(e) == *x).is_ok()
+    })
+  }
+}
+
+
+impl<T: Magma + Finite> Finite for Probe<T> {
+
+  /// The magma elements.
+  fn elements() -> impl Iterator<Item = Self> {
+    T::elements().map(Probe)
+  }
+}
+
+
+impl<T: Magma> Magma for Probe<T> {
+
+  /// The operation is the magma operation.
+  fn op(&self, other: &Self) -> Self {
+    Probe(self.0.op(&other.0))
+  }
+}
+
+
+impl<T: Magma + Clone> Semigroup for Probe<T> {}
+
+
+impl<T: Magma + Finite + Clone> Monoid for Probe<T> {
+
+  /// The identity is found by search. Classification only uses it
+  /// when an identity exists.
+  fn id() -> Self {
+    Self::identity().expect("magma has no identity")
+  }
+}
+
+
+impl<T: Magma + Finite + Clone> Group for Probe<T> {
+
+  /// The inverse is found by search.
+  fn inverse(&self) -> Self {
+    let e = Self::id();
+
+    Self::elements()
+      .find(|y| self.op(y) == e && y.op(self) == e)
+      .unwrap_or_else(|| self.clone())
+  }
+}
+
+
+impl<T: Magma + Finite + Clone> ComGroup for Probe<T> {}
+
+
+impl<T: Magma + Finite + Clone> Quasigroup for Probe<T> {
+
+  /// Left division is found by search.
+  fn left_div(&self, other: &Self) -> Self {
+    Self::elements()
+      .find(|z| self.op(z) == *other)
+      .unwrap_or_else(|| self.clone())
+  }
+
+
+  /// Right division is found by search.
+  fn right_div(&self, other: &Self) -> Self {
+    Self::elements()
+      .find(|z| z.op(other) == *self)
+      .unwrap_or_else(|| self.clone())
+  }
+}
+
+
+impl<T: Magma + Finite + Clone> Loop for Probe<T> {
+
+  /// The identity is the monoid identity.
+  fn identity() -> Self {
+    Self::id()
+  }
+}
+
+
+/// Classify the magma formed by a finite type and its operation.
+/// Wrapper types (e.g. `Sum<T>` or `Product<T>`) classify other
+/// operations.
+pub fn classify<T>() -> Classification<T>
+  where T: Magma + Finite + Clone {
+  let associativity = exhaustive_3::<Probe<T>, _>(
+    Semigroup::axiom_associativity
+  );
+
+  let associativity = match associativity {
+    Err((x, y, z)) => Some(Violation::Associativity(x.0, y.0, z.0)),
+    Ok(()) => None,
+  };
+
+  let (structure, violations) = if let Some(violation) = associativity {
+    let division = exhaustive_2(|xs: Pair<Probe<T>>| {
+      Quasigroup::axiom_left_division(xs)
+        && Quasigroup::axiom_right_division(xs)
+    });
+
+    match division {
+      Ok(()) if Probe::<T>::identity().is_some() => {
+        (Structure::Loop, vec![violation])
+      },
+      Ok(()) => (Structure::Quasigroup, vec![violation, Violation::Identity]),
+      Err((a, b)) => {
+        (Structure::Magma, vec![violation, Violation::Division(a.0, b.0)])
+      }
+    }
+  }
+  else if Probe::<T>::identity().is_none() {
+    (Structure::Semigroup, vec![Violation::Identity])
+  }
+  else {
+    let inverses = exhaustive_1(|x: &Probe<T>| {
+      Group::axiom_left_inverse(x) && Group::axiom_right_inverse(x)
+    });
+
+    match inverses {
+      Err(x) => (Structure::Monoid, vec![Violation::Inverse(x.0)]),
+      Ok(()) => match exhaustive_2::<Probe<T>, _>(ComGroup::axiom_commutivity) {
+        Ok(()) => (Structure::ComGroup, Vec::new()),
+        Err((x, y)) => {
+          (Structure::Group, vec![Violation::Commutativity(x.0, y.0)])
+        }
+      },
+    }
+  };
+
+  Classification { structure, violations }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "classify_test.rs"]
+mod classify_test;
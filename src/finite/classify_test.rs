@@ -0,0 +1,105 @@
+use prelude::*;
+use tests::prelude::*;
+
+
+/// The left zero band `{a, b}` with `x·y = x`.
+struct LeftZero;
+
+impl CayleyTable<2> for LeftZero {
+  type Kind = Semigroups;
+
+  const OP: Table<2> = [[0, 0], [1, 1]];
+}
+
+
+/// Boolean _nand_ on `{false, true}`.
+struct Nand;
+
+impl CayleyTable<2> for Nand {
+  type Kind = Magmas;
+
+  const OP: Table<2> = [[1, 1], [1, 0]];
+}
+
+
+#[test]
+fn classify_com_groups() {
+  let add = classify::<Sum<u8>>();
+
+  assert_eq!(add.structure(), Structure::ComGroup);
+  assert_eq!(add.violations(), &[]);
+  let xor = classify::<Cyclic<2>>();
+
+  assert_eq!(xor.structure(), Structure::ComGroup);
+}
+
+
+#[test]
+fn classify_groups() {
+  let sym = classify::<S3>();
+
+  assert_eq!(sym.structure(), Structure::Group);
+  assert_eq!(sym.violations(), &[
+    Violation::Commutativity(Cayley::new(1), Cayley::new(2)),
+  ]);
+}
+
+
+#[test]
+fn classify_monoids() {
+  let mul = classify::<Product<u8>>();
+
+  assert_eq!(mul.structure(), Structure::Monoid);
+  assert_eq!(mul.violations(), &[Violation::Inverse(Product(0))]);
+  let and = classify::<Min<bool>>();
+
+  assert_eq!(and.violations(), &[Violation::Inverse(Min(false))]);
+}
+
+
+#[test]
+fn classify_semigroups() {
+  let left = classify::<Cayley<LeftZero, 2>>();
+
+  assert_eq!(left.structure(), Structure::Semigroup);
+  assert_eq!(left.violations(), &[Violation::Identity]);
+}
+
+
+#[test]
+fn classify_quasigroups() {
+  let sub = classify::<Subtraction<i8>>();
+  let (x, z) = (Subtraction(-128), Subtraction(-127));
+
+  assert_eq!(sub.structure(), Structure::Quasigroup);
+  assert_eq!(sub.violations(), &[
+    Violation::Associativity(x, x, z),
+    Violation::Identity,
+  ]);
+}
+
+
+#[test]
+fn classify_magmas() {
+  let nand = classify::<Cayley<Nand, 2>>();
+  let (f, t) = (Cayley::new(0), Cayley::new(1));
+
+  assert_eq!(nand.structure(), Structure::Magma);
+  assert_eq!(nand.violations(), &[
+    Violation::Associativity(f, f, t),
+    Violation::Division(f, f),
+  ]);
+}
+
+
+#[test]
+fn classification_reports() {
+  let and = classify::<Min<bool>>();
+  let nand = classify::<Cayley<Nand, 2>>();
+
+  assert_eq!(and.to_string(), "monoid; no inverse for Min(false)");
+  assert_eq!(nand.to_string(),
+             "magma; associativity fails for \
+              (Cayley(0), Cayley(0), Cayley(1)); \
+              division has no unique solution for (Cayley(0), Cayley(0))");
+}
@@ -0,0 +1,65 @@
+//!
+//! Exhaustive checkers for axioms and properties.
+//!
+//! The exhaustive checkers evaluate a structure axiom or property
+//! predicate for _every_ value, `Pair` or `Triple` of values of a
+//! finite type, returning the first failing input (in element order)
+//! as a counterexample.
+//!
+use types::*;
+use finite::finite::*;
+
+
+/// Check a predicate holds for all values of a finite type,
+/// returning the first failing value.
+pub fn exhaustive_1<T, P>(predicate: P) -> Result<(), T>
+  where T: Finite, P: Fn(&T) -> bool {
+  match T::elements().find(|x| !predicate(x)) {
+    Some(x) => Err(x),
+    None => Ok(()),
+  }
+}
+
+
+/// Check a predicate holds for all pairs of values of a finite type,
+/// returning the first failing pair.
+pub fn exhaustive_2<T, P>(predicate: P) -> Result<(), (T, T)>
+  where T: Finite + Clone, P: Fn(Pair<T>) -> bool {
+  let xs: Vec<T> = T::elements().collect();
+
+  for x in &xs {
+    for y in &xs {
+      if !predicate((x, y)) {
+        return Err((x.clone(), y.clone()));
+      }
+    }
+  }
+
+  Ok(())
+}
+
+
+/// Check a predicate holds for all triples of values of a finite
+/// type, returning the first failing triple.
+pub fn exhaustive_3<T, P>(predicate: P) -> Result<(), (T, T, T)>
+  where T: Finite + Clone, P: Fn(Triple<T>) -> bool {
+  let xs: Vec<T> = T::elements().collect();
+
+  for x in &xs {
+    for y in &xs {
+      for z in &xs {
+        if !predicate((x, y, z)) {
+          return Err((x.clone(), y.clone(), z.clone()));
+        }
+      }
+    }
+  }
+
+  Ok(())
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "exhaustive_test.rs"]
+mod exhaustive_test;
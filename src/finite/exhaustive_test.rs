@@ -0,0 +1,37 @@
+use prelude::*;
+
+
+#[test]
+fn exhaustive_i8_group() {
+  let identity = exhaustive_1::<i8, _>(AddMonoid::axiom_left_add_identity);
+  let negate = exhaustive_1::<i8, _>(AddGroup::axiom_left_negate);
+  let commutivity = exhaustive_2::<i8, _>(AddComGroup::axiom_add_commutivity);
+
+  assert_eq!(identity, Ok(()));
+  assert_eq!(negate, Ok(()));
+  assert_eq!(commutivity, Ok(()));
+}
+
+
+#[test]
+fn exhaustive_u8_monoid() {
+  let left = exhaustive_1::<u8, _>(MulMonoid::axiom_left_mul_identity);
+  let right = exhaustive_1::<u8, _>(MulMonoid::axiom_right_mul_identity);
+  let associativity = exhaustive_3(MulSemigroup::axiom_mul_associativity);
+
+  assert_eq!(left, Ok(()));
+  assert_eq!(right, Ok(()));
+  assert_eq!(associativity, Ok::<(), (u8, u8, u8)>(()));
+}
+
+
+#[test]
+fn exhaustive_counterexamples() {
+  let odd = exhaustive_1(|x: &u8| x % 2 == 1);
+  let ordered = exhaustive_2(|(x, y): Pair<i8>| x <= y);
+  let distinct = exhaustive_3(|(x, y, z): Triple<bool>| x != y || y != z);
+
+  assert_eq!(odd, Err(0));
+  assert_eq!(ordered, Err((-127, -128)));
+  assert_eq!(distinct, Err((false, false, false)));
+}
@@ -0,0 +1,88 @@
+//!
+//! The _finite_ type trait.
+//!
+//! A _finite_ type has a finite set of values (its _carrier_) that can
+//! be enumerated, e.g. `bool`, `u8` or the elements of a Cayley table
+//! structure.
+//!
+//! Finite types with small carriers can have their structure axioms
+//! checked exhaustively. Note the number of values in a type's
+//! carrier grows quickly with its size: checking a `Triple` axiom for
+//! `u8` values evaluates 2^24 triples, and for `u16` values 2^48.
+//!
+
+///
+/// A type with a finite, enumerable set of values.
+///
+pub trait Finite: Sized {
+
+  /// An iterator over all values of the type, without duplicates.
+  fn elements() -> impl Iterator<Item = Self>;
+
+
+  /// The number of values of the type.
+  fn size() -> usize {
+    Self::elements().count()
+  }
+}
+
+
+///
+/// Boolean values form a finite type.
+///
+impl Finite for bool {
+
+  /// The boolean values are `false` and `true`.
+  fn elements() -> impl Iterator<Item = Self> {
+    IntoIterator::into_iter([false, true])
+  }
+}
+
+
+///
+/// The unit type forms a finite type.
+///
+impl Finite for () {
+
+  /// The only unit value is `()`.
+  fn elements() -> impl Iterator<Item = Self> {
+    ::std::iter::once(())
+  }
+}
+
+
+///
+/// Trait implementation macro for small integer types.
+///
+/// A macro used to avoid writing repetitive, boilerplate `Finite`
+/// implementations for built-in integer types.
+///
+macro_rules! integer_finite {
+  ($type:ty) => {
+
+    impl Finite for $type {
+
+      /// The integer values from minimum to maximum.
+      fn elements() -> impl Iterator<Item = Self> {
+        <$type>::MIN..=<$type>::MAX
+      }
+    }
+  };
+
+  ($type:ty, $($others:ty),+) => {
+    integer_finite! {$type}
+    integer_finite! {$($others),+}
+  };
+}
+
+
+// Only integer types with 16 or fewer bits are (realistically) finite.
+integer_finite! {
+  u8, u16, i8, i16
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "finite_test.rs"]
+mod finite_test;
@@ -0,0 +1,19 @@
+use prelude::*;
+
+
+#[test]
+fn carrier_sizes() {
+  assert_eq!(<()>::size(), 1);
+  assert_eq!(bool::size(), 2);
+  assert_eq!(u8::size(), 256);
+  assert_eq!(i8::size(), 256);
+  assert_eq!(u16::size(), 65_536);
+}
+
+
+#[test]
+fn carrier_elements() {
+  assert_eq!(bool::elements().collect::<Vec<_>>(), vec![false, true]);
+  assert_eq!(i8::elements().next(), Some(-128));
+  assert_eq!(i16::elements().last(), Some(32_767));
+}
@@ -0,0 +1,20 @@
+//!
+//! Exhaustive axiom verification for _finite_ structures.
+//!
+//! Generative (random) tests only _sample_ the values of a
+//! structure, so they can both repeat values and miss counterexamples.
+//! For small, finite structures every value (and every pair or triple
+//! of values) can be checked, _proving_ the axioms hold rather than
+//! sampling them.
+//!
+//! The `finite` module provides a `Finite` trait for types with an
+//! enumerable set of values, and exhaustive checkers that evaluate
+//! axiom or property predicates over all of those values. Finite
+//! magmas can also be _classified_ by the strongest structure they
+//! form.
+//!
+pub mod finite;
+
+pub mod exhaustive;
+
+pub mod classify;
@@ -0,0 +1,139 @@
+//!
+//! The _cyclic_ groups.
+//!
+//! The cyclic group `C_N` of order `N` is generated by a single
+//! element `g`, with elements `g^0 = e, g^1, ..., g^(N-1)`. Powers of
+//! `g` multiply by adding exponents modulo `N`, so `C_N` is abelian
+//! and isomorphic to the additive integers modulo `N`.
+//!
+//! # Presentation
+//!
+//! `C_N = ⟨g | g^N = e⟩`.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a cyclic group.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// An element `g^k` of the cyclic group of order `N` (`N > 0`).
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Cyclic<const N: usize> {
+  power: usize,
+}
+
+
+impl<const N: usize> Cyclic<N> {
+
+  /// The element `g^k`, with `k` reduced modulo `N`.
+  pub fn new(k: usize) -> Self {
+    assert!(N > 0, "cyclic group of order zero");
+
+    Cyclic { power: k % N }
+  }
+
+
+  /// The group generator `g`.
+  pub fn generator() -> Self {
+    Self::new(1)
+  }
+
+
+  /// The exponent `k` of the element `g^k`, from `0` to `N - 1`.
+  pub fn power(&self) -> usize {
+    self.power
+  }
+}
+
+
+///
+/// Cyclic group elements form a magma.
+///
+impl<const N: usize> Magma for Cyclic<N> {
+
+  /// The operation adds exponents modulo `N`.
+  fn op(&self, other: &Self) -> Self {
+    Self::new(self.power + other.power)
+  }
+}
+
+
+///
+/// Cyclic group elements form a semigroup.
+///
+impl<const N: usize> Semigroup for Cyclic<N> {}
+
+
+///
+/// Cyclic group elements form a monoid.
+///
+impl<const N: usize> Monoid for Cyclic<N> {
+
+  /// The identity is `g^0`.
+  fn id() -> Self {
+    Self::new(0)
+  }
+}
+
+
+///
+/// Cyclic group elements form a group.
+///
+impl<const N: usize> Group for Cyclic<N> {
+
+  /// The inverse of `g^k` is `g^(N-k)`.
+  fn inverse(&self) -> Self {
+    Self::new(N - self.power)
+  }
+}
+
+
+// Groups are loops.
+group_loop! {[const N: usize] Cyclic<N>}
+
+
+///
+/// Cyclic group elements form a commutative group.
+///
+impl<const N: usize> ComGroup for Cyclic<N> {}
+
+
+///
+/// Cyclic group elements form a finite type.
+///
+impl<const N: usize> Finite for Cyclic<N> {
+
+  /// The elements in exponent order.
+  fn elements() -> impl Iterator<Item = Self> {
+    (0..N).map(Self::new)
+  }
+}
+
+
+///
+/// Cyclic groups form finite groups.
+///
+impl<const N: usize> FiniteGroup for Cyclic<N> {
+
+  /// The single generator `g`.
+  fn generators() -> Vec<Self> {
+    vec![Self::generator()]
+  }
+
+
+  /// The relation `g^N = e`.
+  fn axiom_relations() -> bool {
+    Self::generator().pow(N).is_id()
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "cyclic_test.rs"]
+mod cyclic_test;
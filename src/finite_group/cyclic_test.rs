@@ -0,0 +1,74 @@
+use prelude::*;
+use proptest::prelude::*;
+use tests::prelude::*;
+
+
+type C1000 = Cyclic<1000>;
+
+
+prop_compose! {
+
+  /// Generate three random `C1000` elements.
+  fn c1000_3()(xs in any::<Three<usize>>()) -> Three<C1000> {
+    (C1000::new(xs.0), C1000::new(xs.1), C1000::new(xs.2))
+  }
+}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_associativity((ref x, ref y, ref z) in c1000_3()) {
+    Semigroup::axiom_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_left_inverse((ref x, _, _) in c1000_3()) {
+    Group::axiom_left_inverse(x)
+  }
+
+
+  #[test]
+  fn axiom_commutivity((ref x, ref y, _) in c1000_3()) {
+    ComGroup::axiom_commutivity((x, y))
+  }
+
+
+  #[test]
+  fn prop_order_divides((ref x, _, _) in c1000_3()) {
+    FiniteGroup::prop_order_divides(x)
+  }
+}
+
+
+#[test]
+fn exhaustive_commutivity() {
+  let commutivity = exhaustive_2::<Cyclic<24>, _>(ComGroup::axiom_commutivity);
+
+  assert_eq!(commutivity, Ok(()));
+}
+
+
+#[test]
+fn cyclic_elements() {
+  let g = Cyclic::<6>::generator();
+
+  assert_eq!(Cyclic::<6>::size(), 6);
+  assert_eq!(g.pow(4), Cyclic::new(10));
+  assert_eq!(g.pow(4).power(), 4);
+  assert_eq!(g.inverse(), Cyclic::new(5));
+
+  let orders: Vec<usize> = Cyclic::<6>::elements().map(|x| x.order()).collect();
+
+  assert_eq!(orders, vec![1, 6, 3, 2, 3, 6]);
+}
+
+
+#[test]
+#[should_panic]
+fn cyclic_order_zero() {
+  Cyclic::<0>::new(1);
+}
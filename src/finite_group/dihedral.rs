@@ -0,0 +1,162 @@
+//!
+//! The _dihedral_ groups.
+//!
+//! The dihedral group `D_N` of order `2N` is the group of symmetries
+//! of a regular `N`-sided polygon, generated by a rotation `r` (by
+//! `1/N` of a turn) and a reflection `s`. Its elements are the
+//! rotations `r^k` and reflections `r^k·s`, for `k` from `0` to
+//! `N - 1`. Dihedral groups are non-abelian for `N > 2`.
+//!
+//! # Presentation
+//!
+//! `D_N = ⟨r, s | r^N = s^2 = e, s·r·s = r^-1⟩`.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a dihedral group.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// An element `r^k·s^f` of the dihedral group of order `2N` (`N >
+/// 0`).
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Dihedral<const N: usize> {
+  rotation: usize,
+  reflection: bool,
+}
+
+
+impl<const N: usize> Dihedral<N> {
+
+  /// The element `r^k·s` (if `reflection` is true) or `r^k`, with `k`
+  /// reduced modulo `N`.
+  pub fn new(k: usize, reflection: bool) -> Self {
+    assert!(N > 0, "dihedral group of a polygon with no sides");
+
+    Dihedral { rotation: k % N, reflection }
+  }
+
+
+  /// The rotation generator `r`.
+  pub fn r() -> Self {
+    Self::new(1, false)
+  }
+
+
+  /// The reflection generator `s`.
+  pub fn s() -> Self {
+    Self::new(0, true)
+  }
+
+
+  /// The rotation exponent `k` of the element `r^k·s^f`.
+  pub fn rotation(&self) -> usize {
+    self.rotation
+  }
+
+
+  /// Test for a reflection, i.e. an element `r^k·s`.
+  pub fn is_reflection(&self) -> bool {
+    self.reflection
+  }
+}
+
+
+///
+/// Dihedral group elements form a magma.
+///
+impl<const N: usize> Magma for Dihedral<N> {
+
+  /// The operation uses `s·r^k = r^-k·s` to collect rotations.
+  fn op(&self, other: &Self) -> Self {
+    let k = if self.reflection { N - other.rotation } else { other.rotation };
+
+    Self::new(self.rotation + k, self.reflection ^ other.reflection)
+  }
+}
+
+
+///
+/// Dihedral group elements form a semigroup.
+///
+impl<const N: usize> Semigroup for Dihedral<N> {}
+
+
+///
+/// Dihedral group elements form a monoid.
+///
+impl<const N: usize> Monoid for Dihedral<N> {
+
+  /// The identity is the zero rotation.
+  fn id() -> Self {
+    Self::new(0, false)
+  }
+}
+
+
+///
+/// Dihedral group elements form a group.
+///
+impl<const N: usize> Group for Dihedral<N> {
+
+  /// Rotations are inverted by the opposite rotation, and
+  /// reflections are their own inverses.
+  fn inverse(&self) -> Self {
+    if self.reflection {
+      *self
+    }
+    else {
+      Self::new(N - self.rotation, false)
+    }
+  }
+}
+
+
+// Groups are loops.
+group_loop! {[const N: usize] Dihedral<N>}
+
+
+///
+/// Dihedral group elements form a finite type.
+///
+impl<const N: usize> Finite for Dihedral<N> {
+
+  /// The rotations followed by the reflections.
+  fn elements() -> impl Iterator<Item = Self> {
+    let rotations = (0..N).map(|k| Self::new(k, false));
+    let reflections = (0..N).map(|k| Self::new(k, true));
+
+    rotations.chain(reflections)
+  }
+}
+
+
+///
+/// Dihedral groups form finite groups.
+///
+impl<const N: usize> FiniteGroup for Dihedral<N> {
+
+  /// The generators `r` and `s`.
+  fn generators() -> Vec<Self> {
+    vec![Self::r(), Self::s()]
+  }
+
+
+  /// The relations `r^N = s^2 = e` and `s·r·s = r^-1`.
+  fn axiom_relations() -> bool {
+    let (r, s) = (Self::r(), Self::s());
+
+    r.pow(N).is_id() && s.pow(2).is_id() && s.op(&r).op(&s) == r.inverse()
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "dihedral_test.rs"]
+mod dihedral_test;
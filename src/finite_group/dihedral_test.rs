@@ -0,0 +1,76 @@
+use prelude::*;
+use tests::prelude::*;
+
+
+type D360 = Dihedral<360>;
+
+
+prop_compose! {
+
+  /// Generate one random `D360` element.
+  fn d360_1()(k in 0..360usize, f in 0..2u8) -> D360 {
+    D360::new(k, f == 1)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random `D360` elements.
+  fn d360_3()(xs in (d360_1(), d360_1(), d360_1())) -> Three<D360> {
+    xs
+  }
+}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_associativity((ref x, ref y, ref z) in d360_3()) {
+    Semigroup::axiom_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_left_inverse(ref x in d360_1()) {
+    Group::axiom_left_inverse(x)
+  }
+
+
+  #[test]
+  fn axiom_right_inverse(ref x in d360_1()) {
+    Group::axiom_right_inverse(x)
+  }
+
+
+  #[test]
+  fn prop_order_divides(ref x in d360_1()) {
+    FiniteGroup::prop_order_divides(x)
+  }
+}
+
+
+#[test]
+fn non_abelian() {
+  let (r, s) = (Dihedral::<3>::r(), Dihedral::<3>::s());
+
+  assert_ne!(r.op(&s), s.op(&r));
+  assert_eq!(classify::<Dihedral<3>>().structure(), Structure::Group);
+  assert_eq!(classify::<Dihedral<2>>().structure(), Structure::ComGroup);
+}
+
+
+#[test]
+fn dihedral_elements() {
+  let (r, s) = (Dihedral::<4>::r(), Dihedral::<4>::s());
+
+  assert_eq!(Dihedral::<4>::size(), 8);
+  assert_eq!(r.order(), 4);
+  assert_eq!(s.order(), 2);
+  assert_eq!(r.op(&s), Dihedral::new(1, true));
+  assert_eq!(s.op(&r), Dihedral::new(3, true));
+  assert!(r.op(&s).is_reflection());
+  assert_eq!(r.pow(3).rotation(), 3);
+}
@@ -0,0 +1,77 @@
+//!
+//! Algebraic _finite_ _group_ trait.
+//!
+//! A _finite_ _group_ is a group `G` with a finite number of
+//! elements, called the _order_ of `G`. The _order_ of an element
+//! `g` is the smallest `n > 0` with `g^n = e`, and by Lagrange's
+//! theorem element orders divide the group order.
+//!
+//! A _presentation_ of a group is a set of _generators_, whose
+//! products give every group element, together with _relations_
+//! between the generators that determine the group operation.
+//!
+//! # Axioms
+//!
+//! 1. Group: group axioms hold.
+//! 2. Generators: the generators span G.
+//! 3. Relations: the generators satisfy the presentation relations.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a group presentation.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// An algebraic _finite_ _group_ with a presentation.
+///
+pub trait FiniteGroup: Group + Finite + Clone {
+
+  /// The generators of the group presentation.
+  fn generators() -> Vec<Self>;
+
+
+  /// Test the relations of the group presentation.
+  fn axiom_relations() -> bool;
+
+
+  /// The order of a group element.
+  fn order(&self) -> usize {
+    let mut x = self.clone();
+    let mut n = 1;
+
+    while !x.is_id() {
+      x = x.op(self);
+      n += 1;
+    }
+
+    n
+  }
+
+
+  /// An element raised to a (non-negative) power.
+  fn pow(&self, n: usize) -> Self {
+    (0..n).fold(Self::id(), |x, _| x.op(self))
+  }
+
+
+  /// Test the axiom that the generators span the group.
+  fn axiom_generators() -> bool {
+    Subgroup::span(&Self::generators()).order() == Self::size()
+  }
+
+
+  /// Test the property that element orders divide the group order.
+  fn prop_order_divides(&self) -> bool {
+    Self::size() % self.order() == 0
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "finite_group_test.rs"]
+mod finite_group_test;
@@ -0,0 +1,37 @@
+use prelude::*;
+
+
+/// Exhaustively check the group axioms and presentation of a finite
+/// group.
+fn check_finite_group<G: FiniteGroup + ::std::fmt::Debug>() {
+  assert_eq!(exhaustive_3::<G, _>(Semigroup::axiom_associativity), Ok(()));
+  assert_eq!(exhaustive_1::<G, _>(Monoid::axiom_left_identity), Ok(()));
+  assert_eq!(exhaustive_1::<G, _>(Monoid::axiom_right_identity), Ok(()));
+  assert_eq!(exhaustive_1::<G, _>(Group::axiom_left_inverse), Ok(()));
+  assert_eq!(exhaustive_1::<G, _>(Group::axiom_right_inverse), Ok(()));
+  assert_eq!(exhaustive_1::<G, _>(FiniteGroup::prop_order_divides), Ok(()));
+
+  assert!(G::axiom_generators());
+  assert!(G::axiom_relations());
+}
+
+
+#[test]
+fn standard_finite_groups() {
+  check_finite_group::<Cyclic<1>>();
+  check_finite_group::<Cyclic<12>>();
+  check_finite_group::<Dihedral<1>>();
+  check_finite_group::<Dihedral<2>>();
+  check_finite_group::<Dihedral<7>>();
+  check_finite_group::<KleinFour>();
+  check_finite_group::<Q8>();
+}
+
+
+#[test]
+fn element_orders_and_powers() {
+  assert_eq!(Cyclic::<12>::new(8).order(), 3);
+  assert_eq!(Q8::I.order(), 4);
+  assert_eq!(Q8::I.pow(0), Q8::One);
+  assert_eq!(Q8::I.pow(3), Q8::NegI);
+}
@@ -0,0 +1,143 @@
+//!
+//! The _Klein_ _four_ group.
+//!
+//! The Klein four group `V` has four elements `{e, a, b, c}`, where
+//! every element is its own inverse and the product of any two
+//! distinct non-identity elements is the third. `V` is the smallest
+//! non-cyclic group, and is abelian, isomorphic to `C_2 × C_2`.
+//!
+//! # Presentation
+//!
+//! `V = ⟨a, b | a^2 = b^2 = (a·b)^2 = e⟩`.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of the Klein four group.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// An element of the Klein four group.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum KleinFour {
+  E, A, B, C
+}
+
+
+impl KleinFour {
+
+  /// The element bit pattern, with `A` and `B` as the two bits.
+  fn bits(&self) -> u8 {
+    match self {
+      KleinFour::E => 0,
+      KleinFour::A => 1,
+      KleinFour::B => 2,
+      KleinFour::C => 3,
+    }
+  }
+
+
+  /// The element with a bit pattern.
+  fn from_bits(bits: u8) -> Self {
+    match bits {
+      0 => KleinFour::E,
+      1 => KleinFour::A,
+      2 => KleinFour::B,
+      _ => KleinFour::C,
+    }
+  }
+}
+
+
+///
+/// Klein four group elements form a magma.
+///
+impl Magma for KleinFour {
+
+  /// The operation is bitwise XOR of element bit patterns.
+  fn op(&self, other: &Self) -> Self {
+    Self::from_bits(self.bits() ^ other.bits())
+  }
+}
+
+
+///
+/// Klein four group elements form a semigroup.
+///
+impl Semigroup for KleinFour {}
+
+
+///
+/// Klein four group elements form a monoid.
+///
+impl Monoid for KleinFour {
+
+  /// The identity is `E`.
+  fn id() -> Self {
+    KleinFour::E
+  }
+}
+
+
+///
+/// Klein four group elements form a group.
+///
+impl Group for KleinFour {
+
+  /// Elements are their own inverses.
+  fn inverse(&self) -> Self {
+    *self
+  }
+}
+
+
+// Groups are loops.
+group_loop! {[] KleinFour}
+
+
+///
+/// Klein four group elements form a commutative group.
+///
+impl ComGroup for KleinFour {}
+
+
+///
+/// Klein four group elements form a finite type.
+///
+impl Finite for KleinFour {
+
+  /// The elements in declaration order.
+  fn elements() -> impl Iterator<Item = Self> {
+    (0..4).map(Self::from_bits)
+  }
+}
+
+
+///
+/// The Klein four group is a finite group.
+///
+impl FiniteGroup for KleinFour {
+
+  /// The generators `A` and `B`.
+  fn generators() -> Vec<Self> {
+    vec![KleinFour::A, KleinFour::B]
+  }
+
+
+  /// The relations `a^2 = b^2 = (a·b)^2 = e`.
+  fn axiom_relations() -> bool {
+    let (a, b) = (KleinFour::A, KleinFour::B);
+
+    a.pow(2).is_id() && b.pow(2).is_id() && a.op(&b).pow(2).is_id()
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "klein_four_test.rs"]
+mod klein_four_test;
@@ -0,0 +1,25 @@
+use prelude::*;
+
+
+#[test]
+fn exhaustive_com_group() {
+  assert_eq!(exhaustive_2::<KleinFour, _>(ComGroup::axiom_commutivity), Ok(()));
+  assert_eq!(classify::<KleinFour>().structure(), Structure::ComGroup);
+}
+
+
+#[test]
+fn klein_four_elements() {
+  use finite_group::klein_four::KleinFour::*;
+
+  assert_eq!(A.op(&B), C);
+  assert_eq!(B.op(&C), A);
+  assert_eq!(C.inverse(), C);
+  assert!(KleinFour::elements().skip(1).all(|x| x.order() == 2));
+}
+
+
+#[test]
+fn non_cyclic() {
+  assert!(KleinFour::elements().all(|x| Subgroup::span(&[x]).order() < 4));
+}
@@ -0,0 +1,22 @@
+//!
+//! Standard _finite_ _group_ families.
+//!
+//! The `finite_group` module provides a `FiniteGroup` trait for
+//! finite groups with a known _presentation_ (generators and
+//! relations), and ready-made implementations of some standard
+//! finite groups:
+//!
+//! * the _cyclic_ groups `Cyclic<N>` (abelian),
+//! * the _dihedral_ groups `Dihedral<N>` (non-abelian for `N > 2`),
+//! * the _Klein_ _four_ group `KleinFour` (abelian), and
+//! * the _quaternion_ group `Q8` (non-abelian).
+//!
+pub mod finite_group;
+
+pub mod cyclic;
+
+pub mod dihedral;
+
+pub mod klein_four;
+
+pub mod quaternion;
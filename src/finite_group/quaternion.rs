@@ -0,0 +1,154 @@
+//!
+//! The _quaternion_ group.
+//!
+//! The quaternion group `Q8` has the eight elements `{±1, ±i, ±j,
+//! ±k}` of the quaternions, with quaternion multiplication, i.e.
+//! `i^2 = j^2 = k^2 = i·j·k = -1`. `Q8` is non-abelian, e.g. `i·j =
+//! k` but `j·i = -k`, although all of its subgroups are normal.
+//!
+//! # Presentation
+//!
+//! `Q8 = ⟨i, j | i^4 = e, i^2 = j^2, j^-1·i·j = i^-1⟩`.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of the quaternion group.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// An element of the quaternion group.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Q8 {
+  One, NegOne, I, NegI, J, NegJ, K, NegK
+}
+
+
+/// Products of quaternion units `1, i, j, k` as (negated, unit)
+/// pairs.
+const UNITS: [[(bool, usize); 4]; 4] = [
+  [(false, 0), (false, 1), (false, 2), (false, 3)],
+  [(false, 1), (true, 0), (false, 3), (true, 2)],
+  [(false, 2), (true, 3), (true, 0), (false, 1)],
+  [(false, 3), (false, 2), (true, 1), (true, 0)],
+];
+
+
+/// The group elements in (unit, sign) order.
+const ELEMENTS: [Q8; 8] = [
+  Q8::One, Q8::NegOne, Q8::I, Q8::NegI, Q8::J, Q8::NegJ, Q8::K, Q8::NegK
+];
+
+
+impl Q8 {
+
+  /// The element sign and unit, e.g. `-j` is `(true, 2)`.
+  fn parts(&self) -> (bool, usize) {
+    let index = ELEMENTS.iter().position(|x| x == self).unwrap();
+
+    (index % 2 == 1, index / 2)
+  }
+
+
+  /// The element with a sign and unit.
+  fn from_parts(negated: bool, unit: usize) -> Self {
+    ELEMENTS[2 * unit + negated as usize]
+  }
+}
+
+
+///
+/// Quaternion group elements form a magma.
+///
+impl Magma for Q8 {
+
+  /// The operation is quaternion multiplication.
+  fn op(&self, other: &Self) -> Self {
+    let (x, u) = self.parts();
+    let (y, v) = other.parts();
+    let (z, w) = UNITS[u][v];
+
+    Self::from_parts(x ^ y ^ z, w)
+  }
+}
+
+
+///
+/// Quaternion group elements form a semigroup.
+///
+impl Semigroup for Q8 {}
+
+
+///
+/// Quaternion group elements form a monoid.
+///
+impl Monoid for Q8 {
+
+  /// The identity is `1`.
+  fn id() -> Self {
+    Q8::One
+  }
+}
+
+
+///
+/// Quaternion group elements form a group.
+///
+impl Group for Q8 {
+
+  /// The inverse is the quaternion conjugate, i.e. `±1` are their own
+  /// inverses and other elements are negated.
+  fn inverse(&self) -> Self {
+    let (negated, unit) = self.parts();
+
+    Self::from_parts(negated ^ (unit != 0), unit)
+  }
+}
+
+
+// Groups are loops.
+group_loop! {[] Q8}
+
+
+///
+/// Quaternion group elements form a finite type.
+///
+impl Finite for Q8 {
+
+  /// The elements `1, -1, i, -i, j, -j, k, -k`.
+  fn elements() -> impl Iterator<Item = Self> {
+    ELEMENTS.iter().cloned()
+  }
+}
+
+
+///
+/// The quaternion group is a finite group.
+///
+impl FiniteGroup for Q8 {
+
+  /// The generators `i` and `j`.
+  fn generators() -> Vec<Self> {
+    vec![Q8::I, Q8::J]
+  }
+
+
+  /// The relations `i^4 = e`, `i^2 = j^2` and `j^-1·i·j = i^-1`.
+  fn axiom_relations() -> bool {
+    let (i, j) = (Q8::I, Q8::J);
+
+    let conjugate = j.inverse().op(&i).op(&j);
+
+    i.pow(4).is_id() && i.pow(2) == j.pow(2) && conjugate == i.inverse()
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "quaternion_test.rs"]
+mod quaternion_test;
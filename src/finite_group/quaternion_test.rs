@@ -0,0 +1,30 @@
+use prelude::*;
+
+
+#[test]
+fn non_abelian() {
+  assert_eq!(Q8::I.op(&Q8::J), Q8::K);
+  assert_eq!(Q8::J.op(&Q8::I), Q8::NegK);
+  assert_eq!(classify::<Q8>().structure(), Structure::Group);
+}
+
+
+#[test]
+fn quaternion_units() {
+  assert!([Q8::I, Q8::J, Q8::K].iter().all(|x| x.pow(2) == Q8::NegOne));
+  assert_eq!(Q8::I.op(&Q8::J).op(&Q8::K), Q8::NegOne);
+  assert_eq!(Q8::NegJ.inverse(), Q8::J);
+  assert_eq!(Q8::NegOne.inverse(), Q8::NegOne);
+}
+
+
+#[test]
+fn normal_subgroups() {
+  let elements: Vec<Q8> = Q8::elements().collect();
+
+  for x in elements.iter() {
+    for y in elements.iter() {
+      assert!(Subgroup::span(&[*x, *y]).is_normal());
+    }
+  }
+}
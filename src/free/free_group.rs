@@ -0,0 +1,190 @@
+//!
+//! The algebraic _free_ _group_.
+//!
+//! The _free_ _group_ `F(A)` over an alphabet `A` is the set of
+//! _reduced_ words in the letters of `A` and their formal inverses,
+//! i.e. words with no adjacent letter-inverse pairs such as `a a^-1`
+//! or `a^-1 a`. The group operation is word concatenation followed by
+//! _free_ _reduction_ (repeated cancellation of adjacent inverse
+//! pairs), the identity is the empty word, and the inverse of a word
+//! is its reversal with each letter inverted.
+//!
+//! Free groups over one letter are isomorphic to the integers under
+//! addition, and free groups over two or more letters are infinite
+//! and non-commutative.
+//!
+//! # Universal property
+//!
+//! Any map `f: A -> G` from the alphabet into a group `G` extends to
+//! a unique group homomorphism `F(A) -> G`, mapping a word `a1^±1
+//! ... an^±1` to `f(a1)^±1·...·f(an)^±1`.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a free group.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// A reduced word in the free group over an alphabet `A`. Word
+/// letters are pairs of a generator and an _inverted_ flag.
+///
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct FreeGroup<A> {
+  word: Vec<(A, bool)>,
+}
+
+
+impl<A: Clone + PartialEq> FreeGroup<A> {
+
+  /// A new (freely reduced) word from its letters.
+  pub fn new(word: Vec<(A, bool)>) -> Self {
+    FreeGroup { word: Vec::new() }.concat(word)
+  }
+
+
+  /// The single letter word of a generator.
+  pub fn generator(a: A) -> Self {
+    FreeGroup { word: vec![(a, false)] }
+  }
+
+
+  /// The single letter word of a generator inverse.
+  pub fn generator_inverse(a: A) -> Self {
+    FreeGroup { word: vec![(a, true)] }
+  }
+
+
+  /// The letters of the (reduced) word.
+  pub fn letters(&self) -> &[(A, bool)] {
+    &self.word
+  }
+
+
+  /// The length (number of letters) of the reduced word.
+  pub fn len(&self) -> usize {
+    self.word.len()
+  }
+
+
+  /// Test for the empty word, i.e. the identity.
+  pub fn is_empty(&self) -> bool {
+    self.word.is_empty()
+  }
+
+
+  /// The word raised to an integer power.
+  pub fn pow(&self, n: i64) -> Self {
+    let base = if n < 0 { self.inverse() } else { self.clone() };
+
+    (0..n.unsigned_abs()).fold(Self::id(), |w, _| w.op(&base))
+  }
+
+
+  /// Map the word into a group, using a map from generators into the
+  /// group (the unique homomorphism of the universal property).
+  pub fn lift<G, F>(&self, f: F) -> G
+    where G: Group, F: Fn(&A) -> G {
+    self.word.iter().fold(G::id(), |g, (a, inverted)| {
+      if *inverted { g.op(&f(a).inverse()) } else { g.op(&f(a)) }
+    })
+  }
+
+
+  /// Test the universal property homomorphism preserves the group
+  /// operation, for a map from generators into a group.
+  pub fn prop_lift<G, F>(xs: Pair<Self>, f: F) -> bool
+    where G: Group, F: Fn(&A) -> G {
+    let (x, y) = xs;
+
+    x.op(y).lift(&f) == x.lift(&f).op(&y.lift(&f))
+  }
+
+
+  /// Test the universal property homomorphism preserves inverses, for
+  /// a map from generators into a group.
+  pub fn prop_lift_inverse<G, F>(&self, f: F) -> bool
+    where G: Group, F: Fn(&A) -> G {
+    self.inverse().lift(&f) == self.lift(&f).inverse()
+  }
+
+
+  /// Test a word is freely reduced.
+  pub fn prop_reduced(&self) -> bool {
+    self.word.windows(2).all(|w| {
+      w[0].0 != w[1].0 || w[0].1 == w[1].1
+    })
+  }
+
+
+  /// Append letters to the word, freely reducing as we go.
+  fn concat<I>(mut self, letters: I) -> Self
+    where I: IntoIterator<Item = (A, bool)> {
+    for (a, inverted) in letters {
+      match self.word.last() {
+        Some((b, i)) if *b == a && *i != inverted => {
+          self.word.pop();
+        }
+        _ => self.word.push((a, inverted)),
+      }
+    }
+    self
+  }
+}
+
+
+///
+/// Free group words form a magma.
+///
+impl<A: Clone + PartialEq> Magma for FreeGroup<A> {
+
+  /// The operation is word concatenation with free reduction.
+  fn op(&self, other: &Self) -> Self {
+    self.clone().concat(other.word.iter().cloned())
+  }
+}
+
+
+///
+/// Free group words form a semigroup.
+///
+impl<A: Clone + PartialEq> Semigroup for FreeGroup<A> {}
+
+
+///
+/// Free group words form a monoid.
+///
+impl<A: Clone + PartialEq> Monoid for FreeGroup<A> {
+
+  /// The identity is the empty word.
+  fn id() -> Self {
+    FreeGroup { word: Vec::new() }
+  }
+}
+
+
+///
+/// Free group words form a group.
+///
+impl<A: Clone + PartialEq> Group for FreeGroup<A> {
+
+  /// The inverse is the reversed word with each letter inverted.
+  fn inverse(&self) -> Self {
+    let word = self.word.iter().rev().map(|(a, i)| (a.clone(), !i));
+
+    FreeGroup { word: word.collect() }
+  }
+}
+
+
+// Groups are loops.
+group_loop! {[A: Clone + PartialEq] FreeGroup<A>}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "free_group_test.rs"]
+mod free_group_test;
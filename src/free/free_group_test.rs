@@ -0,0 +1,132 @@
+use prelude::*;
+use proptest::prelude::*;
+use tests::prelude::*;
+
+
+prop_compose! {
+
+  /// Generate one random (unreduced) word over the alphabet `{0, 1,
+  /// 2}` and reduce it.
+  fn word_1()(xs in prop::collection::vec((0..3u8, any::<bool>()), 0..12))
+    -> FreeGroup<u8> {
+    FreeGroup::new(xs)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random free group words.
+  fn word_3()(xs in (word_1(), word_1(), word_1())) -> Three<FreeGroup<u8>> {
+    xs
+  }
+}
+
+
+/// Map generators into the dihedral group of order 10.
+fn dihedral(a: &u8) -> Dihedral<5> {
+  [Dihedral::r(), Dihedral::s(), Dihedral::r().op(&Dihedral::s())][*a as usize]
+}
+
+
+/// Map generators into the quaternion group.
+fn quaternion(a: &u8) -> Q8 {
+  [Q8::I, Q8::J, Q8::NegK][*a as usize]
+}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_associativity((ref x, ref y, ref z) in word_3()) {
+    Semigroup::axiom_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_left_identity((ref x, _, _) in word_3()) {
+    Monoid::axiom_left_identity(x)
+  }
+
+
+  #[test]
+  fn axiom_right_identity((ref x, _, _) in word_3()) {
+    Monoid::axiom_right_identity(x)
+  }
+
+
+  #[test]
+  fn axiom_left_inverse((ref x, _, _) in word_3()) {
+    Group::axiom_left_inverse(x)
+  }
+
+
+  #[test]
+  fn axiom_right_inverse((ref x, _, _) in word_3()) {
+    Group::axiom_right_inverse(x)
+  }
+
+
+  #[test]
+  fn prop_reduced((ref x, ref y, _) in word_3()) {
+    FreeGroup::prop_reduced(&x.op(y))
+  }
+
+
+  #[test]
+  fn prop_lift_dihedral((ref x, ref y, _) in word_3()) {
+    FreeGroup::prop_lift((x, y), dihedral)
+  }
+
+
+  #[test]
+  fn prop_lift_quaternion((ref x, ref y, _) in word_3()) {
+    FreeGroup::prop_lift((x, y), quaternion)
+  }
+
+
+  #[test]
+  fn prop_lift_inverse((ref x, _, _) in word_3()) {
+    FreeGroup::prop_lift_inverse(x, dihedral)
+  }
+}
+
+
+#[test]
+fn free_reduction() {
+  let (a, b) = (FreeGroup::generator('a'), FreeGroup::generator('b'));
+  let word = FreeGroup::new(vec![
+    ('a', false), ('b', false), ('b', true), ('a', false), ('a', true),
+  ]);
+
+  assert_eq!(word, a);
+  assert_eq!(a.op(&b).op(&b.inverse()).op(&a.inverse()), FreeGroup::id());
+  assert_eq!(a.op(&b).inverse(), b.inverse().op(&a.inverse()));
+  assert_ne!(a.op(&b), b.op(&a));
+  assert_eq!(FreeGroup::generator_inverse('a'), a.inverse());
+}
+
+
+#[test]
+fn powers() {
+  let a = FreeGroup::generator('a');
+
+  assert_eq!(a.pow(3).len(), 3);
+  assert_eq!(a.pow(-2), a.inverse().op(&a.inverse()));
+  assert_eq!(a.pow(3).op(&a.pow(-3)), FreeGroup::id());
+  assert!(a.pow(0).is_empty());
+}
+
+
+#[test]
+fn lift_relators() {
+  let (r, s) = (FreeGroup::generator(0), FreeGroup::generator(1));
+  let relators = [r.pow(5), s.pow(2), r.op(&s).pow(2)];
+
+  for relator in &relators {
+    assert_eq!(relator.lift(dihedral), Dihedral::id());
+  }
+  assert_ne!(r.lift(dihedral), Dihedral::id());
+}
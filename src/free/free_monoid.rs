@@ -0,0 +1,117 @@
+//!
+//! The algebraic _free_ _monoid_.
+//!
+//! The _free_ _monoid_ `A*` over an alphabet `A` is the set of finite
+//! _words_ (sequences) of letters from `A`, with word concatenation
+//! as the operation and the empty word as identity. Free monoids
+//! over two or more letters are infinite and non-commutative.
+//!
+//! # Universal property
+//!
+//! Any map `f: A -> M` from the alphabet into a monoid `M` extends
+//! to a unique monoid homomorphism `A* -> M`, mapping a word `a1 a2
+//! ... an` to `f(a1)·f(a2)·...·f(an)`.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a free monoid.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// A word in the free monoid over an alphabet `A`.
+///
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct FreeMonoid<A> {
+  word: Vec<A>,
+}
+
+
+impl<A: Clone + PartialEq> FreeMonoid<A> {
+
+  /// A new word from its letters.
+  pub fn new(word: Vec<A>) -> Self {
+    FreeMonoid { word }
+  }
+
+
+  /// The single letter word of a generator.
+  pub fn generator(a: A) -> Self {
+    Self::new(vec![a])
+  }
+
+
+  /// The letters of the word.
+  pub fn letters(&self) -> &[A] {
+    &self.word
+  }
+
+
+  /// The length (number of letters) of the word.
+  pub fn len(&self) -> usize {
+    self.word.len()
+  }
+
+
+  /// Test for the empty word.
+  pub fn is_empty(&self) -> bool {
+    self.word.is_empty()
+  }
+
+
+  /// Map the word into a monoid, using a map from letters into the
+  /// monoid (the unique homomorphism of the universal property).
+  pub fn fold_into<M, F>(&self, f: F) -> M
+    where M: Monoid, F: Fn(&A) -> M {
+    self.word.iter().fold(M::id(), |m, a| m.op(&f(a)))
+  }
+
+
+  /// Test the universal property homomorphism preserves the monoid
+  /// operation, for a map from letters into a monoid.
+  pub fn prop_fold_into<M, F>(xs: Pair<Self>, f: F) -> bool
+    where M: Monoid, F: Fn(&A) -> M {
+    let (x, y) = xs;
+
+    x.op(y).fold_into(&f) == x.fold_into(&f).op(&y.fold_into(&f))
+  }
+}
+
+
+///
+/// Free monoid words form a magma.
+///
+impl<A: Clone + PartialEq> Magma for FreeMonoid<A> {
+
+  /// The operation is word concatenation.
+  fn op(&self, other: &Self) -> Self {
+    Self::new(self.word.op(&other.word))
+  }
+}
+
+
+///
+/// Free monoid words form a semigroup.
+///
+impl<A: Clone + PartialEq> Semigroup for FreeMonoid<A> {}
+
+
+///
+/// Free monoid words form a monoid.
+///
+impl<A: Clone + PartialEq> Monoid for FreeMonoid<A> {
+
+  /// The identity is the empty word.
+  fn id() -> Self {
+    Self::new(Vec::new())
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "free_monoid_test.rs"]
+mod free_monoid_test;
@@ -0,0 +1,108 @@
+use prelude::*;
+use tests::prelude::*;
+use proptest::prelude::*;
+
+
+prop_compose! {
+
+  /// Generate one random word over the alphabet `{0, 1, 2}`.
+  fn word_1()(xs in prop::collection::vec(0..3u8, 0..8)) -> FreeMonoid<u8> {
+    FreeMonoid::new(xs)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random words over the alphabet `{0, 1, 2}`.
+  fn word_3()(xs in (word_1(), word_1(), word_1())) -> Three<FreeMonoid<u8>> {
+    xs
+  }
+}
+
+
+/// Map letters into the multiplicative monoid of 2 by 2 integer
+/// matrices (as flattened arrays).
+fn matrix(a: &u8) -> Matrix {
+  Matrix([[1, 1, 0, 1], [1, 0, 1, 1], [2, 1, 1, 1]][*a as usize])
+}
+
+
+/// A 2 by 2 integer matrix, flattened in row order.
+#[derive(Clone, PartialEq, Debug)]
+struct Matrix([i64; 4]);
+
+impl Magma for Matrix {
+  fn op(&self, other: &Self) -> Self {
+    let ([a, b, c, d], [e, f, g, h]) = (self.0, other.0);
+
+    Matrix([a * e + b * g, a * f + b * h, c * e + d * g, c * f + d * h])
+  }
+}
+
+impl Semigroup for Matrix {}
+
+impl Monoid for Matrix {
+  fn id() -> Self {
+    Matrix([1, 0, 0, 1])
+  }
+}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_associativity((ref x, ref y, ref z) in word_3()) {
+    Semigroup::axiom_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_left_identity((ref x, _, _) in word_3()) {
+    Monoid::axiom_left_identity(x)
+  }
+
+
+  #[test]
+  fn axiom_right_identity((ref x, _, _) in word_3()) {
+    Monoid::axiom_right_identity(x)
+  }
+
+
+  #[test]
+  fn prop_fold_into_string((ref x, ref y, _) in word_3()) {
+    FreeMonoid::prop_fold_into((x, y), |a| a.to_string())
+  }
+
+
+  #[test]
+  fn prop_fold_into_matrix((ref x, ref y, _) in word_3()) {
+    FreeMonoid::prop_fold_into((x, y), matrix)
+  }
+}
+
+
+#[test]
+fn words() {
+  let (a, b) = (FreeMonoid::generator('a'), FreeMonoid::generator('b'));
+  let ab = a.op(&b);
+
+  assert_eq!(ab.letters(), &['a', 'b']);
+  assert_eq!(ab.len(), 2);
+  assert_ne!(ab, b.op(&a));
+  assert!(FreeMonoid::<char>::id().is_empty());
+}
+
+
+#[test]
+fn fold_into_monoids() {
+  let word = FreeMonoid::new(vec!['a', 'b', 'b', 'a']);
+
+  let doubled: Vec<char> = "aabbbbaa".chars().collect();
+
+  assert_eq!(word.fold_into(|c| vec![*c, *c]), doubled);
+  assert_eq!(word.fold_into(|c| c.to_ascii_uppercase().to_string()), "ABBA");
+  assert_eq!(FreeMonoid::<char>::id().fold_into(|c| c.to_string()), "");
+}
@@ -0,0 +1,12 @@
+//!
+//! Algebraic _free_ structures.
+//!
+//! A _free_ structure over an _alphabet_ of generators is the most
+//! general structure of its kind generated by the alphabet, i.e.
+//! elements are _words_ in the generators, subject only to the
+//! structure axioms. The _universal_ _property_ of a free structure
+//! is that any assignment of generators to elements of another
+//! structure extends uniquely to a homomorphism.
+//!
+pub mod free_monoid;
+pub mod free_group;
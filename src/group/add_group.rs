@@ -15,9 +15,11 @@
 //!
 //! See [references] for a formal definition of an additive group.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use monoid::add_monoid::*;
+use numeric::equal::*;
+use numeric::equivalence::*;
 
 
 ///
@@ -37,13 +39,27 @@ pub trait AddGroup: AddMonoid {
 
   /// Test the left axiom of negation.
   fn axiom_left_negate(&self) -> bool {
-    self.negate().add(self) == Self::zero()
+    self.axiom_left_negate_by(&Exact)
   }
 
 
   /// Test the right axiom of negation.
   fn axiom_right_negate(&self) -> bool {
-    self.add(&self.negate()) == Self::zero()
+    self.axiom_right_negate_by(&Exact)
+  }
+
+
+  /// Test the left axiom of negation up to an equivalence.
+  fn axiom_left_negate_by<E>(&self, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    eq.equiv(&self.negate().add(self), &Self::zero())
+  }
+
+
+  /// Test the right axiom of negation up to an equivalence.
+  fn axiom_right_negate_by<E>(&self, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    eq.equiv(&self.add(&self.negate()), &Self::zero())
   }
 }
 
@@ -54,31 +70,28 @@ pub trait AddGroup: AddMonoid {
 /// `NumAddGroup` trait is for types that only form additive groups
 /// when "numeric" comparisons are used, e.g. floating point types.
 ///
-pub trait NumAddGroup: NumAddMonoid {
-
-  /// The unique additive inverse of a group element.
-  fn negate(&self) -> Self;
-
-
-  /// The additive "subtraction" of two group elements.
-  fn sub(&self, other: &Self) -> Self {
-    self.add(&other.negate())
-  }
-
+pub trait NumAddGroup: AddGroup + NumAddMonoid {
 
   /// Numerically test the left axiom of negation.
   fn axiom_left_negate(&self, eps: &Self::Error) -> bool {
-    self.negate().add(self).num_eq(&Self::zero(), eps)
+    self.axiom_left_negate_by(&Within(eps))
   }
 
 
   /// Numerically test the right axiom of negation.
   fn axiom_right_negate(&self, eps: &Self::Error) -> bool {
-    self.add(&self.negate()).num_eq(&Self::zero(), eps)
+    self.axiom_right_negate_by(&Within(eps))
   }
 }
 
 
+///
+/// Additive groups with numeric equality form numeric additive
+/// groups.
+///
+impl<T: AddGroup + NumEq> NumAddGroup for T {}
+
+
 ///
 /// Trait implementation macro for integer types.
 ///
@@ -112,10 +125,9 @@ integer_add_group! {
 
 
 ///
-/// IEEE 32 bit floating point types only form a _numeric_ additive
-/// group.
+/// IEEE 32 bit floating point types form an additive group.
 ///
-impl NumAddGroup for f32 {
+impl AddGroup for f32 {
 
   // Negation is just floating point negation.
   fn negate(&self) -> Self {
@@ -125,10 +137,9 @@ impl NumAddGroup for f32 {
 
 
 ///
-/// IEEE 64 bit floating point types only form a _numeric_ additive
-/// group.
+/// IEEE 64 bit floating point types form an additive group.
 ///
-impl NumAddGroup for f64 {
+impl AddGroup for f64 {
 
   // Negation is just floating point negation.
   fn negate(&self) -> Self {
@@ -9,19 +9,27 @@
 //! 1. Monoid: monoid axioms hold.
 //! 2. Inverse: ∀g ∈ S, ∃g^-1 ∈ S, g·g^-1 = g^-1·g = e.
 //!
+//! Every group is also a _loop_, with left division `x\y = x^-1·y`,
+//! right division `y/x = y·x^-1`, and the group identity as the loop
+//! identity. The [`group_loop!`] macro implements `Quasigroup` and
+//! `Loop` for a group type.
+//!
+//! [`group_loop!`]: crate::group_loop
+//!
 //! # References
 //!
 //! See [references] for a formal definition of a group.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use monoid::monoid::*;
+use quasigroup::loops::*;
 
 
 ///
 /// An algebraic _group_.
 ///
-pub trait Group: Monoid {
+pub trait Group: Monoid + Loop {
 
   /// The unique inverse of a group element.
   fn inverse(&self) -> Self;
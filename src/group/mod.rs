@@ -6,8 +6,15 @@
 //! or _multiplicative groups_, including their "numeric"
 //! counterparts.
 //!
+//! Subgroups, cosets and quotient groups of (finite) groups are
+//! provided by the `subgroup` and `quotient_group` modules.
+//!
 pub mod group;
 
 pub mod add_group;
 
 pub mod mul_group;
+
+pub mod subgroup;
+
+pub mod quotient_group;
@@ -16,9 +16,11 @@
 //! See [references] for a formal definition of a multiplicative
 //! group.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use monoid::mul_monoid::*;
+use numeric::equal::*;
+use numeric::equivalence::*;
 
 
 ///
@@ -43,13 +45,27 @@ pub trait MulGroup: MulMonoid {
 
   /// Test the left axiom of inversion.
   fn axiom_left_invert(&self) -> bool {
-     self.invert().mul(self) == Self::one()
+    self.axiom_left_invert_by(&Exact)
   }
 
 
   /// Test the right axiom of inversion.
   fn axiom_right_invert(&self) -> bool {
-    self.mul(&self.invert()) == Self::one()
+    self.axiom_right_invert_by(&Exact)
+  }
+
+
+  /// Test the left axiom of inversion up to an equivalence.
+  fn axiom_left_invert_by<E>(&self, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    eq.equiv(&self.invert().mul(self), &Self::one())
+  }
+
+
+  /// Test the right axiom of inversion up to an equivalence.
+  fn axiom_right_invert_by<E>(&self, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    eq.equiv(&self.mul(&self.invert()), &Self::one())
   }
 }
 
@@ -57,45 +73,36 @@ pub trait MulGroup: MulMonoid {
 ///
 /// A "numeric" algebraic _multiplicative group_.
 ///
-/// `NumAddGroup` trait is for types that only form multiplicative
+/// `NumMulGroup` trait is for types that only form multiplicative
 /// groups when "numeric" comparisons are used, e.g. floating point
 /// types.
 ///
-pub trait NumMulGroup: NumMulMonoid {
-
-  /// The unique multiplicative inverse of a group element. Inversion
-  /// is only defined for _invertible_ group elements.
-  fn invert(&self) -> Self;
-
-
-  /// Test for an _invertible_ group element.
-  fn is_invertible(&self) -> bool;
-
-
-  /// The multiplicative "division" of two group elements.
-  fn div(&self, other: &Self) -> Self {
-    self.mul(&other.invert())
-  }
-
+pub trait NumMulGroup: MulGroup + NumMulMonoid {
 
   /// Numerically test the left axiom of inversion.
   fn axiom_left_invert(&self, eps: &Self::Error) -> bool {
-    self.invert().mul(self).num_eq(&Self::one(), eps)
+    self.axiom_left_invert_by(&Within(eps))
   }
 
 
   /// Numerically test the right axiom of inversion.
   fn axiom_right_invert(&self, eps: &Self::Error) -> bool {
-    self.mul(&self.invert()).num_eq(&Self::one(), eps)
+    self.axiom_right_invert_by(&Within(eps))
   }
 }
 
 
 ///
-/// IEEE 32 bit floating point types only form a _numeric_
-/// multiplicative group.
+/// Multiplicative groups with numeric equality form numeric
+/// multiplicative groups.
+///
+impl<T: MulGroup + NumEq> NumMulGroup for T {}
+
+
+///
+/// IEEE 32 bit floating point types form a multiplicative group.
 ///
-impl NumMulGroup for f32 {
+impl MulGroup for f32 {
 
   /// Inversion is just floating point inversion.
   fn invert(&self) -> Self {
@@ -111,10 +118,9 @@ impl NumMulGroup for f32 {
 
 
 ///
-/// IEEE 64 bit floating point types only form a _numeric_
-/// multiplicative group.
+/// IEEE 64 bit floating point types form a multiplicative group.
 ///
-impl NumMulGroup for f64 {
+impl MulGroup for f64 {
 
   /// Inversion is just floating point inversion.
   fn invert(&self) -> Self {
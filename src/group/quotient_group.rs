@@ -0,0 +1,179 @@
+//!
+//! Algebraic trait implementations for _quotient groups_.
+//!
+//! The _quotient_ _group_ `G/N` of a group `G` by a _normal_ subgroup
+//! `N` is the set of cosets `gN`, with the operation of coset
+//! representatives. Normality of `N` makes the operation independent
+//! of the chosen representatives. Quotient groups of _abelian_
+//! groups are abelian.
+//!
+//! Quotient group elements can only be constructed from normal
+//! subgroups of finite groups (see [`Subgroup::is_normal`]).
+//!
+//! # Identities
+//!
+//! The group identity here has no way of knowing the subgroup of the
+//! quotient it belongs to. So the identity belongs to the _trivial_
+//! subgroup, and elements of the trivial subgroup adopt the subgroup
+//! of any element they are combined with. Combining elements of
+//! quotient groups by _different_ non-trivial subgroups panics.
+//!
+//! [`Subgroup::is_normal`]: crate::group::subgroup::Subgroup::is_normal
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a quotient group.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// An element (coset) of the quotient of a group by a normal
+/// subgroup.
+///
+#[derive(Clone, Debug)]
+pub struct QuotientGroup<G> {
+  value: G,
+  normal: Subgroup<G>,
+}
+
+
+impl<G: Group + Clone + Finite> QuotientGroup<G> {
+
+  /// The coset `gN` of an element and a normal subgroup, or `None` if
+  /// the subgroup is not normal.
+  pub fn new(g: G, normal: &Subgroup<G>) -> Option<Self> {
+    if normal.is_normal() {
+      Some(Self::with(g, normal))
+    }
+    else {
+      None
+    }
+  }
+}
+
+
+impl<G: Group + Clone> QuotientGroup<G> {
+
+
+  /// A representative of the coset.
+  pub fn value(&self) -> &G {
+    &self.value
+  }
+
+
+  /// The normal subgroup of the quotient group.
+  pub fn normal(&self) -> &Subgroup<G> {
+    &self.normal
+  }
+
+
+  /// The elements of the coset.
+  pub fn coset(&self) -> Vec<G> {
+    self.normal.left_coset(&self.value)
+  }
+
+
+  /// The coset `gN` of an element and a subgroup known to be
+  /// normal.
+  fn with(g: G, normal: &Subgroup<G>) -> Self {
+    QuotientGroup { value: g, normal: normal.clone() }
+  }
+
+
+  /// The subgroup common to two quotient elements. Panics if the
+  /// elements belong to quotient groups by different non-trivial
+  /// subgroups.
+  fn common(&self, other: &Self) -> Subgroup<G> {
+    self.adopt(other).expect("quotient group elements from different subgroups")
+  }
+
+
+  /// The subgroup common to two quotient elements, where the trivial
+  /// subgroup adopts the other subgroup, or `None` if the elements
+  /// belong to quotient groups by different non-trivial subgroups.
+  fn adopt(&self, other: &Self) -> Option<Subgroup<G>> {
+    if self.normal.order() == 1 {
+      Some(other.normal.clone())
+    }
+    else if other.normal.order() == 1 || self.normal == other.normal {
+      Some(self.normal.clone())
+    }
+    else {
+      None
+    }
+  }
+}
+
+
+///
+/// Quotient group elements are equal when they belong to the same
+/// quotient group and their representatives are in the same coset.
+///
+impl<G: Group + Clone> PartialEq for QuotientGroup<G> {
+  fn eq(&self, other: &Self) -> bool {
+    self.adopt(other).is_some_and(|normal| {
+      normal.same_left_coset(&self.value, &other.value)
+    })
+  }
+}
+
+
+///
+/// Quotient groups form a magma.
+///
+impl<G: Group + Clone> Magma for QuotientGroup<G> {
+
+  /// The operation is the operation of representatives.
+  fn op(&self, other: &Self) -> Self {
+    Self::with(self.value.op(&other.value), &self.common(other))
+  }
+}
+
+
+///
+/// Quotient groups form a semigroup.
+///
+impl<G: Group + Clone> Semigroup for QuotientGroup<G> {}
+
+
+///
+/// Quotient groups form a monoid.
+///
+impl<G: Group + Clone> Monoid for QuotientGroup<G> {
+
+  /// The identity is the identity coset of the trivial subgroup.
+  fn id() -> Self {
+    Self::with(G::id(), &Subgroup::trivial())
+  }
+}
+
+
+///
+/// Quotient groups form a group.
+///
+impl<G: Group + Clone> Group for QuotientGroup<G> {
+
+  /// The inverse is the coset of the representative inverse.
+  fn inverse(&self) -> Self {
+    Self::with(self.value.inverse(), &self.normal)
+  }
+}
+
+
+// Groups are loops.
+group_loop! {[G: Group + Clone] QuotientGroup<G>}
+
+
+///
+/// Quotient groups of commutative groups form a commutative group.
+///
+impl<G: ComGroup + Clone> ComGroup for QuotientGroup<G> {}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "quotient_group_test.rs"]
+mod quotient_group_test;
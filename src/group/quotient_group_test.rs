@@ -0,0 +1,101 @@
+use prelude::*;
+use tests::prelude::*;
+
+
+/// Addition modulo 4.
+struct Z4;
+
+impl CayleyTable<4> for Z4 {
+  type Kind = ComGroups;
+
+  const OP: Table<4> = [[0, 1, 2, 3], [1, 2, 3, 0], [2, 3, 0, 1], [3, 0, 1, 2]];
+}
+
+
+type C4 = Cayley<Z4, 4>;
+
+
+/// The quotient S3/A3 elements, one per S3 element.
+fn s3_a3() -> Vec<QuotientGroup<S3>> {
+  let alt = Subgroup::span(&[S3::new(3)]);
+
+  S3::elements().map(|g| alt.quotient(g).unwrap()).collect()
+}
+
+
+#[test]
+fn group_axioms_s3_a3() {
+  let xs = s3_a3();
+
+  for x in &xs {
+    assert!(x.axiom_left_inverse());
+    assert!(x.axiom_right_inverse());
+    assert_eq!(x.op(&QuotientGroup::id()), *x);
+
+    for y in &xs {
+      for z in &xs {
+        assert!(Semigroup::axiom_associativity((x, y, z)));
+      }
+    }
+  }
+}
+
+
+#[test]
+fn quotient_equality() {
+  let xs = s3_a3();
+
+  assert_eq!(xs[0], xs[3]);
+  assert_eq!(xs[1], xs[5]);
+  assert_ne!(xs[0], xs[1]);
+  assert!(xs[0].is_id());
+  assert_eq!(xs[1].op(&xs[2]), QuotientGroup::id());
+  assert_eq!(xs[1].coset().len(), 3);
+}
+
+
+#[test]
+fn com_group_axioms_c4_c2() {
+  let half = Subgroup::span(&[C4::new(2)]);
+  let xs: Vec<_> =
+    C4::elements().map(|g| half.quotient(g).unwrap()).collect();
+
+  assert_eq!(xs[1], xs[3]);
+
+  for x in &xs {
+    for y in &xs {
+      assert!(ComGroup::axiom_commutivity((x, y)));
+    }
+  }
+}
+
+
+#[test]
+fn quotient_requires_normal() {
+  let swap = Subgroup::span(&[S3::new(1)]);
+
+  assert!(swap.quotient(S3::new(2)).is_none());
+  assert!(QuotientGroup::new(S3::new(2), &Subgroup::trivial()).is_some());
+}
+
+
+#[test]
+#[should_panic(expected = "different subgroups")]
+fn mixed_subgroups_panic() {
+  let (twos, threes) = (Cyclic::<6>::new(2), Cyclic::<6>::new(3));
+  let x = Subgroup::span(&[twos]).quotient(Cyclic::new(1)).unwrap();
+  let y = Subgroup::span(&[threes]).quotient(Cyclic::new(1)).unwrap();
+
+  let _ = x.op(&y);
+}
+
+
+#[test]
+fn mixed_subgroups_unequal() {
+  let (twos, threes) = (Cyclic::<6>::new(2), Cyclic::<6>::new(3));
+  let x = Subgroup::span(&[twos]).quotient(Cyclic::new(0)).unwrap();
+  let y = Subgroup::span(&[threes]).quotient(Cyclic::new(1)).unwrap();
+
+  assert_ne!(x, y);
+  assert_eq!(x.op(&QuotientGroup::id()), x);
+}
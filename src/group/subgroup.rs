@@ -0,0 +1,270 @@
+//!
+//! Algebraic _subgroups_ and _cosets_ of groups.
+//!
+//! A _subgroup_ `H` of a group `G` is a subset of `G` that forms a
+//! group under the operation of `G`. The subgroup _spanned_ (or
+//! generated) by a set of elements is the smallest subgroup
+//! containing them, i.e. all products of the elements and their
+//! inverses.
+//!
+//! The _left_ and _right_ _cosets_ of a subgroup `H` are the sets
+//! `gH = {g·h | h ∈ H}` and `Hg = {h·g | h ∈ H}`. The left (or right)
+//! cosets of `H` partition `G` into sets of equal size, so for
+//! finite groups the order of `H` divides the order of `G`
+//! (_Lagrange's_ theorem). A subgroup is _normal_ when its left and
+//! right cosets agree, and then its cosets form a _quotient_ group
+//! `G/H`.
+//!
+//! Subgroups here are stored as lists of their elements, so spanned
+//! subgroups must be _finite_.
+//!
+//! # Axioms
+//!
+//! 1. Identity: e ∈ H.
+//! 2. Closure: ∀x, y ∈ H, x·y ∈ H.
+//! 3. Inverse: ∀x ∈ H, x^-1 ∈ H.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a subgroup.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// A (finite) subgroup of a group.
+///
+#[derive(Clone, Debug)]
+pub struct Subgroup<G> {
+  elements: Vec<G>,
+}
+
+
+impl<G: Group + Clone> Subgroup<G> {
+
+  /// The _trivial_ subgroup containing only the identity.
+  pub fn trivial() -> Self {
+    Subgroup { elements: vec![G::id()] }
+  }
+
+
+  /// The subgroup spanned by a list of generating elements. The
+  /// spanned subgroup must be finite.
+  pub fn span(gens: &[G]) -> Self {
+    let mut elements = vec![G::id()];
+    let mut i = 0;
+
+    // In a finite group element inverses are powers of the element,
+    // so closing the identity under right multiplication by the
+    // generators gives the spanned subgroup.
+    while i < elements.len() {
+      for g in gens {
+        let x = elements[i].op(g);
+
+        if !elements.contains(&x) {
+          elements.push(x);
+        }
+      }
+
+      i += 1;
+    }
+
+    Subgroup { elements }
+  }
+
+
+  /// The elements of the subgroup, identity first.
+  pub fn elements(&self) -> &[G] {
+    &self.elements
+  }
+
+
+  /// The _order_ (number of elements) of the subgroup.
+  pub fn order(&self) -> usize {
+    self.elements.len()
+  }
+
+
+  /// Test for subgroup membership.
+  pub fn contains(&self, x: &G) -> bool {
+    self.elements.contains(x)
+  }
+
+
+  /// Test if the subgroup is a subset of another subgroup.
+  pub fn is_subset(&self, other: &Self) -> bool {
+    self.elements.iter().all(|x| other.contains(x))
+  }
+
+
+  /// The _join_ of two subgroups, i.e. the subgroup spanned by both.
+  pub fn join(&self, other: &Self) -> Self {
+    if other.is_subset(self) {
+      return self.clone();
+    }
+
+    if self.is_subset(other) {
+      return other.clone();
+    }
+
+    let mut gens = self.elements.clone();
+
+    gens.extend(other.elements.iter().cloned());
+
+    Self::span(&gens)
+  }
+
+
+  /// The left coset `gH` of an element.
+  pub fn left_coset(&self, g: &G) -> Vec<G> {
+    self.elements.iter().map(|h| g.op(h)).collect()
+  }
+
+
+  /// The right coset `Hg` of an element.
+  pub fn right_coset(&self, g: &G) -> Vec<G> {
+    self.elements.iter().map(|h| h.op(g)).collect()
+  }
+
+
+  /// Test if two elements are in the same left coset.
+  pub fn same_left_coset(&self, x: &G, y: &G) -> bool {
+    self.contains(&x.inverse().op(y))
+  }
+
+
+  /// Test if two elements are in the same right coset.
+  pub fn same_right_coset(&self, x: &G, y: &G) -> bool {
+    self.contains(&x.op(&y.inverse()))
+  }
+
+
+  /// Test if the subgroup is normal in a list of group elements, i.e.
+  /// `gHg^-1 = H` for each listed element `g`.
+  pub fn is_normal_in(&self, gs: &[G]) -> bool {
+    gs.iter().all(|g| {
+      let inv = g.inverse();
+
+      self.elements.iter().all(|h| self.contains(&g.op(h).op(&inv)))
+    })
+  }
+
+
+  /// Test the identity axiom.
+  pub fn axiom_identity(&self) -> bool {
+    self.contains(&G::id())
+  }
+
+
+  /// Test the closure axiom.
+  pub fn axiom_closure(&self, xs: Pair<G>) -> bool {
+    let (x, y) = xs;
+
+    implies(self.contains(x) && self.contains(y), self.contains(&x.op(y)))
+  }
+
+
+  /// Test the inverse axiom.
+  pub fn axiom_inverse(&self, x: &G) -> bool {
+    implies(self.contains(x), self.contains(&x.inverse()))
+  }
+}
+
+
+impl<G: Group + Clone + Finite> Subgroup<G> {
+
+  /// The distinct left cosets of the subgroup.
+  pub fn left_cosets(&self) -> Vec<Vec<G>> {
+    let mut reps: Vec<G> = Vec::new();
+
+    for g in G::elements() {
+      if !reps.iter().any(|r| self.same_left_coset(r, &g)) {
+        reps.push(g);
+      }
+    }
+
+    reps.iter().map(|r| self.left_coset(r)).collect()
+  }
+
+
+  /// The distinct right cosets of the subgroup.
+  pub fn right_cosets(&self) -> Vec<Vec<G>> {
+    let mut reps: Vec<G> = Vec::new();
+
+    for g in G::elements() {
+      if !reps.iter().any(|r| self.same_right_coset(r, &g)) {
+        reps.push(g);
+      }
+    }
+
+    reps.iter().map(|r| self.right_coset(r)).collect()
+  }
+
+
+  /// The _index_ of the subgroup, i.e. the number of its cosets.
+  pub fn index(&self) -> usize {
+    self.left_cosets().len()
+  }
+
+
+  /// Test if the subgroup is normal.
+  pub fn is_normal(&self) -> bool {
+    self.is_normal_in(&G::elements().collect::<Vec<_>>())
+  }
+
+
+  /// The element of the quotient group `G/H` for an element, or
+  /// `None` if the subgroup is not normal.
+  pub fn quotient(&self, g: G) -> Option<QuotientGroup<G>> {
+    QuotientGroup::new(g, self)
+  }
+
+
+  /// Test the property of Lagrange's theorem, i.e. the group order is
+  /// the product of the subgroup order and index.
+  pub fn prop_lagrange(&self) -> bool {
+    G::size() == self.order() * self.index()
+  }
+}
+
+
+impl Subgroup<Permutation> {
+
+  /// The _symmetric_ _group_ `S_n` of all permutations of the points
+  /// `0..n`, listed directly (in lexicographic order of images)
+  /// rather than spanned.
+  pub fn symmetric(n: usize) -> Self {
+    let next = |images: &Vec<usize>| {
+      let i = (1..n).rev().find(|&i| images[i - 1] < images[i])?;
+      let j = (i..n).rev().find(|&j| images[i - 1] < images[j])?;
+      let mut next = images.clone();
+
+      next.swap(i - 1, j);
+      next[i..].reverse();
+
+      Some(next)
+    };
+
+    let images = ::std::iter::successors(Some((0..n).collect()), next);
+
+    Subgroup { elements: images.map(Permutation::new).collect() }
+  }
+}
+
+
+///
+/// Subgroups are equal when they have the same elements.
+///
+impl<G: Group + Clone> PartialEq for Subgroup<G> {
+  fn eq(&self, other: &Self) -> bool {
+    self.order() == other.order() && self.is_subset(other)
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "subgroup_test.rs"]
+mod subgroup_test;
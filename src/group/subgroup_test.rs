@@ -0,0 +1,107 @@
+use prelude::*;
+use tests::prelude::*;
+use proptest::prelude::*;
+
+
+prop_compose! {
+
+  /// Generate a random list of (up to three) S3 elements.
+  fn s3_gens()(xs in prop::collection::vec(0..6usize, 0..4)) -> Vec<S3> {
+    xs.into_iter().map(S3::new).collect()
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random S3 elements.
+  fn s3_3()(x in 0..6usize, y in 0..6usize, z in 0..6usize) -> Three<S3> {
+    (S3::new(x), S3::new(y), S3::new(z))
+  }
+}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_identity(ref gens in s3_gens()) {
+    Subgroup::span(gens).axiom_identity()
+  }
+
+
+  #[test]
+  fn axiom_closure(ref gens in s3_gens(), (ref x, ref y, _) in s3_3()) {
+    Subgroup::span(gens).axiom_closure((x, y))
+  }
+
+
+  #[test]
+  fn axiom_inverse(ref gens in s3_gens(), (ref x, _, _) in s3_3()) {
+    Subgroup::span(gens).axiom_inverse(x)
+  }
+
+
+  #[test]
+  fn prop_lagrange(ref gens in s3_gens()) {
+    Subgroup::span(gens).prop_lagrange()
+  }
+
+
+  #[test]
+  fn prop_cosets_partition(ref gens in s3_gens()) {
+    let subgroup = Subgroup::span(gens);
+    let cosets = subgroup.left_cosets();
+
+    prop_assert!(cosets.iter().all(|c| c.len() == subgroup.order()));
+    prop_assert_eq!(cosets.len() * subgroup.order(), S3::size());
+  }
+}
+
+
+#[test]
+fn spanned_subgroups() {
+  let alt = Subgroup::span(&[S3::new(3)]);
+  let swap = Subgroup::span(&[S3::new(1)]);
+
+  assert_eq!(Subgroup::<S3>::span(&[]), Subgroup::trivial());
+  assert_eq!(alt.order(), 3);
+  assert_eq!(swap.order(), 2);
+  assert_eq!(alt.join(&swap).order(), 6);
+  assert_eq!(alt, Subgroup::span(&[S3::new(4)]));
+}
+
+
+#[test]
+fn symmetric_groups() {
+  let s4 = Subgroup::symmetric(4);
+  let gens = [Permutation::cycle(&[0, 1]), Permutation::cycle(&[0, 1, 2, 3])];
+
+  assert_eq!(Subgroup::symmetric(0), Subgroup::trivial());
+  assert_eq!(Subgroup::symmetric(5).order(), 120);
+  assert_eq!(s4, Subgroup::span(&gens));
+  assert!(s4.elements()[0].is_id());
+}
+
+
+#[test]
+fn normal_subgroups() {
+  let alt = Subgroup::span(&[S3::new(3)]);
+  let swap = Subgroup::span(&[S3::new(1)]);
+
+  assert!(alt.is_normal());
+  assert!(!swap.is_normal());
+  assert!(Subgroup::<S3>::trivial().is_normal());
+}
+
+
+#[test]
+fn left_and_right_cosets() {
+  let swap = Subgroup::span(&[S3::new(1)]);
+
+  assert_eq!(swap.index(), 3);
+  assert_eq!(swap.left_coset(&S3::new(2)), vec![S3::new(2), S3::new(3)]);
+  assert_eq!(swap.right_coset(&S3::new(2)), vec![S3::new(2), S3::new(4)]);
+  assert_ne!(swap.left_cosets(), swap.right_cosets());
+}
@@ -0,0 +1,77 @@
+//!
+//! The complex _conjugation_ homomorphism.
+//!
+//! Complex conjugation `a + bi -> a - bi` is a ring _automorphism_
+//! of the complex numbers, i.e. a ring homomorphism from the complex
+//! numbers to themselves, and its own inverse. Conjugation fixes
+//! exactly the real numbers.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of complex conjugation.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use complex::complex::*;
+use homomorphism::magma_hom::*;
+use homomorphism::ring_hom::*;
+use homomorphism::group_hom::*;
+use std::marker::PhantomData;
+
+
+///
+/// The complex conjugation map.
+///
+#[derive(Debug)]
+pub struct Conjugation<T> {
+  marker: PhantomData<T>,
+}
+
+
+impl<T> Conjugation<T> {
+
+  /// The complex conjugation map.
+  pub fn new() -> Self {
+    Conjugation { marker: PhantomData }
+  }
+}
+
+
+impl<T> Default for Conjugation<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+
+///
+/// Conjugation maps complex numbers to complex numbers.
+///
+impl<T: Real> Hom for Conjugation<T> {
+  type Domain = Complex<T>;
+  type Codomain = Complex<T>;
+
+
+  /// Conjugation negates the imaginary component.
+  fn apply(&self, z: &Complex<T>) -> Complex<T> {
+    z.conj()
+  }
+}
+
+
+///
+/// Conjugation forms a numeric additive group homomorphism.
+///
+impl<T: Real> NumAddGroupHom for Conjugation<T> {}
+
+
+///
+/// Conjugation forms a numeric ring homomorphism.
+///
+impl<T: Real> NumRingHom for Conjugation<T> {}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "conjugation_test.rs"]
+mod conjugation_test;
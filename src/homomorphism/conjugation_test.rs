@@ -0,0 +1,61 @@
+use prelude::*;
+use tests::prelude::*;
+
+
+prop_compose! {
+
+  /// Generate one random complex number with (bounded) f64
+  /// components.
+  fn c64_1()(x in -1e6..1e6, y in -1e6..1e6) -> Complex<f64> {
+    Complex::new(x, y)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate two random complex numbers with f64 components.
+  fn c64_2()(xs in (c64_1(), c64_1())) -> Two<Complex<f64>> {
+    xs
+  }
+}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_preserve_add_c64((ref w, ref z) in c64_2()) {
+    NumAddGroupHom::axiom_preserve_add(&Conjugation::new(), (w, z), &F64_EPS)
+  }
+
+
+  #[test]
+  fn axiom_preserve_negate_c64(ref z in c64_1()) {
+    NumAddGroupHom::axiom_preserve_negate(&Conjugation::new(), z, &F64_EPS)
+  }
+
+
+  #[test]
+  fn axiom_preserve_mul_c64((ref w, ref z) in c64_2()) {
+    NumRingHom::axiom_preserve_mul(&Conjugation::new(), (w, z), &F64_EPS)
+  }
+
+
+  #[test]
+  fn prop_conjugation_involution(ref z in c64_1()) {
+    let conj = Conjugation::new();
+
+    prop_assert!(conj.apply(&conj.apply(z)) == *z);
+  }
+}
+
+
+#[test]
+fn axiom_preserve_identities() {
+  let (c64, c32) = (Conjugation::<f64>::new(), Conjugation::<f32>::new());
+
+  assert!(NumAddGroupHom::axiom_preserve_zero(&c64, &F64_EPS));
+  assert!(NumRingHom::axiom_preserve_one(&c32, &F32_EPS));
+}
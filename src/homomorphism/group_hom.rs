@@ -0,0 +1,165 @@
+//!
+//! Algebraic _group homomorphism_ traits.
+//!
+//! A _group homomorphism_ is a map `f: G -> H` between two groups
+//! that preserves the group operation `·`. Group homomorphisms then
+//! also preserve the group identity and inverses.
+//!
+//! The _kernel_ of a group homomorphism is the set of elements of
+//! `G` mapped to the identity of `H`. The kernel is a _normal_
+//! subgroup of `G`, and is trivial exactly when `f` is injective.
+//! For a _finite_ domain `G` the kernel can be computed by applying
+//! `f` to each element of `G`.
+//!
+//! The _first_ _isomorphism_ _theorem_ states that the quotient group
+//! `G/ker(f)` is isomorphic to the image of `f`.
+//!
+//! # Axioms
+//!
+//! 1. Operation: ∀x, y ∈ G, f(x·y) = f(x)·f(y).
+//! 2. Identity: f(e) = e.
+//! 3. Inverse: ∀x ∈ G, f(x^-1) = f(x)^-1.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a group
+//! homomorphism.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// An algebraic _group homomorphism_.
+///
+pub trait GroupHom: MagmaHom<Domain: Group, Codomain: Group> {
+
+  /// The kernel of a (finite) list of domain elements.
+  fn kernel(&self, xs: &[Self::Domain]) -> Vec<Self::Domain>
+    where Self::Domain: Clone {
+    xs.iter().filter(|x| self.apply(x).is_id()).cloned().collect()
+  }
+
+
+  /// Test the axiom of identity preservation.
+  fn axiom_preserve_identity(&self) -> bool {
+    self.apply(&Self::Domain::id()).is_id()
+  }
+
+
+  /// Test the axiom of inverse preservation.
+  fn axiom_preserve_inverse(&self, x: &Self::Domain) -> bool {
+    self.apply(&x.inverse()) == self.apply(x).inverse()
+  }
+
+
+  /// Test the property of the _first_ _isomorphism_ _theorem_ for a
+  /// finite domain, i.e. elements have equal images exactly when
+  /// they are in the same coset of the kernel, and the kernel cosets
+  /// correspond to the image elements.
+  fn prop_first_isomorphism(&self, xs: Pair<Self::Domain>) -> bool
+    where Self::Domain: Clone + Finite {
+    let (x, y) = xs;
+
+    let domain: Vec<Self::Domain> = Self::Domain::elements().collect();
+    let kernel = Subgroup::span(&self.kernel(&domain));
+
+    let cosets = kernel.index() == self.image(&domain).len();
+    let equal = self.apply(x) == self.apply(y);
+    let images = equal == kernel.same_left_coset(x, y);
+
+    cosets && images
+  }
+}
+
+
+///
+/// An algebraic _additive group homomorphism_.
+///
+pub trait AddGroupHom: Hom<Domain: AddGroup, Codomain: AddGroup> {
+
+  /// The kernel of a (finite) list of domain elements.
+  fn kernel(&self, xs: &[Self::Domain]) -> Vec<Self::Domain>
+    where Self::Domain: Clone {
+    xs.iter().filter(|x| self.apply(x).is_zero()).cloned().collect()
+  }
+
+
+  /// Test the axiom of addition preservation.
+  fn axiom_preserve_add(&self, xs: Pair<Self::Domain>) -> bool {
+    let (x, y) = xs;
+
+    self.apply(&x.add(y)) == self.apply(x).add(&self.apply(y))
+  }
+
+
+  /// Test the axiom of zero preservation.
+  fn axiom_preserve_zero(&self) -> bool {
+    self.apply(&Self::Domain::zero()).is_zero()
+  }
+
+
+  /// Test the axiom of negation preservation.
+  fn axiom_preserve_negate(&self, x: &Self::Domain) -> bool {
+    self.apply(&x.negate()) == self.apply(x).negate()
+  }
+}
+
+
+///
+/// A "numeric" algebraic _additive group homomorphism_.
+///
+/// `NumAddGroupHom` trait is for maps that only preserve additive
+/// group operations when "numeric" comparisons are used, e.g. maps
+/// between floating point types.
+///
+pub trait NumAddGroupHom:
+  Hom<Domain: NumAddGroup, Codomain: NumAddGroup> {
+
+  /// Numerically test the axiom of addition preservation.
+  fn axiom_preserve_add(&self, xs: Pair<Self::Domain>,
+                        eps: &<Self::Codomain as NumEq>::Error) -> bool {
+    let (x, y) = xs;
+
+    self.apply(&x.add(y)).num_eq(&self.apply(x).add(&self.apply(y)), eps)
+  }
+
+
+  /// Numerically test the axiom of zero preservation.
+  fn axiom_preserve_zero(&self,
+                         eps: &<Self::Codomain as NumEq>::Error) -> bool {
+    self.apply(&Self::Domain::zero()).num_eq(&Self::Codomain::zero(), eps)
+  }
+
+
+  /// Numerically test the axiom of negation preservation.
+  fn axiom_preserve_negate(&self, x: &Self::Domain,
+                           eps: &<Self::Codomain as NumEq>::Error) -> bool {
+    self.apply(&x.negate()).num_eq(&self.apply(x).negate(), eps)
+  }
+}
+
+
+///
+/// The identity map forms a group homomorphism.
+///
+impl<T: Clone + Group> GroupHom for IdentityHom<T> {}
+
+
+///
+/// The identity map forms an additive group homomorphism.
+///
+impl<T: Clone + AddGroup> AddGroupHom for IdentityHom<T> {}
+
+
+///
+/// The identity map forms a numeric additive group homomorphism.
+///
+impl<T: Clone + NumAddGroup> NumAddGroupHom for IdentityHom<T> {}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "group_hom_test.rs"]
+mod group_hom_test;
@@ -0,0 +1,124 @@
+use prelude::*;
+use tests::prelude::*;
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_preserve_add_i32((ref x, ref y) in i32_2()) {
+    AddGroupHom::axiom_preserve_add(&IdentityHom::new(), (x, y))
+  }
+
+
+  #[test]
+  fn axiom_preserve_negate_i64(ref x in i64_1()) {
+    AddGroupHom::axiom_preserve_negate(&IdentityHom::new(), x)
+  }
+
+
+  #[test]
+  fn axiom_preserve_add_f64((ref x, ref y) in f64_2()) {
+    NumAddGroupHom::axiom_preserve_add(&IdentityHom::new(), (x, y), &F64_EPS)
+  }
+
+
+  #[test]
+  fn axiom_preserve_negate_f32(ref x in f32_1()) {
+    NumAddGroupHom::axiom_preserve_negate(&IdentityHom::new(), x, &F32_EPS)
+  }
+}
+
+
+#[test]
+fn axiom_preserve_zero() {
+  let (i16s, f64s) = (IdentityHom::<i16>::new(), IdentityHom::<f64>::new());
+
+  assert!(AddGroupHom::axiom_preserve_zero(&i16s));
+  assert!(NumAddGroupHom::axiom_preserve_zero(&f64s, &F64_EPS));
+}
+
+
+#[test]
+fn identity_kernel_trivial() {
+  let xs: Vec<i8> = (-128..=127).collect();
+
+  assert_eq!(AddGroupHom::kernel(&IdentityHom::new(), &xs), vec![0]);
+}
+
+
+/// Addition modulo 2.
+struct Z2;
+
+impl CayleyTable<2> for Z2 {
+  type Kind = ComGroups;
+
+  const OP: Table<2> = [[0, 1], [1, 0]];
+}
+
+
+type C2 = Cayley<Z2, 2>;
+
+
+/// The _sign_ of a permutation (0 for even, 1 for odd).
+struct Sign;
+
+impl Hom for Sign {
+  type Domain = S3;
+  type Codomain = C2;
+
+  fn apply(&self, x: &S3) -> C2 {
+    C2::new([0, 1, 1, 0, 0, 1][x.index()])
+  }
+}
+
+impl MagmaHom for Sign {}
+
+impl GroupHom for Sign {}
+
+
+prop_compose! {
+
+  /// Generate two random S3 elements.
+  fn s3_2()(x in 0..6usize, y in 0..6usize) -> Two<S3> {
+    (S3::new(x), S3::new(y))
+  }
+}
+
+
+proptest! {
+  #![proptest_config(config_with(1_000, 500))]
+
+
+  #[test]
+  fn axiom_preserve_op_sign((ref x, ref y) in s3_2()) {
+    MagmaHom::axiom_preserve_op(&Sign, (x, y))
+  }
+
+
+  #[test]
+  fn axiom_preserve_inverse_sign((ref x, _) in s3_2()) {
+    GroupHom::axiom_preserve_inverse(&Sign, x)
+  }
+
+
+  #[test]
+  fn prop_first_isomorphism_sign((ref x, ref y) in s3_2()) {
+    GroupHom::prop_first_isomorphism(&Sign, (x, y))
+  }
+}
+
+
+#[test]
+fn sign_kernel() {
+  let xs: Vec<S3> = S3::elements().collect();
+
+  let alt = vec![S3::new(0), S3::new(3), S3::new(4)];
+
+  assert_eq!(GroupHom::kernel(&Sign, &xs), alt);
+  let id = IdentityHom::new();
+
+  assert!(GroupHom::prop_first_isomorphism(&Sign, (&xs[1], &xs[2])));
+  assert!(GroupHom::prop_first_isomorphism(&id, (&xs[1], &xs[2])));
+}
@@ -0,0 +1,126 @@
+//!
+//! Algebraic _magma homomorphism_ traits.
+//!
+//! A _homomorphism_ is a map `f: S -> T` between two algebraic
+//! structures of the same kind that _preserves_ their operations. A
+//! _magma homomorphism_ preserves the magma binary operation `·`.
+//!
+//! The _image_ of a homomorphism is the set of values `f(x)` in `T`.
+//! For a _finite_ domain `S` the image can be computed by applying
+//! `f` to each element of `S`.
+//!
+//! # Axioms
+//!
+//! 1. Operation: ∀x, y ∈ S, f(x·y) = f(x)·f(y).
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a homomorphism.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use types::*;
+use magma::magma::*;
+use std::marker::PhantomData;
+
+
+///
+/// A map between two algebraic structures.
+///
+pub trait Hom {
+
+  /// The domain (source) of the map.
+  type Domain;
+
+
+  /// The codomain (target) of the map.
+  type Codomain: PartialEq;
+
+
+  /// Apply the map to a domain element.
+  fn apply(&self, x: &Self::Domain) -> Self::Codomain;
+
+
+  /// The image of a (finite) list of domain elements, without
+  /// duplicates.
+  fn image(&self, xs: &[Self::Domain]) -> Vec<Self::Codomain> {
+    let mut image = Vec::new();
+
+    for y in xs.iter().map(|x| self.apply(x)) {
+      if !image.contains(&y) {
+        image.push(y);
+      }
+    }
+
+    image
+  }
+}
+
+
+///
+/// An algebraic _magma homomorphism_.
+///
+pub trait MagmaHom: Hom<Domain: Magma, Codomain: Magma> {
+
+  /// Test the axiom of operation preservation.
+  fn axiom_preserve_op(&self, xs: Pair<Self::Domain>) -> bool {
+    let (x, y) = xs;
+
+    self.apply(&x.op(y)) == self.apply(x).op(&self.apply(y))
+  }
+}
+
+
+///
+/// The _identity_ map on a type.
+///
+/// The identity map is a homomorphism of every structure its type
+/// forms, with a trivial kernel and the whole type as its image.
+///
+#[derive(Debug)]
+pub struct IdentityHom<T> {
+  marker: PhantomData<T>,
+}
+
+
+impl<T> IdentityHom<T> {
+
+  /// The identity map.
+  pub fn new() -> Self {
+    IdentityHom { marker: PhantomData }
+  }
+}
+
+
+impl<T> Default for IdentityHom<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+
+///
+/// The identity is a map from a type to itself.
+///
+impl<T: Clone + PartialEq> Hom for IdentityHom<T> {
+  type Domain = T;
+  type Codomain = T;
+
+
+  /// Identity maps values to themselves.
+  fn apply(&self, x: &T) -> T {
+    x.clone()
+  }
+}
+
+
+///
+/// The identity map forms a magma homomorphism.
+///
+impl<T: Clone + Magma> MagmaHom for IdentityHom<T> {}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "magma_hom_test.rs"]
+mod magma_hom_test;
@@ -0,0 +1,24 @@
+use prelude::*;
+use tests::prelude::*;
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn prop_identity_image(ref xs in proptest::collection::vec(i8_1(), 0..50)) {
+    let image = IdentityHom::new().image(xs);
+
+    prop_assert!(xs.iter().all(|x| image.contains(x)));
+    prop_assert!(image.iter().all(|y| xs.contains(y)));
+  }
+}
+
+
+#[test]
+fn identity_image_no_duplicates() {
+  let xs: Vec<i8> = (-128..=127).chain(-128..=127).collect();
+
+  assert_eq!(IdentityHom::new().image(&xs).len(), 256);
+}
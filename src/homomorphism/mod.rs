@@ -0,0 +1,21 @@
+//!
+//! Algebraic _homomorphism_ traits and implementations.
+//!
+//! The `homomorphism` module provides support for structure
+//! preserving maps between algebraic structures, i.e. _magma_,
+//! _group_ and _ring_ _homomorphisms_, including their "numeric"
+//! counterparts.
+//!
+//! It also provides some standard homomorphisms: the _identity_ map,
+//! _reduction_ into a quotient ring (e.g. `i64 -> Z/nZ`), and
+//! complex _conjugation_.
+//!
+pub mod magma_hom;
+
+pub mod group_hom;
+
+pub mod ring_hom;
+
+pub mod reduction;
+
+pub mod conjugation;
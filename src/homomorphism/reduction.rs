@@ -0,0 +1,82 @@
+//!
+//! The _reduction_ homomorphism into a quotient ring.
+//!
+//! Every ideal `I` of a ring `R` gives a _reduction_ (or _canonical
+//! projection_) map `R -> R/I`, sending each element to its coset.
+//! Reduction is a surjective ring homomorphism with kernel `I`, for
+//! example reduction modulo `n` from `i64` to `Z/nZ`.
+//!
+//! Rust's built-in integer types only satisfy the ring axioms with
+//! "wrapping" arithmetic (i.e. modulo `2^k`), so reduction of integer
+//! values is only a homomorphism when their arithmetic doesn't
+//! overflow.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a quotient map.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use ideal::ideal::*;
+use ideal::quotient::*;
+use homomorphism::magma_hom::*;
+use homomorphism::ring_hom::*;
+use homomorphism::group_hom::*;
+use euclid_domain::euclid_domain::*;
+
+
+///
+/// The reduction map of a Euclidean domain into a quotient ring.
+///
+#[derive(Clone, Debug)]
+pub struct Reduction<R> {
+  ideal: Ideal<R>,
+}
+
+
+impl<R: EuclidDomain> Reduction<R> {
+
+  /// The reduction map modulo an ideal.
+  pub fn new(ideal: Ideal<R>) -> Self {
+    Reduction { ideal }
+  }
+
+
+  /// The ideal (and kernel) of the reduction map.
+  pub fn ideal(&self) -> &Ideal<R> {
+    &self.ideal
+  }
+}
+
+
+///
+/// Reduction maps a ring into a quotient ring.
+///
+impl<R: EuclidDomain> Hom for Reduction<R> {
+  type Domain = R;
+  type Codomain = Quotient<R>;
+
+
+  /// Reduction maps an element to its coset.
+  fn apply(&self, x: &R) -> Quotient<R> {
+    self.ideal.quotient(x.clone())
+  }
+}
+
+
+///
+/// Reduction forms an additive group homomorphism.
+///
+impl<R: EuclidDomain> AddGroupHom for Reduction<R> {}
+
+
+///
+/// Reduction forms a ring homomorphism.
+///
+impl<R: EuclidDomain> RingHom for Reduction<R> {}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "reduction_test.rs"]
+mod reduction_test;
@@ -0,0 +1,85 @@
+use prelude::*;
+use tests::prelude::*;
+
+
+/// Reduction modulo 12, from the integers to Z/12Z.
+fn mod12() -> Reduction<i64> {
+  Reduction::new(Ideal::new(12))
+}
+
+
+/// Reduction from GF(2)\[x\] to GF(4) = GF(2)\[x\]/(x²+x+1).
+fn gf4() -> Reduction<Polynomial<QuotientField<i64>>> {
+  let one = Ideal::new(2).field(1).unwrap();
+  let f = Polynomial::new(vec![one.clone(), one.clone(), one]);
+
+  Reduction::new(Ideal::new(f))
+}
+
+
+prop_compose! {
+
+  /// Generate one random polynomial of degree < 6 over GF(2).
+  fn p2_1()(xs in proptest::collection::vec(0..2i64, 0..6))
+    -> Polynomial<QuotientField<i64>> {
+    let two = Ideal::new(2);
+
+    Polynomial::new(xs.into_iter().map(|x| two.field(x).unwrap()).collect())
+  }
+}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_preserve_add_i64((ref x, ref y) in i32_2()) {
+    AddGroupHom::axiom_preserve_add(&mod12(), (&(*x as i64), &(*y as i64)))
+  }
+
+
+  // Negation of i64::MIN wraps, so isn't a ring negation.
+  #[test]
+  fn axiom_preserve_negate_i64(ref x in i64::MIN + 1..i64::MAX) {
+    AddGroupHom::axiom_preserve_negate(&mod12(), x)
+  }
+
+
+  #[test]
+  fn axiom_preserve_mul_i64((ref x, ref y) in i32_2()) {
+    RingHom::axiom_preserve_mul(&mod12(), (&(*x as i64), &(*y as i64)))
+  }
+
+
+  #[test]
+  fn axiom_preserve_mul_gf4(ref p in p2_1(), ref q in p2_1()) {
+    RingHom::axiom_preserve_mul(&gf4(), (p, q))
+  }
+}
+
+
+#[test]
+fn axiom_preserve_identities() {
+  assert!(AddGroupHom::axiom_preserve_zero(&mod12()));
+  assert!(RingHom::axiom_preserve_one(&mod12()));
+  assert!(RingHom::axiom_preserve_one(&gf4()));
+}
+
+
+#[test]
+fn reduction_kernel_is_ideal() {
+  let xs: Vec<i64> = (-60..60).collect();
+  let kernel = AddGroupHom::kernel(&mod12(), &xs);
+
+  assert_eq!(kernel, vec![-60, -48, -36, -24, -12, 0, 12, 24, 36, 48]);
+  assert!(kernel.iter().all(|x| mod12().ideal().contains(x)));
+}
+
+
+#[test]
+fn reduction_image_is_quotient() {
+  let xs: Vec<i64> = (-60..60).collect();
+
+  assert_eq!(mod12().image(&xs).len(), 12);
+}
@@ -0,0 +1,90 @@
+//!
+//! Algebraic _ring homomorphism_ traits.
+//!
+//! A _ring homomorphism_ is a map `f: R -> S` between two rings that
+//! preserves ring addition `+`, multiplication `*` and the
+//! multiplicative identity `1`. A ring homomorphism is therefore
+//! also an _additive_ _group_ homomorphism.
+//!
+//! The _kernel_ of a ring homomorphism is the set of elements of `R`
+//! mapped to the zero of `S`. The kernel is an _ideal_ of `R`, and
+//! the image of `f` is isomorphic to the quotient of `R` by the
+//! kernel.
+//!
+//! # Axioms
+//!
+//! 1. Addition: ∀x, y ∈ R, f(x+y) = f(x)+f(y).
+//! 2. Multiplication: ∀x, y ∈ R, f(x\*y) = f(x)\*f(y).
+//! 3. Identity: f(1) = 1.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a ring homomorphism.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// An algebraic _ring homomorphism_.
+///
+pub trait RingHom: AddGroupHom<Domain: Ring, Codomain: Ring> {
+
+  /// Test the axiom of multiplication preservation.
+  fn axiom_preserve_mul(&self, xs: Pair<Self::Domain>) -> bool {
+    let (x, y) = xs;
+
+    self.apply(&x.mul(y)) == self.apply(x).mul(&self.apply(y))
+  }
+
+
+  /// Test the axiom of one preservation.
+  fn axiom_preserve_one(&self) -> bool {
+    self.apply(&Self::Domain::one()).is_one()
+  }
+}
+
+
+///
+/// A "numeric" algebraic _ring homomorphism_.
+///
+/// `NumRingHom` trait is for maps that only preserve ring operations
+/// when "numeric" comparisons are used, e.g. maps between floating
+/// point types.
+///
+pub trait NumRingHom: NumAddGroupHom<Domain: NumRing, Codomain: NumRing> {
+
+  /// Numerically test the axiom of multiplication preservation.
+  fn axiom_preserve_mul(&self, xs: Pair<Self::Domain>,
+                        eps: &<Self::Codomain as NumEq>::Error) -> bool {
+    let (x, y) = xs;
+
+    self.apply(&x.mul(y)).num_eq(&self.apply(x).mul(&self.apply(y)), eps)
+  }
+
+
+  /// Numerically test the axiom of one preservation.
+  fn axiom_preserve_one(&self,
+                        eps: &<Self::Codomain as NumEq>::Error) -> bool {
+    self.apply(&Self::Domain::one()).num_eq(&Self::Codomain::one(), eps)
+  }
+}
+
+
+///
+/// The identity map forms a ring homomorphism.
+///
+impl<T: Clone + Ring> RingHom for IdentityHom<T> {}
+
+
+///
+/// The identity map forms a numeric ring homomorphism.
+///
+impl<T: Clone + NumRing> NumRingHom for IdentityHom<T> {}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "ring_hom_test.rs"]
+mod ring_hom_test;
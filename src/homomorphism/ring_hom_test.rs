@@ -0,0 +1,26 @@
+use prelude::*;
+use tests::prelude::*;
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_preserve_mul_i16((ref x, ref y) in i16_2()) {
+    RingHom::axiom_preserve_mul(&IdentityHom::new(), (x, y))
+  }
+
+
+  #[test]
+  fn axiom_preserve_mul_f32((ref x, ref y) in f32_2()) {
+    NumRingHom::axiom_preserve_mul(&IdentityHom::new(), (x, y), &F32_EPS)
+  }
+}
+
+
+#[test]
+fn axiom_preserve_one() {
+  assert!(RingHom::axiom_preserve_one(&IdentityHom::<i64>::new()));
+  assert!(NumRingHom::axiom_preserve_one(&IdentityHom::<f32>::new(), &F32_EPS));
+}
@@ -0,0 +1,181 @@
+//!
+//! Algebraic _principal ideals_ of Euclidean domains.
+//!
+//! An algebraic _ideal_ `I` of a commutative ring `R` is an _additive_
+//! _subgroup_ of `R` that _absorbs_ multiplication by any ring
+//! element. Every ideal of a _Euclidean_ _domain_ is _principal_, i.e.
+//! it is the set `(g) = {r*g | r ∈ R}` of all multiples of a single
+//! _generator_ `g`.
+//!
+//! Generators are only unique up to _associates_ (e.g. `(3) = (-3)`)
+//! so ideals store the normal form of their generator.
+//!
+//! # Axioms
+//!
+//! 1. Subgroup: ∀x, y ∈ I, x-y ∈ I.
+//! 2. Absorption: ∀x ∈ I, r ∈ R, r\*x ∈ I.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of an ideal.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use types::*;
+use logic::*;
+use ideal::quotient::*;
+use euclid_domain::euclid_domain::*;
+
+
+///
+/// A principal ideal of a Euclidean domain.
+///
+#[derive(Clone, Debug)]
+pub struct Ideal<R> {
+  gen: R,
+}
+
+
+impl<R: EuclidDomain> Ideal<R> {
+
+  /// The principal ideal generated by an element.
+  pub fn new(gen: R) -> Self {
+    Ideal { gen: gen.normal() }
+  }
+
+
+  /// The (normalized) generator of the ideal.
+  pub fn generator(&self) -> &R {
+    &self.gen
+  }
+
+
+  /// Test for ideal membership, i.e. divisibility by the generator.
+  pub fn contains(&self, x: &R) -> bool {
+    self.gen.divides(x)
+  }
+
+
+  /// Test if this ideal is a subset of another ideal.
+  pub fn is_subset(&self, other: &Self) -> bool {
+    other.contains(&self.gen)
+  }
+
+
+  /// Test for a _proper_ ideal, i.e. one that is not the whole ring.
+  pub fn is_proper(&self) -> bool {
+    !self.gen.is_unit()
+  }
+
+
+  /// The sum `I + J` of two ideals, generated by the greatest common
+  /// divisor of their generators.
+  pub fn sum(&self, other: &Self) -> Self {
+    Self::new(self.gen.gcd(&other.gen))
+  }
+
+
+  /// The product `IJ` of two ideals, generated by the product of
+  /// their generators.
+  pub fn product(&self, other: &Self) -> Self {
+    Self::new(self.gen.mul(&other.gen))
+  }
+
+
+  /// The intersection `I ∩ J` of two ideals, generated by the least
+  /// common multiple of their generators.
+  pub fn intersection(&self, other: &Self) -> Self {
+    Self::new(self.gen.lcm(&other.gen))
+  }
+
+
+  /// The canonical representative of an element's _coset_ `x + I`.
+  pub fn reduce(&self, x: &R) -> R {
+    if self.gen.is_zero() {
+      x.clone()
+    }
+    else {
+      x.rem(&self.gen)
+    }
+  }
+
+
+  /// The canonical representative of the coset of a sum, without
+  /// intermediate overflow for integers.
+  pub fn reduce_sum(&self, x: &R, y: &R) -> R {
+    if self.gen.is_zero() {
+      x.add(y)
+    }
+    else {
+      x.add_rem(y, &self.gen)
+    }
+  }
+
+
+  /// The canonical representative of the coset of a product, without
+  /// intermediate overflow for integers.
+  pub fn reduce_product(&self, x: &R, y: &R) -> R {
+    if self.gen.is_zero() {
+      x.mul(y)
+    }
+    else {
+      x.mul_rem(y, &self.gen)
+    }
+  }
+
+
+  /// The coset `x + I` as an element of the quotient ring `R/I`.
+  pub fn quotient(&self, x: R) -> Quotient<R> {
+    Quotient::new(x, self)
+  }
+
+
+  /// Test the ideal axiom of subgroup closure.
+  pub fn axiom_subgroup(&self, xs: Pair<R>) -> bool {
+    let (x, y) = xs;
+
+    implies(self.contains(x) && self.contains(y), self.contains(&x.sub(y)))
+  }
+
+
+  /// Test the ideal axiom of multiplicative absorption.
+  pub fn axiom_absorption(&self, xs: Pair<R>) -> bool {
+    let (x, r) = xs;
+
+    implies(self.contains(x), self.contains(&r.mul(x)))
+  }
+}
+
+
+impl<R: Irreducible> Ideal<R> {
+
+  /// Test for a _maximal_ ideal, i.e. one generated by an
+  /// irreducible element. The quotient by a maximal ideal is a
+  /// field.
+  pub fn is_maximal(&self) -> bool {
+    self.gen.is_irreducible()
+  }
+
+
+  /// The coset `x + I` as an element of the quotient field `R/I`, or
+  /// `None` if the ideal is not maximal.
+  pub fn field(&self, x: R) -> Option<QuotientField<R>> {
+    QuotientField::new(x, self)
+  }
+}
+
+
+///
+/// Ideals are equal when their normalized generators are equal.
+///
+impl<R: EuclidDomain> PartialEq for Ideal<R> {
+  fn eq(&self, other: &Self) -> bool {
+    self.gen == other.gen
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "ideal_test.rs"]
+mod ideal_test;
@@ -0,0 +1,72 @@
+use prelude::*;
+use tests::prelude::*;
+
+
+prop_compose! {
+
+  /// Generate one random (small generator) integer ideal.
+  fn ideal_1()(n in -1000..1000i64) -> Ideal<i64> {
+    Ideal::new(n)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate two random integer ideals.
+  fn ideal_2()(is in (ideal_1(), ideal_1())) -> Two<Ideal<i64>> {
+    is
+  }
+}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_subgroup(ref i in ideal_1(), (ref x, ref y) in i16_2()) {
+    let (x, y) = (*x as i64, *y as i64);
+
+    i.axiom_subgroup((&i.generator().mul(&x), &i.generator().mul(&y)))
+  }
+
+
+  #[test]
+  fn axiom_absorption(ref i in ideal_1(), (ref x, ref r) in i16_2()) {
+    let (x, r) = (*x as i64, *r as i64);
+
+    i.axiom_absorption((&i.generator().mul(&x), &r))
+  }
+
+
+  #[test]
+  fn prop_sum_contains((ref i, ref j) in ideal_2()) {
+    let s = i.sum(j);
+
+    prop_assert!(i.is_subset(&s) && j.is_subset(&s));
+  }
+
+
+  #[test]
+  fn prop_intersection_contained((ref i, ref j) in ideal_2()) {
+    let k = i.intersection(j);
+
+    prop_assert!(k.is_subset(i) && k.is_subset(j));
+  }
+
+
+  #[test]
+  fn prop_product_contained((ref i, ref j) in ideal_2()) {
+    i.product(j).is_subset(&i.intersection(j))
+  }
+
+
+  #[test]
+  fn prop_maximal_field(ref n in 2..500i64) {
+    let i = Ideal::new(*n);
+    let units = (1..*n).all(|x| i.quotient(x).is_unit());
+
+    prop_assert_eq!(i.is_maximal(), units);
+  }
+}
@@ -0,0 +1,11 @@
+//!
+//! Algebraic _ideal_ and _quotient ring_ types.
+//!
+//! The `ideal` module provides _principal ideals_ of _Euclidean
+//! domains_ and the _quotient rings_ they generate, for example the
+//! integers modulo `n` (`Z/nZ`), or polynomials modulo an
+//! irreducible polynomial (`GF(p)[x]/(f)`).
+//!
+pub mod ideal;
+
+pub mod quotient;
@@ -0,0 +1,428 @@
+//!
+//! Algebraic trait implementations for _quotient rings_.
+//!
+//! The _quotient_ _ring_ `R/I` of a commutative ring `R` by an ideal
+//! `I` is the set of _cosets_ `x + I`, with addition and
+//! multiplication of coset representatives. Quotients of the
+//! integers give the modular integers `Z/nZ`, and quotients of
+//! polynomial rings give the finite fields `GF(p)[x]/(f)`.
+//!
+//! A quotient ring is a _field_ exactly when its ideal is _maximal_,
+//! i.e. when the ideal generator is prime or irreducible. Quotient
+//! fields are the separate `QuotientField` type, which can only be
+//! constructed from a maximal ideal (see [`Ideal::is_maximal`]).
+//!
+//! # Identities
+//!
+//! `un_algebra` structure identities (e.g. `zero()` or `one()`) have
+//! no way of knowing the ideal of the quotient they belong to. The
+//! identity cosets `0 + I` and `1 + I` exist in every quotient ring,
+//! so the identities here have no ideal, and adopt the ideal of any
+//! element they are combined with. Combining elements of quotient
+//! rings by _different_ ideals panics.
+//!
+//! [`Ideal::is_maximal`]: crate::ideal::ideal::Ideal::is_maximal
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a quotient ring.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// An element (coset) of the quotient of a Euclidean domain by a
+/// principal ideal.
+///
+#[derive(Clone, Debug)]
+pub struct Quotient<R> {
+  value: R,
+  ideal: Option<Ideal<R>>,
+}
+
+
+impl<R: EuclidDomain> Quotient<R> {
+
+  /// The coset `x + I` of an element and an ideal.
+  pub fn new(x: R, ideal: &Ideal<R>) -> Self {
+    Self::with(x, Some(ideal.clone()))
+  }
+
+
+  /// The canonical representative of the coset.
+  pub fn value(&self) -> &R {
+    &self.value
+  }
+
+
+  /// The ideal of the quotient ring, or `None` for an identity
+  /// (which belongs to every quotient ring).
+  pub fn ideal(&self) -> Option<&Ideal<R>> {
+    self.ideal.as_ref()
+  }
+
+
+  /// Test for a _unit_, i.e. an element coprime to the ideal
+  /// generator.
+  pub fn is_unit(&self) -> bool {
+    self.value.gcd(&self.generator()).is_unit()
+  }
+
+
+  /// The multiplicative inverse of a unit (using the extended
+  /// Euclidean algorithm), or `None` for a non-unit.
+  pub fn inverse(&self) -> Option<Self> {
+    let (d, s, _) = self.value.ext_gcd(&self.generator());
+
+    if d.is_unit() {
+      Some(Self::with(s, self.ideal.clone()))
+    }
+    else {
+      None
+    }
+  }
+
+
+  /// The coset of an element and an (optional) ideal.
+  fn with(x: R, ideal: Option<Ideal<R>>) -> Self {
+    let value = match ideal {
+      Some(ref ideal) => ideal.reduce(&x),
+      None => x,
+    };
+
+    Quotient { value, ideal }
+  }
+
+
+  /// The ideal generator, zero for an identity.
+  fn generator(&self) -> R {
+    self.ideal.as_ref().map_or_else(R::zero, |i| i.generator().clone())
+  }
+
+
+  /// The ideal common to two quotient elements. Panics if the
+  /// elements belong to quotient rings by different ideals.
+  fn common(&self, other: &Self) -> Option<Ideal<R>> {
+    match (&self.ideal, &other.ideal) {
+      (Some(i), Some(j)) => {
+        assert!(i == j, "quotient ring elements from different ideals");
+
+        Some(i.clone())
+      },
+      (Some(i), None) | (None, Some(i)) => Some(i.clone()),
+      (None, None) => None,
+    }
+  }
+}
+
+
+///
+/// Quotient elements are equal when they belong to the same quotient
+/// ring and their representatives are equal.
+///
+impl<R: EuclidDomain> PartialEq for Quotient<R> {
+  fn eq(&self, other: &Self) -> bool {
+    match (&self.ideal, &other.ideal) {
+      (Some(i), Some(j)) => i == j && self.value == other.value,
+      (Some(i), None) => self.value == i.reduce(&other.value),
+      (None, Some(j)) => j.reduce(&self.value) == other.value,
+      (None, None) => self.value == other.value,
+    }
+  }
+}
+
+
+///
+/// Quotient rings form an additive magma.
+///
+impl<R: EuclidDomain> AddMagma for Quotient<R> {
+
+  /// Addition is representative addition, reduced without
+  /// intermediate overflow.
+  fn add(&self, other: &Self) -> Self {
+    let ideal = self.common(other);
+
+    let sum = match ideal {
+      Some(ref i) => i.reduce_sum(&self.value, &other.value),
+      None => self.value.add(&other.value),
+    };
+
+    Self::with(sum, ideal)
+  }
+}
+
+
+///
+/// Quotient rings form an additive semigroup.
+///
+impl<R: EuclidDomain> AddSemigroup for Quotient<R> {}
+
+
+///
+/// Quotient rings form an additive monoid.
+///
+impl<R: EuclidDomain> AddMonoid for Quotient<R> {
+
+  /// Zero is the zero coset of every ideal.
+  fn zero() -> Self {
+    Self::with(R::zero(), None)
+  }
+}
+
+
+///
+/// Quotient rings form an additive group.
+///
+impl<R: EuclidDomain> AddGroup for Quotient<R> {
+
+  /// Negation is representative negation.
+  fn negate(&self) -> Self {
+    Self::with(self.value.negate(), self.ideal.clone())
+  }
+}
+
+
+///
+/// Quotient rings form an additive commutative group.
+///
+impl<R: EuclidDomain> AddComGroup for Quotient<R> {}
+
+
+///
+/// Quotient rings form a multiplicative magma.
+///
+impl<R: EuclidDomain> MulMagma for Quotient<R> {
+
+  /// Multiplication is representative multiplication, reduced without
+  /// intermediate overflow.
+  fn mul(&self, other: &Self) -> Self {
+    let ideal = self.common(other);
+
+    let product = match ideal {
+      Some(ref i) => i.reduce_product(&self.value, &other.value),
+      None => self.value.mul(&other.value),
+    };
+
+    Self::with(product, ideal)
+  }
+}
+
+
+///
+/// Quotient rings form a multiplicative semigroup.
+///
+impl<R: EuclidDomain> MulSemigroup for Quotient<R> {}
+
+
+///
+/// Quotient rings form a multiplicative monoid.
+///
+impl<R: EuclidDomain> MulMonoid for Quotient<R> {
+
+  /// One is the one coset of every ideal.
+  fn one() -> Self {
+    Self::with(R::one(), None)
+  }
+}
+
+
+///
+/// Quotient rings form a ring.
+///
+impl<R: EuclidDomain> Ring for Quotient<R> {}
+
+
+///
+/// Quotient rings form a commutative ring.
+///
+impl<R: EuclidDomain> ComRing for Quotient<R> {}
+
+
+///
+/// An element of the quotient of a Euclidean domain by a _maximal_
+/// ideal, i.e. of a quotient field.
+///
+#[derive(Clone, Debug)]
+pub struct QuotientField<R>(Quotient<R>);
+
+
+impl<R: Irreducible> QuotientField<R> {
+
+  /// The coset `x + I` of an element and a maximal ideal, or `None`
+  /// if the ideal is not maximal.
+  pub fn new(x: R, ideal: &Ideal<R>) -> Option<Self> {
+    if ideal.is_maximal() {
+      Some(QuotientField(Quotient::new(x, ideal)))
+    }
+    else {
+      None
+    }
+  }
+}
+
+
+impl<R: EuclidDomain> QuotientField<R> {
+
+  /// The canonical representative of the coset.
+  pub fn value(&self) -> &R {
+    self.0.value()
+  }
+
+
+  /// The element as a quotient ring element.
+  pub fn quotient(&self) -> &Quotient<R> {
+    &self.0
+  }
+}
+
+
+///
+/// Quotient field elements are equal when their quotient ring
+/// elements are equal.
+///
+impl<R: EuclidDomain> PartialEq for QuotientField<R> {
+  fn eq(&self, other: &Self) -> bool {
+    self.0 == other.0
+  }
+}
+
+
+///
+/// Quotient fields form an additive magma.
+///
+impl<R: EuclidDomain> AddMagma for QuotientField<R> {
+
+  /// Addition is quotient ring addition.
+  fn add(&self, other: &Self) -> Self {
+    QuotientField(self.0.add(&other.0))
+  }
+}
+
+
+///
+/// Quotient fields form an additive semigroup.
+///
+impl<R: EuclidDomain> AddSemigroup for QuotientField<R> {}
+
+
+///
+/// Quotient fields form an additive monoid.
+///
+impl<R: EuclidDomain> AddMonoid for QuotientField<R> {
+
+  /// Zero is the quotient ring zero.
+  fn zero() -> Self {
+    QuotientField(Quotient::zero())
+  }
+}
+
+
+///
+/// Quotient fields form an additive group.
+///
+impl<R: EuclidDomain> AddGroup for QuotientField<R> {
+
+  /// Negation is quotient ring negation.
+  fn negate(&self) -> Self {
+    QuotientField(self.0.negate())
+  }
+}
+
+
+///
+/// Quotient fields form an additive commutative group.
+///
+impl<R: EuclidDomain> AddComGroup for QuotientField<R> {}
+
+
+///
+/// Quotient fields form a multiplicative magma.
+///
+impl<R: EuclidDomain> MulMagma for QuotientField<R> {
+
+  /// Multiplication is quotient ring multiplication.
+  fn mul(&self, other: &Self) -> Self {
+    QuotientField(self.0.mul(&other.0))
+  }
+}
+
+
+///
+/// Quotient fields form a multiplicative semigroup.
+///
+impl<R: EuclidDomain> MulSemigroup for QuotientField<R> {}
+
+
+///
+/// Quotient fields form a multiplicative monoid.
+///
+impl<R: EuclidDomain> MulMonoid for QuotientField<R> {
+
+  /// One is the quotient ring one.
+  fn one() -> Self {
+    QuotientField(Quotient::one())
+  }
+}
+
+
+///
+/// Non-zero quotient field elements form a multiplicative group.
+///
+impl<R: EuclidDomain> MulGroup for QuotientField<R> {
+
+  /// Inversion uses the extended Euclidean algorithm.
+  fn invert(&self) -> Self {
+    QuotientField(self.0.inverse().expect("inverse of zero"))
+  }
+
+
+  /// Non-zero elements are invertible.
+  fn is_invertible(&self) -> bool {
+    self.0.is_unit()
+  }
+}
+
+
+///
+/// Non-zero quotient field elements form a multiplicative
+/// commutative group.
+///
+impl<R: EuclidDomain> MulComGroup for QuotientField<R> {}
+
+
+///
+/// Quotient fields form a ring.
+///
+impl<R: EuclidDomain> Ring for QuotientField<R> {}
+
+
+///
+/// Quotient fields form a commutative ring.
+///
+impl<R: EuclidDomain> ComRing for QuotientField<R> {}
+
+
+///
+/// Quotient rings by maximal ideals form a field.
+///
+impl<R: EuclidDomain> Field for QuotientField<R> {
+
+  /// Inversion uses the extended Euclidean algorithm.
+  fn invert(&self) -> Self {
+    MulGroup::invert(self)
+  }
+
+
+  /// Non-zero elements are invertible.
+  fn is_invertible(&self) -> bool {
+    MulGroup::is_invertible(self)
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "quotient_test.rs"]
+mod quotient_test;
@@ -0,0 +1,273 @@
+use prelude::*;
+use tests::prelude::*;
+
+
+/// The finite field _GF(2)_ as a Cayley table.
+struct Gf2;
+
+impl RingTable<2> for Gf2 {
+  type Kind = Fields;
+
+  const ADD: Table<2> = [[0, 1], [1, 0]];
+  const MUL: Table<2> = [[0, 0], [0, 1]];
+}
+
+
+/// Polynomials with coefficients in the finite field _GF(2)_.
+type Poly2 = Polynomial<CayleyRing<Gf2, 2>>;
+
+
+/// The finite field _GF(4)_ as the quotient _GF(2)\[x\]/(x²+x+1)_.
+type GF4 = QuotientField<Poly2>;
+
+
+prop_compose! {
+
+  /// Generate one random element of the modular integers Z/12Z.
+  fn z12_1()(x in i64_1()) -> Quotient<i64> {
+    Ideal::new(12).quotient(x)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate two random elements of Z/12Z.
+  fn z12_2()(xs in (z12_1(), z12_1())) -> Two<Quotient<i64>> {
+    xs
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random elements of Z/12Z.
+  fn z12_3()(xs in (z12_1(), z12_1(), z12_1())) -> Three<Quotient<i64>> {
+    xs
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate one random element of the finite field Z/101Z.
+  fn z101_1()(x in i64_1()) -> QuotientField<i64> {
+    Ideal::new(101).field(x).unwrap()
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random elements of Z/101Z.
+  fn z101_3()(xs in (z101_1(), z101_1(), z101_1()))
+    -> Three<QuotientField<i64>> {
+    xs
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate one random element of the finite field GF(4).
+  fn gf4_1()(a in 0..2usize, b in 0..2usize) -> GF4 {
+    let one = CayleyRing::new(1);
+    let f = Polynomial::new(vec![one, one, one]);
+    let x = Polynomial::new(vec![CayleyRing::new(a), CayleyRing::new(b)]);
+
+    Ideal::new(f).field(x).unwrap()
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random elements of GF(4).
+  fn gf4_3()(xs in (gf4_1(), gf4_1(), gf4_1())) -> Three<GF4> {
+    xs
+  }
+}
+
+
+proptest! {
+  #![proptest_config(config_with(1000, 500))]
+
+
+  #[test]
+  fn axiom_add_associativity_z12((ref x, ref y, ref z) in z12_3()) {
+    AddSemigroup::axiom_add_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_mul_associativity_z12((ref x, ref y, ref z) in z12_3()) {
+    MulSemigroup::axiom_mul_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_left_add_identity_z12(ref x in z12_1()) {
+    AddMonoid::axiom_left_add_identity(x)
+  }
+
+
+  #[test]
+  fn axiom_left_negate_z12(ref x in z12_1()) {
+    AddGroup::axiom_left_negate(x)
+  }
+
+
+  #[test]
+  fn axiom_right_negate_z12(ref x in z12_1()) {
+    AddGroup::axiom_right_negate(x)
+  }
+
+
+  #[test]
+  fn axiom_add_commutivity_z12((ref x, ref y) in z12_2()) {
+    AddComGroup::axiom_add_commutivity((x, y))
+  }
+
+
+  #[test]
+  fn axiom_left_distributivity_z12((ref x, ref y, ref z) in z12_3()) {
+    Ring::axiom_left_distributivity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_right_distributivity_z12((ref x, ref y, ref z) in z12_3()) {
+    Ring::axiom_right_distributivity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_mul_commutivity_z12((ref x, ref y) in z12_2()) {
+    ComRing::axiom_mul_commutivity((x, y))
+  }
+
+
+  #[test]
+  fn prop_inverse_z12(ref x in z12_1()) {
+    match x.inverse() {
+      Some(y) => x.is_unit() && x.mul(&y) == Quotient::one(),
+      None => !x.is_unit(),
+    }
+  }
+
+
+  #[test]
+  fn axiom_left_invert_z101(ref x in z101_1()) {
+    prop_assume!(MulGroup::is_invertible(x));
+
+    MulGroup::axiom_left_invert(x)
+  }
+
+
+  #[test]
+  fn axiom_field_left_invert_z101(ref x in z101_1()) {
+    prop_assume!(Field::is_invertible(x));
+
+    Field::axiom_left_invert(x)
+  }
+
+
+  #[test]
+  fn axiom_field_right_invert_z101(ref x in z101_1()) {
+    prop_assume!(Field::is_invertible(x));
+
+    Field::axiom_right_invert(x)
+  }
+
+
+  #[test]
+  fn prop_mul_cancel_z101((ref x, ref y, ref z) in z101_3()) {
+    Field::prop_mul_cancel((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_mul_associativity_gf4((ref x, ref y, ref z) in gf4_3()) {
+    MulSemigroup::axiom_mul_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_left_distributivity_gf4((ref x, ref y, ref z) in gf4_3()) {
+    Ring::axiom_left_distributivity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_field_left_invert_gf4(ref x in gf4_1()) {
+    prop_assume!(Field::is_invertible(x));
+
+    Field::axiom_left_invert(x)
+  }
+
+
+  #[test]
+  fn axiom_field_right_invert_gf4(ref x in gf4_1()) {
+    prop_assume!(Field::is_invertible(x));
+
+    Field::axiom_right_invert(x)
+  }
+
+
+  #[test]
+  fn prop_mul_zero_gf4((ref x, ref y, _) in gf4_3()) {
+    Field::prop_mul_zero((x, y))
+  }
+}
+
+
+#[test]
+fn field_requires_maximal_ideal() {
+  let (zero, one) = (CayleyRing::<Gf2, 2>::new(0), CayleyRing::new(1));
+
+  // x² + 1 = (x + 1)² is reducible over GF(2).
+  let square = Polynomial::new(vec![one, zero, one]);
+
+  assert!(QuotientField::new(5, &Ideal::new(12)).is_none());
+  assert!(QuotientField::new(5, &Ideal::new(13)).is_some());
+  assert!(Ideal::new(square).field(Poly2::x()).is_none());
+}
+
+
+#[test]
+fn identities_adopt_ideal() {
+  let x = Ideal::new(12).quotient(7);
+
+  assert_eq!(x.add(&Quotient::zero()).ideal(), Some(&Ideal::new(12)));
+  assert_eq!(x.mul(&Quotient::one()), x);
+  assert_eq!(Quotient::one(), Ideal::new(12).quotient(13));
+  assert_eq!(Quotient::<i64>::zero().ideal(), None);
+}
+
+
+#[test]
+#[should_panic(expected = "different ideals")]
+fn mixed_ideals_panic() {
+  let _ = Ideal::new(12).quotient(1).add(&Ideal::new(7).quotient(1));
+}
+
+
+#[test]
+fn mixed_ideals_unequal() {
+  assert_ne!(Ideal::new(12).quotient(1), Ideal::new(7).quotient(1));
+}
+
+
+#[test]
+fn large_moduli() {
+  let p = Ideal::new((1i64 << 61) - 1);
+  let m = Ideal::new(i64::MAX);
+  let q = Ideal::new(i128::MAX);
+
+  let (x, y) = (p.quotient(-1), m.quotient(-1));
+  let z = q.quotient(-2);
+
+  assert_eq!(x.mul(&x), p.quotient(1), "(-1)² in Z/(2^61-1)");
+  assert_eq!(y.add(&y), m.quotient(-2), "(-1)+(-1) in Z/(2^63-1)");
+  assert_eq!(z.mul(&z), q.quotient(4), "(-2)² in Z/(2^127-1)");
+}
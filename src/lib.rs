@@ -1,5 +1,3 @@
-#![feature(external_doc)]
-
 //!
 //! Simple implementations of selected abstract algebraic structures.
 //!
@@ -18,8 +16,8 @@
 //! implementation of selected algebraic structures in Rust. I hope
 //! it is useful to developers learning abstract algebra concepts
 //! for the first time. Currently this crate provides _magma_,
-//! _semigroup_, _monoid_, _group_, _ring_ and _field_
-//! implementations.
+//! _semigroup_, _monoid_, _group_, _ring_, _field_ and _Euclidean
+//! domain_ implementations, along with _ideals_ and _quotient rings_.
 //! 
 //! # Production use
 //!
@@ -55,6 +53,7 @@
 //! * `Add` for "additive"
 //! * `Com` for "commutative"
 //! * `Mul` for "multiplicative"
+//! * `Hom` for "homomorphism"
 //!
 //! # Organization
 //!
@@ -70,6 +69,11 @@
 //! defined using the terminology of addition and multiplication
 //! rather than as abstract binary operations.
 //!
+//! Trait methods can be hard to read in longer formulas, so the
+//! [`operator`] module provides the `Alg` wrapper type, implementing
+//! Rust's arithmetic operators (e.g. `+`, `*`) by delegating to the
+//! structure traits.
+//!
 //! # Axioms and properties
 //!
 //! All `un_algebra` structure traits are equipped with predicate
@@ -97,6 +101,10 @@
 //! modules use the boilerplate code approach and in other cases
 //! they rely on a macro to create the `impl` items.
 //!
+//! User defined structs whose fields implement the structure traits
+//! can derive component-wise implementations of them, using the
+//! `un_algebra_derive` companion crate.
+//!
 //! # Unit tests
 //!
 //! Where `un_algebra` traits provide unit tests they are generally
@@ -117,6 +125,11 @@
 //! only satisfy abstract structure axioms with modulo, or
 //! "wrapping" addition and multiplication.
 //!
+//! The [`partial`] module provides _checked_ integer types, whose
+//! arithmetic reports overflow instead of wrapping. These form
+//! _partial_ _rings_, which satisfy the ring axioms wherever their
+//! operations are defined.
+//!
 //! # Floating point types
 //! 
 //! Many application data types that in theory conform to modern
@@ -132,6 +145,14 @@
 //! [`NumAddGroup`] and [`NumMulGroup`]. Using these numeric trait
 //! variants we can at least numerically satisfy the trait axioms.
 //!
+//! Floating point types implement the same structure traits (and so
+//! operations) as exact types, and any structure with numeric
+//! equality is also a numeric structure. Generic code, e.g.
+//! polynomial arithmetic, is written once for both `BigRational` and
+//! `f64`. Axioms are tested up to an _equivalence_ _relation_
+//! ([`numeric::equivalence`]), with exact equality as the zero
+//! tolerance case.
+//!
 //! # Examples
 //!
 //! `un_algebra` implements the relevant structure traits for all
@@ -145,11 +166,54 @@
 //! the conforming traits in the [`complex`] and [`rational`]
 //! modules.
 //!
+//! Polynomials with field coefficients are provided by the
+//! [`polynomial`] module, and the [`ideal`] module builds _quotient
+//! rings_ like the modular integers `Z/nZ` or the finite fields
+//! `GF(p)[x]/(f)` from a single construction.
+//!
+//! Rust's tuple and fixed-size array types form _direct products_
+//! of the structures their components form (see [`product`]), so for
+//! example `(i32, BigRational)` forms a ring.
+//!
+//! _Quasigroups_ and _loops_ (magmas with division) are provided by
+//! the [`quasigroup`] module, including the _Moufang_ loop of unit
+//! _octonions_.
+//!
+//! Small finite structures can also be defined directly by their
+//! operation tables, using the _Cayley_ _table_ types in the
+//! [`cayley`] module.
+//!
+//! Axioms of structures with small, _finite_ carriers (e.g. `u8`, or
+//! Cayley table structures) can be checked exhaustively rather than
+//! by random sampling, using the [`finite`] module.
+//!
+//! The [`finite_group`] module provides concrete _finite_ _groups_,
+//! e.g. the abelian _cyclic_ groups and the non-abelian _dihedral_
+//! groups.
+//!
+//! _Free_ structures, e.g. the free monoid and free group of words
+//! over an alphabet, are provided by the [`free`] module.
+//!
+//! Finitely _presented_ groups, _Todd-Coxeter_ coset enumeration of
+//! their elements as permutations, and _Knuth-Bendix_ completion of
+//! their normal forms, are provided by the [`presentation`] module.
+//!
+//! Deterministic finite _automata_, with their transition and
+//! syntactic monoids, are provided by the [`automata`] module.
+//!
+//! _Segment_ _trees_ over monoids and _Fenwick_ _trees_ over
+//! additive commutative groups, for range queries of sequences, are
+//! provided by the [`range`] module.
+//!
 //! In addition, the crate examples directory contains abstract
 //! structure implementations of selected concepts, for example,
 //! _finite_ _fields_.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../doc/references.md")]
+
+// Structure modules keep their trait in a same-named file, e.g.
+// `magma/magma.rs`.
+#![allow(clippy::module_inception)]
 
 
 // Supporting crates needed.
@@ -164,6 +228,9 @@ pub mod types;
 pub mod logic;
 pub mod numeric;
 pub mod magma;
+#[macro_use]
+pub mod quasigroup;
+pub mod partial;
 pub mod semigroup;
 pub mod monoid;
 pub mod group;
@@ -171,8 +238,21 @@ pub mod com_group;
 pub mod ring;
 pub mod com_ring;
 pub mod field;
+pub mod euclid_domain;
+pub mod polynomial;
+pub mod ideal;
+pub mod homomorphism;
 pub mod complex;
 pub mod rational;
+pub mod product;
+pub mod cayley;
+pub mod finite;
+pub mod finite_group;
+pub mod free;
+pub mod presentation;
+pub mod automata;
+pub mod range;
+pub mod operator;
 pub mod prelude;
 pub mod tests;
 
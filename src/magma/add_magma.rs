@@ -12,7 +12,7 @@
 //!
 //! See [references] for a formal definition of an additive magma.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use types::*;
 use num::traits::*;
@@ -40,12 +40,10 @@ pub trait AddMagma: Sized + PartialEq {
 ///
 /// `NumAddMagma` trait is for types that only form additive magmas
 /// when "numeric" comparisons are used, e.g. floating point types.
+/// Numeric additive magmas share their addition with `AddMagma`, so
+/// any additive magma with numeric equality is a numeric one.
 ///
-pub trait NumAddMagma: Sized + NumEq {
-
-  /// The magma `addition` binary operation.
-  fn add(&self, other: &Self) -> Self;
-
+pub trait NumAddMagma: AddMagma + NumEq {
 
   /// Numerically test the axiom of closure (always true).
   fn axiom_add_closure(_: NumPair<Self>) -> bool {
@@ -54,6 +52,13 @@ pub trait NumAddMagma: Sized + NumEq {
 }
 
 
+///
+/// Additive magmas with numeric equality form numeric additive
+/// magmas.
+///
+impl<T: AddMagma + NumEq> NumAddMagma for T {}
+
+
 ///
 /// Trait implementation macro for integer types.
 ///
@@ -94,10 +99,9 @@ integer_add_magma! {
 
 
 ///
-/// IEEE 64 bit floating point types only form a _numeric_ additive
-/// magma.
+/// IEEE 32 bit floating point types form an additive magma.
 ///
-impl NumAddMagma for f32 {
+impl AddMagma for f32 {
 
   /// Magma addition is just f32 addition.
   fn add(&self, other: &Self) -> Self {
@@ -107,10 +111,9 @@ impl NumAddMagma for f32 {
 
 
 ///
-/// IEEE 64 bit floating point types only form a _numeric_ additive
-/// magma.
+/// IEEE 64 bit floating point types form an additive magma.
 ///
-impl NumAddMagma for f64 {
+impl AddMagma for f64 {
 
   /// Magma addition is just f64 addition.
   fn add(&self, other: &Self) -> Self {
@@ -4,6 +4,8 @@
 //! An algebraic _magma_ is a set `S`, equipped with a _binary
 //! operation_ `·`.
 //!
+//! Strings and vectors form magmas under _concatenation_.
+//!
 //! # Axioms
 //!
 //! 1. Closure: ∀x, y ∈ S, x·y ∈ S.
@@ -12,7 +14,7 @@
 //!
 //! See [references] for a formal definition of a magma.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use types::*;
 
@@ -33,4 +35,28 @@ pub trait Magma: Sized + PartialEq {
 }
 
 
+///
+/// Strings form a magma.
+///
+impl Magma for String {
+
+  /// The operation is string concatenation.
+  fn op(&self, other: &Self) -> Self {
+    [self.as_str(), other].concat()
+  }
+}
+
+
+///
+/// Vectors form a magma.
+///
+impl<T: Clone + PartialEq> Magma for Vec<T> {
+
+  /// The operation is vector concatenation.
+  fn op(&self, other: &Self) -> Self {
+    [self.as_slice(), other].concat()
+  }
+}
+
+
 
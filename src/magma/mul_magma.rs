@@ -13,7 +13,7 @@
 //! See [references] for a formal definition of a multiplicative
 //! magma.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use types::*;
 use numeric::equal::*;
@@ -40,13 +40,11 @@ pub trait MulMagma: Sized + PartialEq {
 ///
 /// `NumMulMagma` trait is for types that only form multiplicative
 /// magmas when "numeric" comparisons are used, e.g. floating point
-/// types.
+/// types. Numeric multiplicative magmas share their multiplication
+/// with `MulMagma`, so any multiplicative magma with numeric
+/// equality is a numeric one.
 ///
-pub trait NumMulMagma: Sized + NumEq {
-
-  /// The `multiplication` binary operation.
-  fn mul(&self, other: &Self) -> Self;
-
+pub trait NumMulMagma: MulMagma + NumEq {
 
   /// Numerically test the axiom of closure (always true).
   fn axiom_mul_closure(_: NumPair<Self>) -> bool {
@@ -55,6 +53,13 @@ pub trait NumMulMagma: Sized + NumEq {
 }
 
 
+///
+/// Multiplicative magmas with numeric equality form numeric
+/// multiplicative magmas.
+///
+impl<T: MulMagma + NumEq> NumMulMagma for T {}
+
+
 ///
 /// Trait implementation macro for integer types.
 ///
@@ -95,10 +100,9 @@ integer_mul_magma! {
 
 
 ///
-/// IEEE 32 bit floating point types only form a _numeric_
-/// multiplicative magma.
+/// IEEE 32 bit floating point types form a multiplicative magma.
 ///
-impl NumMulMagma for f32 {
+impl MulMagma for f32 {
 
   /// Magma multiplication is just f32 multiplication.
   fn mul(&self, other: &Self) -> Self {
@@ -108,10 +112,9 @@ impl NumMulMagma for f32 {
 
 
 ///
-/// IEEE 64 bit floating point types only form a _numeric_
-/// multiplicative magma.
+/// IEEE 64 bit floating point types form a multiplicative magma.
 ///
-impl NumMulMagma for f64 {
+impl MulMagma for f64 {
 
   /// Magma multiplication is just f64 multiplication.
   fn mul(&self, other: &Self) -> Self {
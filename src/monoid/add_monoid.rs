@@ -14,9 +14,11 @@
 //!
 //! See [references] for a formal definition of an additive monoid.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use semigroup::add_semigroup::*;
+use numeric::equal::*;
+use numeric::equivalence::*;
 
 
 ///
@@ -38,13 +40,27 @@ pub trait AddMonoid: AddSemigroup {
 
   /// Test the left additive identity axiom.
   fn axiom_left_add_identity(x: &Self) -> bool {
-    Self::zero().add(x) == *x
+    Self::axiom_left_add_identity_by(x, &Exact)
   }
 
 
   /// Test the right additive identity axiom.
   fn axiom_right_add_identity(&self) -> bool {
-    self.add(&Self::zero()) == Self::zero()
+    self.axiom_right_add_identity_by(&Exact)
+  }
+
+
+  /// Test the left additive identity axiom up to an equivalence.
+  fn axiom_left_add_identity_by<E>(&self, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    eq.equiv(&Self::zero().add(self), self)
+  }
+
+
+  /// Test the right additive identity axiom up to an equivalence.
+  fn axiom_right_add_identity_by<E>(&self, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    eq.equiv(&self.add(&Self::zero()), self)
   }
 }
 
@@ -55,33 +71,28 @@ pub trait AddMonoid: AddSemigroup {
 /// `NumAddMonoid` trait is for types that only form additive monoids
 /// when "numeric" comparisons are used, e.g. floating point types.
 ///
-pub trait NumAddMonoid: NumAddSemigroup {
-
-  /// Unique zero (additive identity) element. Zero is ideally a
-  /// `const` value, but the `const` rules make it too difficult to
-  /// create `const` instances for many third party types.
-  fn zero() -> Self;
-
-
-  /// Test for the zero (additive identity) element.
-  fn is_zero(&self) -> bool {
-    *self == Self::zero()
-  }
-
+pub trait NumAddMonoid: AddMonoid + NumAddSemigroup {
 
   /// Numerically test the left additive identity axiom.
   fn axiom_left_add_identity(&self, eps: &Self::Error) -> bool {
-    Self::zero().add(self).num_eq(&Self::zero(), eps)
+    self.axiom_left_add_identity_by(&Within(eps))
   }
 
 
   /// Numerically test the right additive identity axiom.
   fn axiom_right_add_identity(&self, eps: &Self::Error) -> bool {
-    self.add(&Self::zero()).num_eq(&Self::zero(), eps)
+    self.axiom_right_add_identity_by(&Within(eps))
   }
 }
 
 
+///
+/// Additive monoids with numeric equality form numeric
+/// additive monoids.
+///
+impl<T: AddMonoid + NumEq> NumAddMonoid for T {}
+
+
 ///
 /// Trait implementation macro for integer types.
 ///
@@ -120,10 +131,11 @@ integer_add_monoid! {
 
 
 ///
-/// IEEE 32 bit floating point types only form a _numeric_ additive
-/// monoid.
+/// IEEE 32 bit floating point types form an additive monoid.
 ///
-impl NumAddMonoid for f32 {
+impl AddMonoid for f32 {
+
+  /// Zero is just floating point zero.
   fn zero() -> Self {
     0.0
   }
@@ -131,10 +143,11 @@ impl NumAddMonoid for f32 {
 
 
 ///
-/// IEEE 64 bit floating point types only form a _numeric_ additive
-/// monoid.
+/// IEEE 64 bit floating point types form an additive monoid.
 ///
-impl NumAddMonoid for f64 {
+impl AddMonoid for f64 {
+
+  /// Zero is just floating point zero.
   fn zero() -> Self {
     0.0
   }
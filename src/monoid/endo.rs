@@ -0,0 +1,199 @@
+//!
+//! The _endomorphism_ _monoid_ of a finite type.
+//!
+//! The functions `T -> T` on a type `T` form a monoid under function
+//! composition, with the identity function as identity. For a finite
+//! type `T` these functions are stored as lookup tables over the
+//! values of `T` (in `Finite::elements` order), so they can be
+//! compared, hashed and enumerated.
+//!
+//! As with transformations, functions act on the _right_, i.e. the
+//! product `f·g` is the function applying `f` then `g` (`g ∘ f`). The
+//! units of the monoid are the permutations of the values of `T`.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of an endomorphism
+//! monoid.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use prelude::*;
+use monoid::transformation::{maps, map_count};
+
+
+///
+/// A function from a finite type `T` to itself.
+///
+pub struct Endo<T> {
+  images: Vec<usize>,
+  carrier: PhantomData<T>,
+}
+
+
+impl<T: Finite + PartialEq> Endo<T> {
+
+  /// The lookup table of a function on `T`.
+  pub fn new<F: Fn(&T) -> T>(f: F) -> Self {
+    let images = T::elements().map(|x| index(&f(&x))).collect();
+
+    Endo { images, carrier: PhantomData }
+  }
+
+
+  /// The function value at `x`.
+  pub fn apply(&self, x: &T) -> T {
+    value(self.images[index(x)])
+  }
+
+
+  /// The rank (number of distinct values) of the function.
+  pub fn rank(&self) -> usize {
+    let n = self.images.len();
+
+    (0..n).filter(|y| self.images.contains(y)).count()
+  }
+
+
+  /// Test for an idempotent function, i.e. `f·f = f`.
+  pub fn is_idempotent(&self) -> bool {
+    self.op(self) == *self
+  }
+
+
+  /// Test for an invertible (bijective) function.
+  pub fn is_unit(&self) -> bool {
+    self.rank() == self.images.len()
+  }
+
+
+  /// The idempotent functions.
+  pub fn idempotents() -> Vec<Self> {
+    Self::elements().filter(Self::is_idempotent).collect()
+  }
+
+
+  /// The function as a permutation of the positions of the values
+  /// of `T`, when it is bijective.
+  pub fn to_permutation(&self) -> Option<Permutation> {
+    if self.is_unit() {
+      Some(Permutation::new(self.images.clone()))
+    }
+    else {
+      None
+    }
+  }
+
+
+  /// The units (bijective functions) as permutations, i.e. the
+  /// symmetric group on the values of `T`.
+  pub fn units() -> Subgroup<Permutation> {
+    Subgroup::symmetric(T::elements().count())
+  }
+}
+
+
+/// The position of a value in the finite type elements.
+fn index<T: Finite + PartialEq>(x: &T) -> usize {
+  T::elements().position(|y| y == *x).expect("finite type value")
+}
+
+
+/// The finite type value at a position.
+fn value<T: Finite>(i: usize) -> T {
+  T::elements().nth(i).expect("finite type index")
+}
+
+
+///
+/// Endomorphisms form a magma.
+///
+impl<T: Finite + PartialEq> Magma for Endo<T> {
+
+  /// The operation is function composition, applying `self` then
+  /// `other`.
+  fn op(&self, other: &Self) -> Self {
+    let images = self.images.iter().map(|&y| other.images[y]).collect();
+
+    Endo { images, carrier: PhantomData }
+  }
+}
+
+
+///
+/// Endomorphisms form a semigroup.
+///
+impl<T: Finite + PartialEq> Semigroup for Endo<T> {}
+
+
+///
+/// Endomorphisms form a monoid.
+///
+impl<T: Finite + PartialEq> Monoid for Endo<T> {
+
+  /// The identity is the identity function.
+  fn id() -> Self {
+    Endo { images: (0..T::size()).collect(), carrier: PhantomData }
+  }
+}
+
+
+///
+/// Endomorphisms of a finite type form a finite type.
+///
+impl<T: Finite + PartialEq> Finite for Endo<T> {
+
+  /// All `n^n` functions, for `n` values of `T`.
+  fn elements() -> impl Iterator<Item = Self> {
+    maps(T::size()).map(|images| Endo { images, carrier: PhantomData })
+  }
+
+
+  /// The number `n^n` of functions. Panics if the number overflows
+  /// `usize`.
+  fn size() -> usize {
+    map_count(T::size()).expect("endomorphism count overflows usize")
+  }
+}
+
+
+// Trait implementations are written out to avoid derived bounds on
+// the carrier type `T`.
+impl<T> Clone for Endo<T> {
+  fn clone(&self) -> Self {
+    Endo { images: self.images.clone(), carrier: PhantomData }
+  }
+}
+
+
+impl<T> PartialEq for Endo<T> {
+  fn eq(&self, other: &Self) -> bool {
+    self.images == other.images
+  }
+}
+
+
+impl<T> Eq for Endo<T> {}
+
+
+impl<T> Hash for Endo<T> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.images.hash(state);
+  }
+}
+
+
+impl<T> fmt::Debug for Endo<T> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "Endo({:?})", self.images)
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "endo_test.rs"]
+mod endo_test;
@@ -0,0 +1,73 @@
+use prelude::*;
+
+
+#[test]
+fn exhaustive_associativity() {
+  let result = exhaustive_3::<Endo<Cyclic<3>>, _>(|xs| {
+    Semigroup::axiom_associativity(xs)
+  });
+
+  assert_eq!(result, Ok(()));
+}
+
+
+#[test]
+fn exhaustive_identity() {
+  let left = exhaustive_1::<Endo<Cyclic<3>>, _>(Monoid::axiom_left_identity);
+  let right = exhaustive_1::<Endo<Cyclic<3>>, _>(Monoid::axiom_right_identity);
+
+  assert_eq!((left, right), (Ok(()), Ok(())));
+}
+
+
+#[test]
+fn boolean_functions() {
+  let not = Endo::new(|x: &bool| !x);
+  let yes = Endo::new(|_: &bool| true);
+
+  assert_eq!(Endo::<bool>::size(), 4);
+  assert!(!not.apply(&true));
+  assert_eq!(not.op(&not), Endo::id());
+  assert_eq!(not.op(&yes), yes);
+  assert_eq!(yes.op(&not), Endo::new(|_: &bool| false));
+  assert_ne!(yes.op(&not), not.op(&yes));
+}
+
+
+#[test]
+fn function_composition() {
+  let f = Endo::new(|x: &i8| i8::wrapping_add(*x, 1));
+  let g = Endo::new(|x: &i8| i8::wrapping_mul(*x, 2));
+
+  assert_eq!(f.op(&g).apply(&5), 12);
+  assert_eq!(g.op(&f).apply(&5), 11);
+  assert!(f.is_unit());
+  assert_eq!(g.rank(), 128);
+}
+
+
+#[test]
+fn idempotents_and_units() {
+  let units = Endo::<Cyclic<4>>::units();
+
+  assert_eq!(Endo::<Cyclic<3>>::idempotents().len(), 10);
+  assert_eq!(units.order(), 24);
+  assert!(units.elements().iter().all(|p| p.degree() <= 4));
+  assert!(Endo::<bool>::idempotents().iter().all(Endo::is_idempotent));
+}
+
+
+#[test]
+fn large_carrier() {
+  let f = Endo::new(|x: &u8| u8::wrapping_add(*x, 1));
+
+  assert!(Endo::<u8>::elements().nth(3).is_some());
+  assert_eq!(f.op(&f).apply(&255), 1);
+}
+
+
+#[test]
+#[should_panic(expected = "endomorphism count overflows usize")]
+fn large_carrier_size() {
+  let _ = Endo::<u8>::size();
+}
@@ -0,0 +1,126 @@
+//!
+//! Generic _folds_ over monoids.
+//!
+//! The elements of a monoid can be combined in sequence, starting
+//! from the identity, e.g. the sum of a sequence of numbers starts
+//! from zero. The functions in this module fold iterators of monoid
+//! (or additive or multiplicative monoid) values, and of _non-empty_
+//! sequences of semigroup values, which have no identity to start
+//! from.
+//!
+//! The `Sum` and `Product` wrapper types also implement the standard
+//! library's `std::iter::Sum` and `std::iter::Product` traits, so
+//! iterators of wrapped values can use `Iterator::sum` and
+//! `Iterator::product`.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a monoid.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use std::iter;
+use prelude::*;
+
+
+///
+/// The combination of a sequence of monoid values, or the identity
+/// for an empty sequence.
+///
+pub fn concat<M, I>(xs: I) -> M
+  where M: Monoid, I: IntoIterator<Item = M> {
+  xs.into_iter().fold(M::id(), |m, x| m.op(&x))
+}
+
+
+///
+/// The combination of a sequence of semigroup values, or `None` for
+/// an empty sequence.
+///
+pub fn sconcat<S, I>(xs: I) -> Option<S>
+  where S: Semigroup, I: IntoIterator<Item = S> {
+  let mut xs = xs.into_iter();
+  let first = xs.next()?;
+
+  Some(xs.fold(first, |s, x| s.op(&x)))
+}
+
+
+///
+/// The combination of a sequence of values mapped into a monoid, or
+/// the identity for an empty sequence.
+///
+pub fn fold_all<T, M, I, F>(xs: I, f: F) -> M
+  where M: Monoid, I: IntoIterator<Item = T>, F: Fn(T) -> M {
+  xs.into_iter().fold(M::id(), |m, x| m.op(&f(x)))
+}
+
+
+///
+/// The sum of a sequence of additive monoid values, or zero for an
+/// empty sequence.
+///
+pub fn add_concat<A, I>(xs: I) -> A
+  where A: AddMonoid, I: IntoIterator<Item = A> {
+  xs.into_iter().fold(A::zero(), |a, x| a.add(&x))
+}
+
+
+///
+/// The product of a sequence of multiplicative monoid values, or one
+/// for an empty sequence.
+///
+pub fn mul_concat<M, I>(xs: I) -> M
+  where M: MulMonoid, I: IntoIterator<Item = M> {
+  xs.into_iter().fold(M::one(), |m, x| m.mul(&x))
+}
+
+
+///
+/// Wrapped additive monoid values can be summed by iterators.
+///
+impl<T: AddMonoid> iter::Sum for Sum<T> {
+  fn sum<I: Iterator<Item = Self>>(xs: I) -> Self {
+    concat(xs)
+  }
+}
+
+
+///
+/// Wrapped additive monoid value references can be summed by
+/// iterators.
+///
+impl<'a, T: AddMonoid + Clone + 'a> iter::Sum<&'a Sum<T>> for Sum<T> {
+  fn sum<I: Iterator<Item = &'a Self>>(xs: I) -> Self {
+    concat(xs.cloned())
+  }
+}
+
+
+///
+/// Wrapped multiplicative monoid values can be multiplied by
+/// iterators.
+///
+impl<T: MulMonoid> iter::Product for Product<T> {
+  fn product<I: Iterator<Item = Self>>(xs: I) -> Self {
+    concat(xs)
+  }
+}
+
+
+///
+/// Wrapped multiplicative monoid value references can be multiplied
+/// by iterators.
+///
+impl<'a, T> iter::Product<&'a Product<T>> for Product<T>
+  where T: MulMonoid + Clone + 'a {
+  fn product<I: Iterator<Item = &'a Self>>(xs: I) -> Self {
+    concat(xs.cloned())
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "fold_test.rs"]
+mod fold_test;
@@ -0,0 +1,86 @@
+use prelude::*;
+use num::bigint::*;
+use num::rational::*;
+use proptest::prelude::*;
+use tests::prelude::*;
+
+
+/// A rational number from a numerator and denominator.
+fn rational(n: i64, d: i64) -> BigRational {
+  BigRational::new(n.to_bigint().unwrap(), d.to_bigint().unwrap())
+}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn prop_fold_homomorphism((xs, ys) in any::<(Vec<i32>, Vec<i32>)>()) {
+    let (x, y): (Sum<i32>, Sum<i32>) =
+      (fold_all(xs.clone(), Sum), fold_all(ys.clone(), Sum));
+    let xys: Vec<i32> = xs.into_iter().chain(ys).collect();
+
+    prop_assert!(fold_all(xys, Sum) == x.op(&y));
+  }
+
+
+  #[test]
+  fn prop_iter_sum(xs in any::<Vec<i64>>()) {
+    let wrapped: Vec<Sum<i64>> = xs.iter().cloned().map(Sum).collect();
+    let sum: Sum<i64> = wrapped.iter().sum();
+
+    prop_assert!(sum == Sum(add_concat(xs)));
+  }
+
+
+  #[test]
+  fn prop_sconcat_concat(xs in any::<Vec<u8>>()) {
+    let lifted: Option<Min<u8>> = concat(xs.iter().cloned().map(Min).map(Some));
+
+    prop_assert!(sconcat(xs.into_iter().map(Min)) == lifted);
+  }
+}
+
+
+#[test]
+fn rational_sums() {
+  let qs: Vec<BigRational> = (1..5).map(|n| rational(1, n)).collect();
+
+  assert_eq!(add_concat(qs.clone()), rational(25, 12));
+  assert_eq!(mul_concat(qs.clone()), rational(1, 24));
+  assert_eq!(qs.into_iter().map(Sum).sum::<Sum<_>>(), Sum(rational(25, 12)));
+  assert_eq!(add_concat(Vec::<BigRational>::new()), rational(0, 1));
+}
+
+
+#[test]
+fn iterator_products() {
+  let xs = vec![Product(2u32), Product(3), Product(7)];
+
+  assert_eq!(xs.iter().product::<Product<u32>>(), Product(42));
+  assert_eq!(xs.into_iter().product::<Product<u32>>(), Product(42));
+  let empty: Product<i8> = Vec::<Product<i8>>::new().into_iter().product();
+
+  assert_eq!(empty, Product(1));
+}
+
+
+#[test]
+fn monoid_folds() {
+  let words = vec!["un", "_", "algebra"];
+
+  assert_eq!(concat(words.iter().map(|w| w.to_string())), "un_algebra");
+  assert_eq!(fold_all(words.clone(), |w| Max(w.len())), Max(7));
+  assert_eq!(fold_all(words.clone(), |w| First(Some(w))), First(Some("un")));
+  assert_eq!(concat(Vec::<String>::new()), "");
+}
+
+
+#[test]
+fn semigroup_folds() {
+  let words = vec!["un", "algebra"];
+
+  assert_eq!(sconcat(words.into_iter().map(Min)), Some(Min("algebra")));
+  assert_eq!(sconcat(Vec::<Min<&str>>::new()), None);
+}
@@ -6,8 +6,32 @@
 //! or _multiplicative monoids_, including their "numeric"
 //! counterparts.
 //!
+//! Function composition gives realistic _non-commutative_ monoids:
+//! the endomorphisms of a finite type, and the full transformation
+//! monoids.
+//!
+//! Wrapper types (e.g. `Min<T>`) select one of the monoid structures
+//! a type carries, for use with generic folds.
+//!
+//! Associativity allows folds (and prefix scans) to be computed in
+//! parallel, as a tree of independent reductions.
+//!
 pub mod monoid;
 
 pub mod add_monoid;
 
 pub mod mul_monoid;
+
+pub mod endo;
+
+pub mod transformation;
+
+pub mod wrapper;
+
+pub mod fold;
+
+mod tree;
+
+pub mod parallel;
+
+pub mod scan;
@@ -3,7 +3,10 @@
 //!
 //! An algebraic _monoid_ is a _semigroup_ `S`, with a unique
 //! _identity_ element denoted `e`.
-//! 
+//!
+//! Strings and vectors form (non-commutative) monoids under
+//! concatenation, with the empty string or vector as identity.
+//!
 //! # Axioms
 //!
 //! 1. Semigroup: semigroup axioms hold.
@@ -13,7 +16,7 @@
 //!
 //! See [references] for a formal definition of a monoid.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use semigroup::semigroup::*;
 
@@ -35,13 +38,42 @@ pub trait Monoid: Semigroup {
 
   /// Test the left identity axiom.
   fn axiom_left_identity(&self) -> bool {
-    Self::id().op(self) == Self::id()
+    Self::id().op(self) == *self
   }
 
 
   /// Test the right identity axiom.
   fn axiom_right_identity(&self) -> bool {
-    self.op(&Self::id()) == Self::id()
+    self.op(&Self::id()) == *self
+  }
+}
+
+
+///
+/// Strings form a monoid.
+///
+impl Monoid for String {
+
+  /// The identity is the empty string.
+  fn id() -> Self {
+    String::new()
   }
 }
 
+
+///
+/// Vectors form a monoid.
+///
+impl<T: Clone + PartialEq> Monoid for Vec<T> {
+
+  /// The identity is the empty vector.
+  fn id() -> Self {
+    Vec::new()
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "monoid_test.rs"]
+mod monoid_test;
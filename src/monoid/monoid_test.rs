@@ -0,0 +1,91 @@
+use prelude::*;
+use tests::prelude::*;
+use proptest::prelude::*;
+
+
+prop_compose! {
+
+  /// Generate one random (short) string.
+  fn str_1()(x in "\\PC{0,8}") -> String {
+    x
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random (short) strings.
+  fn str_3()(xs in (str_1(), str_1(), str_1())) -> Three<String> {
+    xs
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate one random (short) byte vector.
+  fn vec_1()(xs in prop::collection::vec(any::<u8>(), 0..8)) -> Vec<u8> {
+    xs
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random (short) byte vectors.
+  fn vec_3()(xs in (vec_1(), vec_1(), vec_1())) -> Three<Vec<u8>> {
+    xs
+  }
+}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_associativity_string((ref x, ref y, ref z) in str_3()) {
+    Semigroup::axiom_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_left_identity_string((ref x, _, _) in str_3()) {
+    Monoid::axiom_left_identity(x)
+  }
+
+
+  #[test]
+  fn axiom_right_identity_string((_, _, ref x) in str_3()) {
+    Monoid::axiom_right_identity(x)
+  }
+
+
+  #[test]
+  fn axiom_associativity_vec((ref x, ref y, ref z) in vec_3()) {
+    Semigroup::axiom_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_left_identity_vec((ref x, _, _) in vec_3()) {
+    Monoid::axiom_left_identity(x)
+  }
+
+
+  #[test]
+  fn axiom_right_identity_vec((ref x, _, _) in vec_3()) {
+    Monoid::axiom_right_identity(x)
+  }
+}
+
+
+#[test]
+fn non_commutative() {
+  let (x, y) = (String::from("ab"), String::from("cd"));
+
+  assert_eq!(x.op(&y), "abcd");
+  assert_ne!(x.op(&y), y.op(&x));
+  assert!(String::id().is_empty());
+  assert!(Vec::<u8>::id().is_id());
+}
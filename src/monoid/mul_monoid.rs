@@ -15,9 +15,11 @@
 //! See [references] for a formal definition of a multiplicative
 //! monoid.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use semigroup::mul_semigroup::*;
+use numeric::equal::*;
+use numeric::equivalence::*;
 
 
 ///
@@ -39,13 +41,29 @@ pub trait MulMonoid: MulSemigroup {
 
   /// Test the left multiplicative identity axiom.
   fn axiom_left_mul_identity(&self) -> bool {
-    Self::one().mul(self) == Self::one()
+    self.axiom_left_mul_identity_by(&Exact)
   }
 
 
   /// Test the right multiplicative identity axiom.
   fn axiom_right_mul_identity(&self) -> bool {
-    self.mul(&Self::one()) == Self::one()
+    self.axiom_right_mul_identity_by(&Exact)
+  }
+
+
+  /// Test the left multiplicative identity axiom up to an
+  /// equivalence.
+  fn axiom_left_mul_identity_by<E>(&self, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    eq.equiv(&Self::one().mul(self), self)
+  }
+
+
+  /// Test the right multiplicative identity axiom up to an
+  /// equivalence.
+  fn axiom_right_mul_identity_by<E>(&self, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    eq.equiv(&self.mul(&Self::one()), self)
   }
 }
 
@@ -57,33 +75,28 @@ pub trait MulMonoid: MulSemigroup {
 /// monoids when "numeric" comparisons are used, e.g. floating point
 /// types.
 ///
-pub trait NumMulMonoid: NumMulSemigroup {
-
-  /// Unique one (multiplicative identity) element. One is ideally a
-  /// `const` value, but the `const` rules make it too difficult to
-  /// create `const` instances for many third party types.
-  fn one() -> Self;
-
-
-  /// Test for the one (multiplicative identity) element.
-  fn is_one(&self) -> bool {
-    *self == Self::one()
-  }
-
+pub trait NumMulMonoid: MulMonoid + NumMulSemigroup {
 
   /// Numerically test the left multiplicative identity axiom.
   fn axiom_left_mul_identity(&self, eps: &Self::Error) -> bool {
-    Self::one().mul(self).num_eq(&Self::one(), eps)
+    self.axiom_left_mul_identity_by(&Within(eps))
   }
 
 
   /// Numerically test the right multiplicative identity axiom.
   fn axiom_right_mul_identity(&self, eps: &Self::Error) -> bool {
-    self.mul(&Self::one()).num_eq(&Self::one(), eps)
+    self.axiom_right_mul_identity_by(&Within(eps))
   }
 }
 
 
+///
+/// Multiplicative monoids with numeric equality form numeric
+/// multiplicative monoids.
+///
+impl<T: MulMonoid + NumEq> NumMulMonoid for T {}
+
+
 ///
 /// Trait implementation macro for integer types.
 ///
@@ -122,10 +135,11 @@ integer_mul_monoid! {
 
 
 ///
-/// IEEE 32 bit floating point types only form a _numeric_
-/// multiplicative monoid.
+/// IEEE 32 bit floating point types form a multiplicative monoid.
 ///
-impl NumMulMonoid for f32 {
+impl MulMonoid for f32 {
+
+  /// One is just floating point one.
   fn one() -> Self {
     1.0
   }
@@ -133,10 +147,11 @@ impl NumMulMonoid for f32 {
 
 
 ///
-/// IEEE 64 bit floating point types only form a _numeric_
-/// multiplicative monoid.
+/// IEEE 64 bit floating point types form a multiplicative monoid.
 ///
-impl NumMulMonoid for f64 {
+impl MulMonoid for f64 {
+
+  /// One is just floating point one.
   fn one() -> Self {
     1.0
   }
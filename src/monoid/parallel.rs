@@ -0,0 +1,156 @@
+//!
+//! Parallel _reduction_ of semigroups and monoids.
+//!
+//! The associativity axiom of a semigroup guarantees that any
+//! bracketing of a sequence of operations gives the same result, so
+//! a sequence can be split into halves, each half reduced
+//! independently, and the two results combined. Repeating the split
+//! gives a _tree_ reduction whose branches can be reduced in
+//! parallel.
+//!
+//! The reductions in this module split a slice into at most
+//! `workers` contiguous parts, reduced on scoped `std::thread`
+//! workers. Parts are only ever combined in sequence order, so
+//! non-commutative semigroups (e.g. strings) reduce correctly.
+//!
+//! Floating point addition is not associative, so a parallel sum of
+//! floating point values can differ from the sequential sum. The
+//! numeric reduction reports the worst-case deviation, from the
+//! standard rounding error bound `γ_h·Σ|x|` for a sum with `h`
+//! additions on the path of any term, where `γ_h = h·u/(1 - h·u)` and
+//! `u` is the unit roundoff.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a semigroup.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use num::Float;
+use prelude::*;
+use monoid::tree::*;
+
+
+///
+/// Tree reduction of a non-empty slice by an associative operation,
+/// split into at most `parts` contiguous parts.
+///
+fn reduce<T, F>(xs: &[T], parts: usize, op: &F) -> T
+  where T: Clone + Send + Sync, F: Fn(&T, &T) -> T + Sync {
+  let parts = parts.min(xs.len());
+
+  if parts < 2 {
+    let first = xs[0].clone();
+
+    return xs[1..].iter().fold(first, |t, x| op(&t, x));
+  }
+
+  let (left, right) = xs.split_at(split(xs.len(), parts));
+  let (left, right) = fork(
+    || reduce(left, parts / 2, op),
+    || reduce(right, parts - parts / 2, op)
+  );
+
+  op(&left, &right)
+}
+
+
+///
+/// The most operations on the path of any value in a tree reduction
+/// of a non-empty slice of length `len` into at most `parts` parts.
+///
+fn height(len: usize, parts: usize) -> usize {
+  let parts = parts.min(len);
+
+  if parts < 2 {
+    return len - 1;
+  }
+
+  let mid = split(len, parts);
+
+  1 + height(mid, parts / 2).max(height(len - mid, parts - parts / 2))
+}
+
+
+///
+/// The parallel combination of a slice of semigroup values, or
+/// `None` for an empty slice.
+///
+pub fn par_sconcat<S>(xs: &[S], workers: usize) -> Option<S>
+  where S: Semigroup + Clone + Send + Sync {
+  if xs.is_empty() {
+    return None;
+  }
+
+  Some(reduce(xs, workers, &S::op))
+}
+
+
+///
+/// The parallel combination of a slice of monoid values, or the
+/// identity for an empty slice.
+///
+pub fn par_concat<M>(xs: &[M], workers: usize) -> M
+  where M: Monoid + Clone + Send + Sync {
+  par_sconcat(xs, workers).unwrap_or_else(M::id)
+}
+
+
+///
+/// The parallel sum of a slice of additive monoid values, or zero for
+/// an empty slice.
+///
+pub fn par_add_concat<A>(xs: &[A], workers: usize) -> A
+  where A: AddMonoid + Clone + Send + Sync {
+  if xs.is_empty() {
+    return A::zero();
+  }
+
+  reduce(xs, workers, &A::add)
+}
+
+
+///
+/// The parallel product of a slice of multiplicative monoid values,
+/// or one for an empty slice.
+///
+pub fn par_mul_concat<M>(xs: &[M], workers: usize) -> M
+  where M: MulMonoid + Clone + Send + Sync {
+  if xs.is_empty() {
+    return M::one();
+  }
+
+  reduce(xs, workers, &M::mul)
+}
+
+
+///
+/// The parallel sum of a slice of "numeric" additive monoid values,
+/// and the worst-case deviation of any parallel sum from the
+/// sequential (left to right) sum.
+///
+/// The deviation is bounded by the rounding errors of both sums, so
+/// the values must be floating point values, with magnitudes and a
+/// unit roundoff.
+///
+pub fn num_par_add_concat<A>(xs: &[A], workers: usize) -> (A, A)
+  where A: NumAddMonoid + Float + Send + Sync {
+  let zero = <A as AddMonoid>::zero();
+
+  if xs.is_empty() {
+    return (zero, zero);
+  }
+
+  let sum = reduce(xs, workers, &<A as AddMagma>::add);
+  let magnitude = xs.iter().fold(zero, |t, x| t + x.abs());
+  let (n, h) = (xs.len(), height(xs.len(), workers));
+  let deviation = (gamma::<A>(h) + gamma::<A>(n - 1)) * magnitude;
+
+  (sum, deviation)
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "parallel_test.rs"]
+mod parallel_test;
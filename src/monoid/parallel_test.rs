@@ -0,0 +1,112 @@
+use prelude::*;
+use super::height;
+use proptest::prelude::*;
+use tests::prelude::*;
+
+
+prop_compose! {
+
+  /// Generate a random `i32` vector, and a number of workers.
+  fn i32_workers()
+    (xs in prop::collection::vec(any::<i32>(), 0..50), w in 0..9usize)
+    -> (Vec<i32>, usize) {
+    (xs, w)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate a random `String` vector, and a number of workers.
+  fn string_workers()
+    (xs in prop::collection::vec(any::<String>(), 0..50), w in 0..9usize)
+    -> (Vec<String>, usize) {
+    (xs, w)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate a random `f64` vector, and a number of workers.
+  fn f64_workers()
+    (xs in prop::collection::vec(0..1000u16, 0..50), w in 0..9usize)
+    -> (Vec<f64>, usize) {
+    (xs.iter().map(|&x| x as f64 / 7.0).collect(), w)
+  }
+}
+
+
+proptest! {
+  #![proptest_config(config_with(1000, 500))]
+
+
+  #[test]
+  fn prop_par_concat((ref xs, w) in i32_workers()) {
+    prop_assert!(par_add_concat(xs, w) == add_concat(xs.clone()));
+    prop_assert!(par_mul_concat(xs, w) == mul_concat(xs.clone()));
+  }
+
+
+  #[test]
+  fn prop_par_sconcat((ref xs, w) in string_workers()) {
+    let mins: Vec<Min<String>> = xs.iter().cloned().map(Min).collect();
+
+    prop_assert!(par_concat(xs, w) == concat(xs.clone()));
+    prop_assert!(par_sconcat(&mins, w) == sconcat(mins.clone()));
+  }
+
+
+  #[test]
+  fn prop_num_par_concat((ref xs, w) in f64_workers()) {
+    let (sum, deviation) = num_par_add_concat(xs, w);
+    let sequential: f64 = xs.iter().sum();
+
+    prop_assert!((sum - sequential).abs() <= deviation);
+    prop_assert!(sum.num_eq(&sequential, &deviation));
+  }
+}
+
+
+#[test]
+fn non_commutative_reductions() {
+  let words: Vec<String> = (0..100).map(|n| n.to_string()).collect();
+  let joined: String = words.concat();
+
+  assert_eq!(par_concat(&words, 4), joined);
+  assert_eq!(par_concat(&words, 7), joined);
+  assert_eq!(par_sconcat(&words, 64), Some(joined));
+}
+
+
+#[test]
+fn empty_reductions() {
+  let empty: Vec<String> = Vec::new();
+
+  assert_eq!(par_sconcat(&empty, 4), None);
+  assert_eq!(par_concat(&empty, 4), "");
+  assert_eq!(par_add_concat::<u8>(&[], 4), 0);
+  assert_eq!(par_mul_concat::<u8>(&[], 4), 1);
+  assert_eq!(num_par_add_concat::<f32>(&[], 4), (0.0, 0.0));
+}
+
+
+#[test]
+fn numeric_deviation() {
+  let xs = [1.0, 1e16, -1e16, 1.0];
+  let (sum, deviation) = num_par_add_concat(&xs, 2);
+
+  // The parallel sum loses both ones, and the sequential sum one.
+  assert_eq!(sum, 0.0);
+  assert!((1.0..12.0).contains(&deviation));
+}
+
+
+#[test]
+fn worker_parts() {
+  // Three workers split nine values into three parts of three, so
+  // the reduction tree has two levels above the parts.
+  assert_eq!(height(9, 3), 4);
+  assert_eq!(height(9, 1), 8);
+  assert_eq!(height(2, 8), 1);
+}
@@ -0,0 +1,203 @@
+//!
+//! Prefix _scans_ of monoids.
+//!
+//! A prefix scan of a sequence of monoid values is the sequence of
+//! combinations of its prefixes. An _inclusive_ scan of `[a, b, c]`
+//! is `[a, ab, abc]`, and an _exclusive_ scan is `[e, a, ab]`, where
+//! `e` is the monoid identity.
+//!
+//! Associativity allows scans to be computed in parallel, using the
+//! work-efficient algorithm of Blelloch. An _up-sweep_ reduces the
+//! halves of a sequence (recursively) in parallel, building a tree of
+//! partial combinations. A _down-sweep_ then scans the halves in
+//! parallel, with the right half offset by the combination of the
+//! left half. The recursion stops after splitting a sequence into at
+//! most `workers` parts, and each worker scans its part sequentially.
+//! With a single worker a scan is just a left to right fold.
+//!
+//! Scans are generic over monoids, so additive and multiplicative
+//! scans (e.g. prefix sums) are scans of the `Sum` and `Product`
+//! wrappers.
+//!
+//! Floating point addition is not associative, so parallel scans of
+//! floating point values only agree numerically with sequential
+//! scans. The numeric additive scan reports the worst-case deviation
+//! of each prefix sum. Every prefix sum of `k` values adds them in
+//! some order, with at most `k - 1` additions on the path of any
+//! value, so parallel and sequential prefix sums differ by at most
+//! `2·γ_(k-1)·Σ|x|`.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a monoid.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use num::Float;
+use prelude::*;
+use monoid::tree::*;
+
+
+///
+/// The prefixes combined by a prefix scan.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Scan {
+
+  /// Prefixes up to and including each value, e.g. `[a, ab, abc]`.
+  Inclusive,
+
+  /// Prefixes up to but excluding each value, e.g. `[e, a, ab]`.
+  Exclusive,
+}
+
+
+///
+/// A tree of up-sweep combinations of (recursive) parts of a
+/// sequence.
+///
+struct Sweep<T> {
+  len: usize,
+  total: T,
+  halves: Option<Box<(Sweep<T>, Sweep<T>)>>,
+}
+
+
+///
+/// Up-sweep combinations of a sequence, split into `parts` parts.
+///
+fn up_sweep<M>(xs: &[M], parts: usize) -> Sweep<M>
+  where M: Monoid + Clone + Send + Sync {
+  if parts < 2 {
+    let total = xs.iter().fold(M::id(), |t, x| t.op(x));
+
+    return Sweep { len: xs.len(), total, halves: None };
+  }
+
+  let (left, right) = xs.split_at(split(xs.len(), parts));
+  let (left, right) = fork(
+    || up_sweep(left, parts / 2),
+    || up_sweep(right, parts - parts / 2)
+  );
+
+  Sweep {
+    len: xs.len(),
+    total: left.total.op(&right.total),
+    halves: Some(Box::new((left, right))),
+  }
+}
+
+
+///
+/// Sequential scan of a sequence into `scans`, offset by `offset`.
+///
+fn fold_scan<M: Monoid + Clone>(xs: &[M], offset: M, scans: &mut [M],
+                                kind: Scan) {
+  let mut acc = offset;
+
+  for (x, scan) in xs.iter().zip(scans.iter_mut()) {
+    let next = acc.op(x);
+
+    *scan = if kind == Scan::Inclusive { next.clone() } else { acc };
+    acc = next;
+  }
+}
+
+
+///
+/// Down-sweep scans of a sequence into `scans`, offset by `offset`.
+///
+fn down_sweep<M>(xs: &[M], sweep: &Sweep<M>, offset: M, scans: &mut [M],
+                 kind: Scan)
+  where M: Monoid + Clone + Send + Sync {
+  let (left, right) = match sweep.halves {
+    Some(ref halves) => (&halves.0, &halves.1),
+    None => return fold_scan(xs, offset, scans, kind),
+  };
+
+  let (xs_left, xs_right) = xs.split_at(left.len);
+  let (scans_left, scans_right) = scans.split_at_mut(left.len);
+  let right_offset = offset.op(&left.total);
+
+  fork(
+    || down_sweep(xs_left, left, offset, scans_left, kind),
+    || down_sweep(xs_right, right, right_offset, scans_right, kind)
+  );
+}
+
+
+///
+/// The prefix scan of a slice of monoid values, split into at most
+/// `workers` parts scanned in parallel.
+///
+pub fn scan<M>(xs: &[M], kind: Scan, workers: usize) -> Vec<M>
+  where M: Monoid + Clone + Send + Sync {
+  let parts = workers.min(xs.len());
+  let mut scans = vec![M::id(); xs.len()];
+
+  if parts < 2 {
+    fold_scan(xs, M::id(), &mut scans, kind);
+  }
+  else {
+    let sweep = up_sweep(xs, parts);
+
+    down_sweep(xs, &sweep, M::id(), &mut scans, kind);
+  }
+
+  scans
+}
+
+
+///
+/// The prefix sums of a slice of additive monoid values, split into
+/// at most `workers` parts scanned in parallel.
+///
+pub fn add_scan<A>(xs: &[A], kind: Scan, workers: usize) -> Vec<A>
+  where A: AddMonoid + Clone + Send + Sync {
+  let sums: Vec<Sum<A>> = xs.iter().cloned().map(Sum).collect();
+
+  scan(&sums, kind, workers).into_iter().map(|s| s.0).collect()
+}
+
+
+///
+/// The prefix products of a slice of multiplicative monoid values,
+/// split into at most `workers` parts scanned in parallel.
+///
+pub fn mul_scan<M>(xs: &[M], kind: Scan, workers: usize) -> Vec<M>
+  where M: MulMonoid + Clone + Send + Sync {
+  let products: Vec<Product<M>> = xs.iter().cloned().map(Product).collect();
+
+  scan(&products, kind, workers).into_iter().map(|p| p.0).collect()
+}
+
+
+///
+/// The parallel prefix sums of a slice of "numeric" additive monoid
+/// values, and the worst-case deviation of each parallel prefix sum
+/// from the sequential (left to right) prefix sum.
+///
+/// The deviations are bounded by the rounding errors of both sums,
+/// so the values must be floating point values, with magnitudes and
+/// a unit roundoff.
+///
+pub fn num_add_scan<A>(xs: &[A], kind: Scan, workers: usize)
+  -> (Vec<A>, Vec<A>)
+  where A: NumAddMonoid + Float + Send + Sync {
+  let magnitudes: Vec<A> = xs.iter().map(|x| x.abs()).collect();
+  let magnitudes = add_scan(&magnitudes, kind, 1);
+
+  let deviations = magnitudes.into_iter().enumerate().map(|(i, m)| {
+    let terms = if kind == Scan::Inclusive { i + 1 } else { i };
+
+    gamma::<A>(terms.saturating_sub(1)) * (m + m)
+  });
+
+  (add_scan(xs, kind, workers), deviations.collect())
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "scan_test.rs"]
+mod scan_test;
@@ -0,0 +1,127 @@
+use prelude::*;
+use proptest::prelude::*;
+use tests::prelude::*;
+
+
+prop_compose! {
+
+  /// Generate a random `i64` vector, and a number of workers.
+  fn i64_workers()
+    (xs in prop::collection::vec(any::<i64>(), 0..50), w in 0..9usize)
+    -> (Vec<i64>, usize) {
+    (xs, w)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate a random `String` vector, and a number of workers.
+  fn string_workers()
+    (xs in prop::collection::vec(any::<String>(), 0..50), w in 0..9usize)
+    -> (Vec<String>, usize) {
+    (xs, w)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate a random `f64` vector, and a number of workers.
+  fn f64_workers()
+    (xs in prop::collection::vec(-1e3..1e3, 0..50), w in 0..9usize)
+    -> (Vec<f64>, usize) {
+    (xs, w)
+  }
+}
+
+
+proptest! {
+  #![proptest_config(config_with(1000, 500))]
+
+
+  #[test]
+  fn prop_par_scan((ref xs, w) in string_workers()) {
+    for kind in [Scan::Inclusive, Scan::Exclusive] {
+      prop_assert!(scan(xs, kind, w) == scan(xs, kind, 1));
+    }
+  }
+
+
+  #[test]
+  fn prop_par_add_scan((ref xs, w) in i64_workers()) {
+    for kind in [Scan::Inclusive, Scan::Exclusive] {
+      prop_assert!(add_scan(xs, kind, w) == add_scan(xs, kind, 1));
+    }
+  }
+
+
+  #[test]
+  fn prop_par_mul_scan((ref xs, w) in i64_workers()) {
+    for kind in [Scan::Inclusive, Scan::Exclusive] {
+      prop_assert!(mul_scan(xs, kind, w) == mul_scan(xs, kind, 1));
+    }
+  }
+
+
+  #[test]
+  fn prop_num_par_add_scan((ref xs, w) in f64_workers()) {
+    for kind in [Scan::Inclusive, Scan::Exclusive] {
+      let (sums, deviations) = num_add_scan(xs, kind, w);
+      let sequential = add_scan(xs, kind, 1);
+
+      for ((x, y), d) in sums.iter().zip(&sequential).zip(&deviations) {
+        prop_assert!((x - y).abs() <= *d);
+      }
+    }
+  }
+
+
+  #[test]
+  fn prop_scan_shift((ref xs, w) in string_workers()) {
+    let inclusive = scan(xs, Scan::Inclusive, w);
+    let exclusive = scan(xs, Scan::Exclusive, w);
+    let total = inclusive.last().cloned().unwrap_or_default();
+
+    prop_assert!(exclusive.len() == xs.len());
+    prop_assert!(exclusive.iter().skip(1).zip(&inclusive).all(|(e, i)| e == i));
+    prop_assert!(total == concat(xs.clone()));
+  }
+}
+
+
+#[test]
+fn prefix_scans() {
+  let xs = [1, 2, 3, 4, 5];
+
+  assert_eq!(add_scan(&xs, Scan::Inclusive, 1), [1, 3, 6, 10, 15]);
+  assert_eq!(add_scan(&xs, Scan::Exclusive, 0), [0, 1, 3, 6, 10]);
+  assert_eq!(mul_scan(&xs, Scan::Inclusive, 4), [1, 2, 6, 24, 120]);
+  assert_eq!(mul_scan(&xs, Scan::Exclusive, 3), [1, 1, 2, 6, 24]);
+}
+
+
+#[test]
+fn numeric_deviations() {
+  let (sums, deviations) = num_add_scan(&[1.0, -2.0, 4.0], Scan::Exclusive, 2);
+
+  assert_eq!(sums, [0.0, 1.0, -1.0]);
+  assert_eq!(deviations[..2], [0.0, 0.0], "no additions in prefixes of one value");
+  assert!(deviations[2] > 0.0 && deviations[2] < 1e-14, "rounding bound");
+}
+
+
+#[test]
+fn non_commutative_scans() {
+  let words: Vec<String> = vec!["a".into(), "b".into(), "c".into()];
+
+  assert_eq!(scan(&words, Scan::Exclusive, 8), ["", "a", "ab"]);
+  assert_eq!(scan(&words, Scan::Inclusive, 2), ["a", "ab", "abc"]);
+}
+
+
+#[test]
+fn empty_scans() {
+  assert_eq!(scan::<String>(&[], Scan::Inclusive, 1), Vec::<String>::new());
+  assert_eq!(scan::<Sum<u8>>(&[], Scan::Exclusive, 4), vec![]);
+}
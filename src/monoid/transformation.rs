@@ -0,0 +1,216 @@
+//!
+//! The full _transformation_ _monoid_.
+//!
+//! The full _transformation_ _monoid_ `T_n` is the set of all maps
+//! from `{0, 1, ..., n-1}` to itself, under composition, with the
+//! identity map as identity. It has `n^n` elements, and every finite
+//! semigroup embeds in some full transformation monoid (Cayley's
+//! theorem for semigroups).
+//!
+//! Transformations here act on the _right_, i.e. the product `s·t` is
+//! the transformation applying `s` then `t`, matching permutations
+//! and automata transitions.
+//!
+//! The _rank_ of a transformation is the size of its image, and the
+//! transformations of full rank (the _units_ of `T_n`) are exactly the
+//! permutations, i.e. the group of units of `T_n` is the _symmetric_
+//! _group_ `S_n`.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a transformation
+//! monoid.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use std::convert::TryFrom;
+use prelude::*;
+
+
+///
+/// All maps from `{0, 1, ..., n-1}` to itself, as vectors of images,
+/// in lexicographic order.
+///
+pub fn maps(n: usize) -> impl Iterator<Item = Vec<usize>> {
+  ::std::iter::successors(Some(vec![0; n]), move |images| {
+    let mut next = images.clone();
+
+    for x in next.iter_mut().rev() {
+      *x += 1;
+      if *x < n {
+        return Some(next);
+      }
+      *x = 0;
+    }
+    None
+  })
+}
+
+
+///
+/// The number `n^n` of maps from `{0, 1, ..., n-1}` to itself, or
+/// `None` if the number overflows `usize`.
+///
+pub fn map_count(n: usize) -> Option<usize> {
+  u32::try_from(n).ok().and_then(|e| n.checked_pow(e))
+}
+
+
+///
+/// A transformation of the points `{0, 1, ..., N-1}`.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Transformation<const N: usize> {
+  images: [usize; N],
+}
+
+
+impl<const N: usize> Transformation<N> {
+
+  /// A new transformation from the images of each point. Panics if
+  /// an image is out of range.
+  pub fn new(images: [usize; N]) -> Self {
+    assert!(images.iter().all(|&x| x < N), "image out of range");
+
+    Transformation { images }
+  }
+
+
+  /// The constant transformation mapping every point to `x`.
+  pub fn constant(x: usize) -> Self {
+    Self::new([x; N])
+  }
+
+
+  /// The image of a point under the transformation.
+  pub fn apply(&self, x: usize) -> usize {
+    self.images[x]
+  }
+
+
+  /// The images of points `0..N`.
+  pub fn images(&self) -> &[usize; N] {
+    &self.images
+  }
+
+
+  /// The rank (number of distinct images) of the transformation.
+  pub fn rank(&self) -> usize {
+    (0..N).filter(|&y| self.images.contains(&y)).count()
+  }
+
+
+  /// Test for an idempotent transformation, i.e. `t·t = t`.
+  pub fn is_idempotent(&self) -> bool {
+    self.op(self) == *self
+  }
+
+
+  /// Test the powers of the transformation are eventually constant,
+  /// i.e. `t^n = t^(n+1)` for some `n`.
+  pub fn is_aperiodic(&self) -> bool {
+    let mut powers = vec![*self];
+
+    loop {
+      let next = powers.last().unwrap().op(self);
+
+      if let Some(i) = powers.iter().position(|p| *p == next) {
+        return i == powers.len() - 1;
+      }
+      powers.push(next);
+    }
+  }
+
+
+  /// The transformation as a permutation, when it has full rank.
+  pub fn to_permutation(&self) -> Option<Permutation> {
+    if self.rank() == N {
+      Some(Permutation::new(self.images.to_vec()))
+    }
+    else {
+      None
+    }
+  }
+
+
+  /// The idempotent transformations.
+  pub fn idempotents() -> Vec<Self> {
+    Self::elements().filter(Self::is_idempotent).collect()
+  }
+
+
+  /// The units (invertible transformations) as permutations, i.e. the
+  /// symmetric group on `N` points.
+  pub fn units() -> Subgroup<Permutation> {
+    Subgroup::symmetric(N)
+  }
+}
+
+
+///
+/// Transformations form a magma.
+///
+impl<const N: usize> Magma for Transformation<N> {
+
+  /// The operation is composition, applying `self` then `other`.
+  fn op(&self, other: &Self) -> Self {
+    let mut images = [0; N];
+
+    for (x, y) in images.iter_mut().enumerate() {
+      *y = other.apply(self.apply(x));
+    }
+    Transformation { images }
+  }
+}
+
+
+///
+/// Transformations form a semigroup.
+///
+impl<const N: usize> Semigroup for Transformation<N> {}
+
+
+///
+/// Transformations form a monoid.
+///
+impl<const N: usize> Monoid for Transformation<N> {
+
+  /// The identity transformation fixes every point.
+  fn id() -> Self {
+    let mut images = [0; N];
+
+    for (x, y) in images.iter_mut().enumerate() {
+      *y = x;
+    }
+    Transformation { images }
+  }
+}
+
+
+///
+/// Transformations form a finite type.
+///
+impl<const N: usize> Finite for Transformation<N> {
+
+  /// All `N^N` transformations in lexicographic order of images.
+  fn elements() -> impl Iterator<Item = Self> {
+    maps(N).map(|images| {
+      let mut array = [0; N];
+      array.copy_from_slice(&images);
+      Transformation { images: array }
+    })
+  }
+
+
+  /// The number `N^N` of transformations. Panics if the number
+  /// overflows `usize`.
+  fn size() -> usize {
+    map_count(N).expect("transformation count overflows usize")
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "transformation_test.rs"]
+mod transformation_test;
@@ -0,0 +1,131 @@
+use prelude::*;
+use proptest::prelude::*;
+use tests::prelude::*;
+
+
+type T5 = Transformation<5>;
+
+
+prop_compose! {
+
+  /// Generate one random transformation of five points.
+  fn t5_1()(xs in any::<[usize; 5]>()) -> T5 {
+    let mut images = xs;
+
+    for x in images.iter_mut() {
+      *x %= 5;
+    }
+    T5::new(images)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random transformations of five points.
+  fn t5_3()(xs in (t5_1(), t5_1(), t5_1())) -> Three<T5> {
+    xs
+  }
+}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_associativity((ref x, ref y, ref z) in t5_3()) {
+    Semigroup::axiom_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_left_identity((ref x, _, _) in t5_3()) {
+    Monoid::axiom_left_identity(x)
+  }
+
+
+  #[test]
+  fn axiom_right_identity((ref x, _, _) in t5_3()) {
+    Monoid::axiom_right_identity(x)
+  }
+
+
+  #[test]
+  fn prop_rank_decreases((ref x, ref y, _) in t5_3()) {
+    prop_assert!(x.op(y).rank() <= x.rank().min(y.rank()));
+  }
+
+
+  #[test]
+  fn prop_right_action((ref x, ref y, _) in t5_3(), p in 0..5usize) {
+    prop_assert!(x.op(y).apply(p) == y.apply(x.apply(p)));
+  }
+}
+
+
+#[test]
+fn exhaustive_associativity() {
+  let result = exhaustive_3::<Transformation<3>, _>(|xs| {
+    Semigroup::axiom_associativity(xs)
+  });
+
+  assert_eq!(result, Ok(()));
+}
+
+
+#[test]
+fn transformation_counts() {
+  assert_eq!(Transformation::<3>::size(), 27);
+  assert_eq!(Transformation::<4>::size(), 256);
+  assert_eq!(Transformation::<0>::size(), 1);
+  assert_eq!(Transformation::<0>::elements().count(), 1);
+  assert_eq!(map_count(15), Some(437_893_890_380_859_375));
+  assert_eq!(map_count(16), None);
+}
+
+
+#[test]
+fn idempotents() {
+  // The idempotents of T_n number sum_k C(n, k) k^(n-k).
+  assert_eq!(Transformation::<1>::idempotents().len(), 1);
+  assert_eq!(Transformation::<2>::idempotents().len(), 3);
+  assert_eq!(Transformation::<3>::idempotents().len(), 10);
+  assert_eq!(Transformation::<4>::idempotents().len(), 41);
+}
+
+
+#[test]
+fn symmetric_group_units() {
+  assert_eq!(Transformation::<3>::units().order(), 6);
+  assert_eq!(Transformation::<4>::units().order(), 24);
+  assert_eq!(Transformation::<8>::units().order(), 40_320);
+  assert_eq!(T5::constant(2).to_permutation(), None);
+}
+
+
+#[test]
+fn aperiodic_transformations() {
+  assert!(T5::constant(3).is_aperiodic());
+  assert!(T5::new([1, 2, 3, 4, 4]).is_aperiodic());
+  assert!(!T5::new([1, 0, 2, 3, 4]).is_aperiodic());
+  assert!(!T5::new([1, 0, 0, 3, 4]).is_aperiodic());
+}
+
+
+#[test]
+fn non_commutative() {
+  let (s, t) = (T5::constant(0), T5::constant(1));
+
+  assert_ne!(s.op(&t), t.op(&s));
+  assert_eq!(s.op(&t), t);
+  assert_eq!(s.rank(), 1);
+  assert!(s.is_idempotent());
+}
+
+
+#[test]
+#[should_panic]
+fn image_out_of_range() {
+  Transformation::new([0, 3, 1]);
+}
@@ -0,0 +1,65 @@
+//!
+//! Tree splitting of slices between scoped worker threads.
+//!
+//! Parallel reductions and scans split a slice into at most `workers`
+//! contiguous parts, by recursively splitting the slice (and the
+//! workers) in two, with one half on a new scoped `std::thread`
+//! worker and the other on the current thread.
+//!
+//! Floating point sums combined by a tree (or in sequence) deviate
+//! from the exact sum by at most `γ_h·Σ|x|`, where `h` is the most
+//! additions on the path of any term.
+//!
+
+use std::panic;
+use std::thread;
+use num::Float;
+
+
+///
+/// The split point of a slice of length `len` between `parts` parts,
+/// with `parts / 2` parts on the left. The parts are at most `len`.
+///
+pub fn split(len: usize, parts: usize) -> usize {
+  let left = parts / 2;
+
+  len / parts * left + len % parts * left / parts
+}
+
+
+///
+/// Run `left` on a scoped worker thread and `right` on the current
+/// thread, propagating any worker panic.
+///
+pub fn fork<A, B, L, R>(left: L, right: R) -> (A, B)
+  where A: Send, L: FnOnce() -> A + Send, R: FnOnce() -> B {
+  thread::scope(|scope| {
+    let worker = scope.spawn(left);
+    let right = right();
+    let left = worker.join().unwrap_or_else(|e| panic::resume_unwind(e));
+
+    (left, right)
+  })
+}
+
+
+///
+/// The rounding error factor `γ_h = h·u/(1 - h·u)` of `h` floating
+/// point additions, or infinity if `h·u >= 1`.
+///
+pub fn gamma<A: Float>(h: usize) -> A {
+  let hu = A::epsilon() / (A::one() + A::one()) * A::from(h).unwrap();
+
+  if hu < A::one() {
+    hu / (A::one() - hu)
+  }
+  else {
+    A::infinity()
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "tree_test.rs"]
+mod tree_test;
@@ -0,0 +1,25 @@
+use monoid::tree::*;
+
+
+#[test]
+fn split_points() {
+  // The left half gets `parts / 2` of the parts, rounding down.
+  assert_eq!((split(9, 3), split(10, 4), split(3, 3)), (3, 5, 1));
+  assert_eq!((split(2, 2), split(7, 2), split(100, 8)), (1, 3, 50));
+}
+
+
+#[test]
+fn fork_results() {
+  let xs = [1, 2, 3, 4];
+  let (left, right) = fork(|| xs[..2].iter().sum(), || xs[2..].iter().sum());
+
+  assert_eq!((left, right), (3, 7));
+}
+
+
+#[test]
+#[should_panic(expected = "worker panic")]
+fn fork_worker_panic() {
+  fork(|| panic!("worker panic"), || ());
+}
@@ -0,0 +1,338 @@
+//!
+//! Monoid _wrapper_ types.
+//!
+//! Many types form more than one monoid, e.g. integers form monoids
+//! under addition, multiplication, minimum and maximum. The wrapper
+//! types in this module select one of these structures, so generic
+//! code using the abstract `Semigroup` and `Monoid` traits (e.g.
+//! folds) can be given the intended structure:
+//!
+//! * `Sum<T>`: addition, with identity zero.
+//! * `Product<T>`: multiplication, with identity one.
+//! * `Min<T>`: minimum, with identity the greatest value.
+//! * `Max<T>`: maximum, with identity the least value.
+//! * `First<T>`: the first present value, with identity `None`.
+//! * `Last<T>`: the last present value, with identity `None`.
+//!
+//! Any semigroup `S` can also be _lifted_ to a monoid `Option<S>`, by
+//! adjoining `None` as a new identity element.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a monoid.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use num::traits::Bounded;
+use prelude::*;
+
+
+///
+/// A value under addition.
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Sum<T>(pub T);
+
+
+///
+/// A value under multiplication.
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Product<T>(pub T);
+
+
+///
+/// A value under minimum.
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Min<T>(pub T);
+
+
+///
+/// A value under maximum.
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Max<T>(pub T);
+
+
+///
+/// An optional value, keeping the first present value.
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct First<T>(pub Option<T>);
+
+
+///
+/// An optional value, keeping the last present value.
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Last<T>(pub Option<T>);
+
+
+///
+/// Trait implementation macro for wrapper types.
+///
+/// A macro used to avoid writing repetitive, boilerplate `Finite`
+/// implementations for wrappers of single values.
+///
+macro_rules! wrapper_finite {
+  ($wrapper:ident) => {
+    impl<T: Finite> Finite for $wrapper<T> {
+
+      /// The wrapped values of the underlying values.
+      fn elements() -> impl Iterator<Item = Self> {
+        T::elements().map($wrapper)
+      }
+    }
+  };
+}
+
+wrapper_finite!(Sum);
+wrapper_finite!(Product);
+wrapper_finite!(Min);
+wrapper_finite!(Max);
+
+
+///
+/// Additive magmas form a magma under addition.
+///
+impl<T: AddMagma> Magma for Sum<T> {
+
+  /// The operation is addition.
+  fn op(&self, other: &Self) -> Self {
+    Sum(self.0.add(&other.0))
+  }
+}
+
+
+///
+/// Additive semigroups form a semigroup under addition.
+///
+impl<T: AddSemigroup> Semigroup for Sum<T> {}
+
+
+///
+/// Additive monoids form a monoid under addition.
+///
+impl<T: AddMonoid> Monoid for Sum<T> {
+
+  /// The identity is zero.
+  fn id() -> Self {
+    Sum(T::zero())
+  }
+}
+
+
+///
+/// Additive groups form a group under addition.
+///
+impl<T: AddGroup> Group for Sum<T> {
+
+  /// The inverse is the negation.
+  fn inverse(&self) -> Self {
+    Sum(self.0.negate())
+  }
+}
+
+
+// Groups are loops.
+group_loop! {[T: AddGroup] Sum<T>}
+
+
+///
+/// Additive commutative groups form a commutative group under
+/// addition.
+///
+impl<T: AddComGroup> ComGroup for Sum<T> {}
+
+
+///
+/// Multiplicative magmas form a magma under multiplication.
+///
+impl<T: MulMagma> Magma for Product<T> {
+
+  /// The operation is multiplication.
+  fn op(&self, other: &Self) -> Self {
+    Product(self.0.mul(&other.0))
+  }
+}
+
+
+///
+/// Multiplicative semigroups form a semigroup under multiplication.
+///
+impl<T: MulSemigroup> Semigroup for Product<T> {}
+
+
+///
+/// Multiplicative monoids form a monoid under multiplication.
+///
+impl<T: MulMonoid> Monoid for Product<T> {
+
+  /// The identity is one.
+  fn id() -> Self {
+    Product(T::one())
+  }
+}
+
+
+///
+/// Ordered values form a magma under minimum.
+///
+impl<T: Ord + Clone> Magma for Min<T> {
+
+  /// The operation is minimum.
+  fn op(&self, other: &Self) -> Self {
+    Min(self.0.clone().min(other.0.clone()))
+  }
+}
+
+
+///
+/// Ordered values form a semigroup under minimum.
+///
+impl<T: Ord + Clone> Semigroup for Min<T> {}
+
+
+///
+/// Bounded ordered values form a monoid under minimum.
+///
+impl<T: Ord + Clone + Bounded> Monoid for Min<T> {
+
+  /// The identity is the greatest value.
+  fn id() -> Self {
+    Min(T::max_value())
+  }
+}
+
+
+///
+/// Ordered values form a magma under maximum.
+///
+impl<T: Ord + Clone> Magma for Max<T> {
+
+  /// The operation is maximum.
+  fn op(&self, other: &Self) -> Self {
+    Max(self.0.clone().max(other.0.clone()))
+  }
+}
+
+
+///
+/// Ordered values form a semigroup under maximum.
+///
+impl<T: Ord + Clone> Semigroup for Max<T> {}
+
+
+///
+/// Bounded ordered values form a monoid under maximum.
+///
+impl<T: Ord + Clone + Bounded> Monoid for Max<T> {
+
+  /// The identity is the least value.
+  fn id() -> Self {
+    Max(T::min_value())
+  }
+}
+
+
+///
+/// Optional values form a magma keeping the first present value.
+///
+impl<T: Clone + PartialEq> Magma for First<T> {
+
+  /// The operation keeps the first present value.
+  fn op(&self, other: &Self) -> Self {
+    First(self.0.clone().or_else(|| other.0.clone()))
+  }
+}
+
+
+///
+/// Optional values form a semigroup keeping the first present value.
+///
+impl<T: Clone + PartialEq> Semigroup for First<T> {}
+
+
+///
+/// Optional values form a monoid keeping the first present value.
+///
+impl<T: Clone + PartialEq> Monoid for First<T> {
+
+  /// The identity is no value.
+  fn id() -> Self {
+    First(None)
+  }
+}
+
+
+///
+/// Optional values form a magma keeping the last present value.
+///
+impl<T: Clone + PartialEq> Magma for Last<T> {
+
+  /// The operation keeps the last present value.
+  fn op(&self, other: &Self) -> Self {
+    Last(other.0.clone().or_else(|| self.0.clone()))
+  }
+}
+
+
+///
+/// Optional values form a semigroup keeping the last present value.
+///
+impl<T: Clone + PartialEq> Semigroup for Last<T> {}
+
+
+///
+/// Optional values form a monoid keeping the last present value.
+///
+impl<T: Clone + PartialEq> Monoid for Last<T> {
+
+  /// The identity is no value.
+  fn id() -> Self {
+    Last(None)
+  }
+}
+
+
+///
+/// Optional magma values form a magma, with `None` combining as an
+/// identity.
+///
+impl<S: Magma + Clone> Magma for Option<S> {
+
+  /// The operation combines present values.
+  fn op(&self, other: &Self) -> Self {
+    match (self, other) {
+      (Some(x), Some(y)) => Some(x.op(y)),
+      (Some(x), None) => Some(x.clone()),
+      (None, y) => y.clone(),
+    }
+  }
+}
+
+
+///
+/// Optional semigroup values form a semigroup.
+///
+impl<S: Semigroup + Clone> Semigroup for Option<S> {}
+
+
+///
+/// Optional semigroup values form a monoid, lifting the semigroup
+/// with `None` as an adjoined identity.
+///
+impl<S: Semigroup + Clone> Monoid for Option<S> {
+
+  /// The identity is `None`.
+  fn id() -> Self {
+    None
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "wrapper_test.rs"]
+mod wrapper_test;
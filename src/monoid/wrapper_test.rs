@@ -0,0 +1,160 @@
+use prelude::*;
+use proptest::prelude::*;
+use tests::prelude::*;
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_sum_associativity(xs in any::<Three<i32>>()) {
+    let (x, y, z) = (Sum(xs.0), Sum(xs.1), Sum(xs.2));
+
+    Semigroup::axiom_associativity((&x, &y, &z))
+  }
+
+
+  #[test]
+  fn axiom_sum_identity(x in any::<i64>()) {
+    let x = Sum(x);
+
+    prop_assert!(Monoid::axiom_left_identity(&x));
+    prop_assert!(Monoid::axiom_right_identity(&x));
+  }
+
+
+  #[test]
+  fn axiom_sum_inverse(x in any::<i16>()) {
+    Group::axiom_left_inverse(&Sum(x))
+  }
+
+
+  #[test]
+  fn axiom_product_associativity(xs in any::<Three<u32>>()) {
+    let (x, y, z) = (Product(xs.0), Product(xs.1), Product(xs.2));
+
+    Semigroup::axiom_associativity((&x, &y, &z))
+  }
+
+
+  #[test]
+  fn axiom_product_identity(x in any::<u64>()) {
+    Monoid::axiom_left_identity(&Product(x))
+  }
+
+
+  #[test]
+  fn axiom_min_associativity(xs in any::<Three<i32>>()) {
+    let (x, y, z) = (Min(xs.0), Min(xs.1), Min(xs.2));
+
+    Semigroup::axiom_associativity((&x, &y, &z))
+  }
+
+
+  #[test]
+  fn axiom_min_identity(x in any::<i32>()) {
+    let x = Min(x);
+
+    prop_assert!(Monoid::axiom_left_identity(&x));
+    prop_assert!(Monoid::axiom_right_identity(&x));
+  }
+
+
+  #[test]
+  fn axiom_max_associativity(xs in any::<Three<u8>>()) {
+    let (x, y, z) = (Max(xs.0), Max(xs.1), Max(xs.2));
+
+    Semigroup::axiom_associativity((&x, &y, &z))
+  }
+
+
+  #[test]
+  fn axiom_max_identity(x in any::<u8>()) {
+    let x = Max(x);
+
+    prop_assert!(Monoid::axiom_left_identity(&x));
+    prop_assert!(Monoid::axiom_right_identity(&x));
+  }
+
+
+  #[test]
+  fn axiom_first_associativity(xs in any::<Three<Option<u8>>>()) {
+    let (x, y, z) = (First(xs.0), First(xs.1), First(xs.2));
+
+    Semigroup::axiom_associativity((&x, &y, &z))
+  }
+
+
+  #[test]
+  fn axiom_last_associativity(xs in any::<Three<Option<u8>>>()) {
+    let (x, y, z) = (Last(xs.0), Last(xs.1), Last(xs.2));
+
+    Semigroup::axiom_associativity((&x, &y, &z))
+  }
+
+
+  #[test]
+  fn axiom_option_associativity(xs in any::<Three<Option<i32>>>()) {
+    let lift = |x: Option<i32>| x.map(Max);
+    let (x, y, z) = (lift(xs.0), lift(xs.1), lift(xs.2));
+
+    Semigroup::axiom_associativity((&x, &y, &z))
+  }
+
+
+  #[test]
+  fn axiom_option_identity(x in any::<Option<i32>>()) {
+    let x = x.map(Max);
+
+    prop_assert!(Monoid::axiom_left_identity(&x));
+    prop_assert!(Monoid::axiom_right_identity(&x));
+  }
+}
+
+
+#[test]
+fn identities() {
+  assert_eq!(Min::<i32>::id(), Min(i32::MAX));
+  assert_eq!(Max::<i32>::id(), Max(i32::MIN));
+  assert_eq!(Sum::<i8>::id(), Sum(0));
+  assert_eq!(Product::<u16>::id(), Product(1));
+  assert_eq!(First::<char>::id(), First(None));
+  assert_eq!(Option::<Min<u8>>::id(), None);
+}
+
+
+#[test]
+fn selected_structures() {
+  let (x, y) = (3, 5);
+
+  assert_eq!(Sum(x).op(&Sum(y)), Sum(8));
+  assert_eq!(Product(x).op(&Product(y)), Product(15));
+  assert_eq!(Min(x).op(&Min(y)), Min(3));
+  assert_eq!(Max(x).op(&Max(y)), Max(5));
+  assert_eq!(First(Some(x)).op(&First(Some(y))), First(Some(3)));
+  assert_eq!(Last(Some(x)).op(&Last(Some(y))), Last(Some(5)));
+  assert_eq!(First(None).op(&First(Some(y))), First(Some(5)));
+  assert_eq!(Last(Some(x)).op(&Last(None)), Last(Some(3)));
+}
+
+
+#[test]
+fn option_lifting() {
+  // Strings with no identity of their own: max under ordering.
+  let (x, y) = (Some(Max("b")), Some(Max("a")));
+
+  assert_eq!(x.op(&y), Some(Max("b")));
+  assert_eq!(None.op(&y), y);
+  assert_eq!(x.op(&None), x);
+}
+
+
+#[test]
+fn finite_wrappers() {
+  assert_eq!(Sum::<u8>::size(), 256);
+  assert_eq!(Min::<bool>::elements().collect::<Vec<_>>(),
+             vec![Min(false), Min(true)]);
+  assert!(exhaustive_3::<Product<i8>, _>(Semigroup::axiom_associativity)
+    .is_ok());
+}
@@ -0,0 +1,75 @@
+//!
+//! Equivalence relations for testing structure axioms.
+//!
+//! Structure axioms are equations, e.g. `x+(y+z) = (x+y)+z`, but
+//! what counts as "equal" depends on the carrier type. Exact types
+//! (e.g. integers or rationals) use equality, while floating point
+//! types can only satisfy the axioms with _numeric_ equality, i.e.
+//! equality within an "epsilon" error term.
+//!
+//! Structure traits test their axioms once, parameterised by an
+//! _equivalence_ _relation_. Exact equality is the "zero tolerance"
+//! equivalence, and numeric equality within an error term is the
+//! equivalence used by the "numeric" structure traits.
+//!
+//! # Axioms
+//!
+//! 1. Reflexivity: ∀x ∈ S, x ~ x.
+//! 2. Symmetry: ∀x, y ∈ S, x ~ y ⇒ y ~ x.
+//! 3. Transitivity: ∀x, y, z ∈ S, x ~ y ∧ y ~ z ⇒ x ~ z.
+//!
+//! Numeric equality is not transitive, so it is only an
+//! approximation to an equivalence relation.
+//!
+use numeric::equal::*;
+
+
+///
+/// An equivalence relation on a type.
+///
+pub trait Equivalence<T> {
+
+  /// Are two values equivalent?
+  fn equiv(&self, x: &T, y: &T) -> bool;
+}
+
+
+///
+/// Exact equality, i.e. the zero tolerance equivalence relation.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Exact;
+
+
+///
+/// Numeric equality within an error term.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Within<'a, E: 'a>(pub &'a E);
+
+
+///
+/// Exact equality is an equivalence relation on any type with
+/// equality.
+///
+impl<T: PartialEq> Equivalence<T> for Exact {
+
+  /// Values are equivalent when they are equal.
+  fn equiv(&self, x: &T, y: &T) -> bool {
+    x == y
+  }
+}
+
+
+///
+/// Numeric equality is (approximately) an equivalence relation on
+/// any type with numeric equality.
+///
+impl<'a, T: NumEq> Equivalence<T> for Within<'a, T::Error> {
+
+  /// Values are equivalent when they are numerically equal within the
+  /// error term.
+  fn equiv(&self, x: &T, y: &T) -> bool {
+    x.num_eq(y, self.0)
+  }
+}
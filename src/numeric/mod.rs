@@ -7,8 +7,11 @@
 //!
 //! The `numeric` module provides traits and helper functions for
 //! working with floating point number types. This includes floating
-//! point comparisons with an "epsilon" or error term.
+//! point comparisons with an "epsilon" or error term, and the
+//! _equivalence_ _relations_ structure axioms are tested with.
 //!
 pub mod equal;
 
+pub mod equivalence;
+
 pub mod prelude;
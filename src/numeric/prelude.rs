@@ -1 +1,2 @@
 pub use super::equal::*;
+pub use super::equivalence::*;
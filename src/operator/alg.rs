@@ -0,0 +1,135 @@
+//!
+//! Arithmetic operators for algebraic structures.
+//!
+//! Formulas written with structure trait methods quickly become hard
+//! to read, e.g. `x.mul(&y.add(z))`. The `Alg` wrapper type
+//! implements the standard library operator traits by delegating to
+//! the structure traits of the wrapped type, so the same formula can
+//! be written `x * (y + z)`:
+//!
+//! * `+` and `+=` delegate to `AddMagma::add`.
+//! * `-` and `-=` delegate to `AddGroup::sub`.
+//! * unary `-` delegates to `AddGroup::negate`.
+//! * `*` and `*=` delegate to `MulMagma::mul`.
+//! * `/` and `/=` delegate to `Field::div`.
+//!
+//! Floating point types share their operations with exact types, so
+//! `Alg` wraps them too, e.g. `Alg(2.0) * Alg(0.5)`. The "numeric"
+//! `NumAlg` wrapper is a deprecated alias of `Alg`.
+//!
+//! Operators are implemented for wrapped values and for references
+//! to wrapped values, e.g. `&x * &y`, to avoid cloning values that
+//! are reused.
+//!
+//! # References
+//!
+//! See [references] for formal definitions of the structures.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use std::ops::*;
+use prelude::*;
+
+
+///
+/// A value with arithmetic operators delegating to its structure
+/// traits.
+///
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Alg<T>(pub T);
+
+
+///
+/// A value with arithmetic operators delegating to its "numeric"
+/// structure traits, now the same as its structure traits.
+///
+#[deprecated(note = "use `Alg`, which wraps floating point types too")]
+pub type NumAlg<T> = Alg<T>;
+
+
+///
+/// Operator implementation macro for binary operators.
+///
+/// A macro used to avoid writing repetitive, boilerplate implementations
+/// of a binary operator and its assignment operator, for wrapped values
+/// and references to wrapped values.
+///
+macro_rules! alg_binary_op {
+  ($alg:ident, $bound:ident, $op:ident, $method:ident, $assign:ident,
+   $assign_method:ident, $delegate:path) => {
+    impl<T: $bound> $op for $alg<T> {
+      type Output = Self;
+
+      fn $method(self, other: Self) -> Self {
+        $alg($delegate(&self.0, &other.0))
+      }
+    }
+
+
+    impl<'a, T: $bound> $op<&'a $alg<T>> for &'a $alg<T> {
+      type Output = $alg<T>;
+
+      fn $method(self, other: Self) -> $alg<T> {
+        $alg($delegate(&self.0, &other.0))
+      }
+    }
+
+
+    impl<T: $bound> $assign for $alg<T> {
+      fn $assign_method(&mut self, other: Self) {
+        self.0 = $delegate(&self.0, &other.0);
+      }
+    }
+
+
+    impl<'a, T: $bound> $assign<&'a $alg<T>> for $alg<T> {
+      fn $assign_method(&mut self, other: &'a Self) {
+        self.0 = $delegate(&self.0, &other.0);
+      }
+    }
+  };
+}
+
+
+///
+/// Operator implementation macro for the negation operator.
+///
+/// A macro used to avoid writing repetitive, boilerplate implementations
+/// of negation, for wrapped values and references to wrapped values.
+///
+macro_rules! alg_neg_op {
+  ($alg:ident, $bound:ident, $delegate:path) => {
+    impl<T: $bound> Neg for $alg<T> {
+      type Output = Self;
+
+      fn neg(self) -> Self {
+        $alg($delegate(&self.0))
+      }
+    }
+
+
+    impl<'a, T: $bound> Neg for &'a $alg<T> {
+      type Output = $alg<T>;
+
+      fn neg(self) -> $alg<T> {
+        $alg($delegate(&self.0))
+      }
+    }
+  };
+}
+
+
+// Operators delegating to the structure traits.
+alg_binary_op! {Alg, AddMagma, Add, add, AddAssign, add_assign, AddMagma::add}
+alg_binary_op! {Alg, AddGroup, Sub, sub, SubAssign, sub_assign, AddGroup::sub}
+alg_binary_op! {Alg, MulMagma, Mul, mul, MulAssign, mul_assign, MulMagma::mul}
+alg_binary_op! {Alg, Field, Div, div, DivAssign, div_assign, Field::div}
+alg_neg_op! {Alg, AddGroup, AddGroup::negate}
+
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "alg_test.rs"]
+mod alg_test;
@@ -0,0 +1,118 @@
+use prelude::*;
+use num::bigint::*;
+use num::rational::*;
+use proptest::prelude::*;
+use tests::prelude::*;
+
+
+/// Ring left distributivity, written with operators.
+fn left_distributivity<R: Ring>(xs: Three<Alg<R>>) -> bool {
+  let (x, y, z) = xs;
+
+  &x * &(&y + &z) == &x * &y + &x * &z
+}
+
+
+/// Ring multiplicative negation, written with operators.
+fn mul_negate<R: Ring>(xs: Two<Alg<R>>) -> bool {
+  let (x, y) = xs;
+
+  -&x * -&y == &x * &y
+}
+
+
+prop_compose! {
+
+  /// Generate a random non-zero `BigRational` value.
+  fn rbig_1()(n in any::<i32>(), d in 1..1000i32) -> BigRational {
+    BigRational::new(n.to_bigint().unwrap(), d.to_bigint().unwrap())
+  }
+}
+
+
+proptest! {
+  #![proptest_config(config_with(1000, 500))]
+
+
+  #[test]
+  fn prop_left_distributivity((x, y, z) in i32_3()) {
+    left_distributivity((Alg(x), Alg(y), Alg(z)))
+  }
+
+
+  #[test]
+  fn prop_mul_negate((x, y, _) in i64_3()) {
+    mul_negate((Alg(x), Alg(y)))
+  }
+
+
+  #[test]
+  fn prop_operators((x, y, _) in i32_3()) {
+    prop_assert!(Alg(x) + Alg(y) == Alg(x.add(&y)));
+    prop_assert!(Alg(x) - Alg(y) == Alg(x.sub(&y)));
+    prop_assert!(Alg(x) * Alg(y) == Alg(x.mul(&y)));
+    prop_assert!(-Alg(x) == Alg(x.negate()));
+  }
+
+
+  #[test]
+  fn prop_assign_operators((x, y, _) in i32_3()) {
+    let mut z = Alg(x);
+
+    z += Alg(y);
+    z -= &Alg(y);
+    prop_assert!(z == Alg(x));
+
+    z *= &Alg(y);
+    prop_assert!(z == Alg(x) * Alg(y));
+  }
+
+
+  #[test]
+  fn prop_field_division((ref x, ref y) in (rbig_1(), rbig_1())) {
+    let (x, y) = (Alg(x.clone()), Alg(y.clone()));
+    let mut z = &x * &y;
+
+    z /= &y;
+    prop_assert!(z == x);
+    prop_assert!(&(&x / &y) * &y == x);
+  }
+
+
+  #[test]
+  fn prop_numeric_operators((x, y, z) in (-1e3..1e3, -1e3..1e3, 1.0..1e3)) {
+    let (x, y, z): Three<Alg<f64>> = (Alg(x), Alg(y), Alg(z));
+    let mut w = x;
+
+    w += y;
+    w -= -z;
+
+    prop_assert!((x * (y + z)).0.num_eq(&(x * y + x * z).0, &1e-9));
+    prop_assert!((x / z * z).0.num_eq(&x.0, &1e-9));
+    prop_assert!(w.0.num_eq(&(x + y + z).0, &1e-9));
+  }
+}
+
+
+#[test]
+fn rational_formulas() {
+  let q = |n: i64, d: i64| {
+    Alg(BigRational::new(n.to_bigint().unwrap(), d.to_bigint().unwrap()))
+  };
+  let (x, y) = (q(1, 2), q(1, 3));
+
+  assert_eq!(&x + &y, q(5, 6));
+  assert_eq!(&x - &y, q(1, 6));
+  assert_eq!(&x * &y, q(1, 6));
+  assert_eq!(&x / &y, q(3, 2));
+  assert_eq!(-x, q(-1, 2));
+}
+
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_numeric_wrapper() {
+  let x: NumAlg<f64> = Alg(1.5);
+
+  assert_eq!(x * x - x, Alg(0.75));
+}
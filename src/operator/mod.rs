@@ -0,0 +1,9 @@
+//!
+//! Operator overloading for algebraic structures.
+//!
+//! The `operator` module provides wrapper types that implement the
+//! standard library's arithmetic operator traits (e.g. `std::ops::Add`)
+//! by delegating to the algebraic structure traits, so formulas in
+//! generic code can use infix notation.
+//!
+pub mod alg;
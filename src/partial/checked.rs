@@ -0,0 +1,121 @@
+//!
+//! _Checked_ integers.
+//!
+//! Rust's built-in integer types form rings only with "wrapping"
+//! arithmetic, i.e. `i32` forms the ring of integers modulo `2^32`,
+//! not a subring of the integers (&#x2124;). Code that must behave
+//! like true integers needs arithmetic that reports overflow instead.
+//!
+//! The `Checked` wrapper type uses the built-in _checked_ integer
+//! operations (e.g. `i32::checked_add`), which return `None` on
+//! overflow, so checked integers form _partial_ _rings_. Where
+//! checked arithmetic is defined it agrees with integer arithmetic,
+//! so the partial ring axioms hold.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a ring.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+use partial::partial_magma::*;
+use partial::partial_ring::*;
+
+
+///
+/// A built-in integer with checked (overflow reporting) arithmetic.
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Checked<T>(pub T);
+
+
+///
+/// Checked values of a finite type form a finite type.
+///
+impl<T: Finite> Finite for Checked<T> {
+
+  /// The checked values of the underlying values.
+  fn elements() -> impl Iterator<Item = Self> {
+    T::elements().map(Checked)
+  }
+}
+
+
+///
+/// Trait implementation macro for integer types.
+///
+/// A macro used to avoid writing repetitive, boilerplate partial
+/// structure implementations for checked built-in integer types.
+///
+macro_rules! checked_integer {
+  ($type:ty) => {
+    impl PartialAddMagma for Checked<$type> {
+
+      /// Addition is checked integer addition.
+      fn partial_add(&self, other: &Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Checked)
+      }
+    }
+
+
+    impl PartialMulMagma for Checked<$type> {
+
+      /// Multiplication is checked integer multiplication.
+      fn partial_mul(&self, other: &Self) -> Option<Self> {
+        self.0.checked_mul(other.0).map(Checked)
+      }
+    }
+
+
+    impl PartialRing for Checked<$type> {
+
+      /// Zero is just integer zero.
+      fn zero() -> Self {
+        Checked(0)
+      }
+
+
+      /// One is just integer one.
+      fn one() -> Self {
+        Checked(1)
+      }
+
+
+      /// Negation is checked integer negation.
+      fn partial_negate(&self) -> Option<Self> {
+        self.0.checked_neg().map(Checked)
+      }
+
+
+      /// Subtraction is checked integer subtraction (defined for
+      /// more values than negation).
+      fn partial_sub(&self, other: &Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Checked)
+      }
+    }
+  };
+
+  ($type:ty, $($others:ty),+) => {
+    checked_integer! {$type}
+    checked_integer! {$($others),+}
+  };
+}
+
+
+// Unsigned checked integer partial rings.
+checked_integer! {
+  u8, u16, u32, u64, u128, usize
+}
+
+
+// Signed checked integer partial rings.
+checked_integer! {
+  i8, i16, i32, i64, i128, isize
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "checked_test.rs"]
+mod checked_test;
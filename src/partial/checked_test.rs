@@ -0,0 +1,108 @@
+use prelude::*;
+use proptest::prelude::*;
+use tests::prelude::*;
+
+
+type C8 = Checked<i8>;
+
+
+prop_compose! {
+
+  /// Generate three random `Checked<i8>` values.
+  fn c8_3()(xs in any::<Three<i8>>()) -> Three<C8> {
+    (Checked(xs.0), Checked(xs.1), Checked(xs.2))
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random `Checked<i64>` values.
+  fn c64_3()(xs in any::<Three<i64>>()) -> Three<Checked<i64>> {
+    (Checked(xs.0), Checked(xs.1), Checked(xs.2))
+  }
+}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_add_associativity((ref x, ref y, ref z) in c8_3()) {
+    PartialAddMagma::axiom_add_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_mul_associativity((ref x, ref y, ref z) in c8_3()) {
+    PartialMulMagma::axiom_mul_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_left_distributivity((ref x, ref y, ref z) in c8_3()) {
+    PartialRing::axiom_left_distributivity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_right_distributivity((ref x, ref y, ref z) in c8_3()) {
+    PartialRing::axiom_right_distributivity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_distributivity_i64((ref x, ref y, ref z) in c64_3()) {
+    prop_assert!(PartialRing::axiom_left_distributivity((x, y, z)));
+    prop_assert!(PartialRing::axiom_right_distributivity((x, y, z)));
+  }
+}
+
+
+#[test]
+fn exhaustive_commutivity() {
+  let add = exhaustive_2::<C8, _>(PartialAddMagma::axiom_add_commutivity);
+  let mul = exhaustive_2::<C8, _>(PartialMulMagma::axiom_mul_commutivity);
+
+  assert_eq!(add, Ok(()));
+  assert_eq!(mul, Ok(()));
+}
+
+
+#[test]
+fn exhaustive_identities() {
+  assert_eq!(exhaustive_1::<C8, _>(C8::axiom_left_add_identity), Ok(()));
+  assert_eq!(exhaustive_1::<C8, _>(C8::axiom_right_add_identity), Ok(()));
+  assert_eq!(exhaustive_1::<C8, _>(C8::axiom_left_mul_identity), Ok(()));
+  assert_eq!(exhaustive_1::<C8, _>(C8::axiom_right_mul_identity), Ok(()));
+  assert_eq!(exhaustive_1::<C8, _>(C8::axiom_negate), Ok(()));
+  assert_eq!(exhaustive_2::<C8, _>(C8::prop_sub), Ok(()));
+}
+
+
+#[test]
+fn overflow_is_undefined() {
+  let (max, one) = (Checked(i8::MAX), Checked(1i8));
+
+  assert_eq!(max.partial_add(&one), None);
+  assert_eq!(max.partial_mul(&Checked(2)), None);
+  assert_eq!(Checked(i8::MIN).partial_negate(), None);
+  assert_eq!(Checked(0u8).partial_sub(&Checked(1)), None);
+  assert_eq!(Checked(-1i8).partial_sub(&Checked(i8::MIN)), Some(max));
+
+  assert!(!max.is_add_defined(&one));
+  assert!(max.is_mul_defined(&one));
+}
+
+
+#[test]
+fn wrapping_is_not_integer() {
+  let (x, y, z) = (100i8, 100i8, -100i8);
+
+  // Wrapping arithmetic "satisfies" associativity modulo 256, but the
+  // intermediate sum 100 + 100 is not an `i8` integer.
+  assert_eq!(x.add(&y).add(&z), 100);
+  assert_eq!(Checked(x).partial_add(&Checked(y)), None);
+  assert!(defined_eq(None, Some(Checked(x))));
+}
@@ -0,0 +1,14 @@
+//!
+//! Algebraic _partial_ structure traits and implementations.
+//!
+//! The `partial` module provides support for types whose operations
+//! are only _partially_ defined, e.g. integer arithmetic that reports
+//! overflow rather than wrapping. The axioms of a partial structure
+//! are those of the total structure it approximates, tested only
+//! where both sides of an axiom are defined.
+//!
+pub mod partial_magma;
+
+pub mod partial_ring;
+
+pub mod checked;
@@ -0,0 +1,111 @@
+//!
+//! Algebraic _partial_ _magma_ traits.
+//!
+//! An algebraic _partial_ _magma_ is a set `S`, equipped with a
+//! _partial_ binary operation, i.e. an operation that is only defined
+//! for some pairs of elements of `S`. For example, the sum of two
+//! `i32` integers is only an `i32` integer when the sum does not
+//! overflow.
+//!
+//! The additive and multiplicative partial magmas here approximate
+//! _commutative_ _semigroups_, so their associativity and
+//! commutativity axioms are tested where the operations are defined.
+//!
+//! # Axioms
+//!
+//! 1. Associativity: ∀x, y, z ∈ S, (x+y)+z = x+(y+z), where both sides
+//!    are defined.
+//! 2. Commutivity: ∀x, y ∈ S, x+y = y+x, and x+y is defined if and
+//!    only if y+x is defined.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a partial magma (or
+//! _partial_ _groupoid_).
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use types::*;
+
+
+///
+/// Are two partial results equal, where both are defined?
+///
+pub fn defined_eq<T: PartialEq>(x: Option<T>, y: Option<T>) -> bool {
+  match (x, y) {
+    (Some(x), Some(y)) => x == y,
+    _ => true,
+  }
+}
+
+
+///
+/// An algebraic _additive partial magma_.
+///
+pub trait PartialAddMagma: Sized + PartialEq {
+
+  /// The partial magma `addition` operation, or `None` where
+  /// addition is undefined.
+  fn partial_add(&self, other: &Self) -> Option<Self>;
+
+
+  /// Test if the addition of two values is defined.
+  fn is_add_defined(&self, other: &Self) -> bool {
+    self.partial_add(other).is_some()
+  }
+
+
+  /// Test the axiom of additive associativity, where defined.
+  fn axiom_add_associativity(xs: Triple<Self>) -> bool {
+    let (x, y, z) = xs;
+
+    let left = x.partial_add(y).and_then(|xy| xy.partial_add(z));
+    let right = y.partial_add(z).and_then(|yz| x.partial_add(&yz));
+
+    defined_eq(left, right)
+  }
+
+
+  /// Test the axiom of additive commutivity.
+  fn axiom_add_commutivity(xs: Pair<Self>) -> bool {
+    let (x, y) = xs;
+
+    x.partial_add(y) == y.partial_add(x)
+  }
+}
+
+
+///
+/// An algebraic _multiplicative partial magma_.
+///
+pub trait PartialMulMagma: Sized + PartialEq {
+
+  /// The partial magma `multiplication` operation, or `None` where
+  /// multiplication is undefined.
+  fn partial_mul(&self, other: &Self) -> Option<Self>;
+
+
+  /// Test if the multiplication of two values is defined.
+  fn is_mul_defined(&self, other: &Self) -> bool {
+    self.partial_mul(other).is_some()
+  }
+
+
+  /// Test the axiom of multiplicative associativity, where defined.
+  fn axiom_mul_associativity(xs: Triple<Self>) -> bool {
+    let (x, y, z) = xs;
+
+    let left = x.partial_mul(y).and_then(|xy| xy.partial_mul(z));
+    let right = y.partial_mul(z).and_then(|yz| x.partial_mul(&yz));
+
+    defined_eq(left, right)
+  }
+
+
+  /// Test the axiom of multiplicative commutivity.
+  fn axiom_mul_commutivity(xs: Pair<Self>) -> bool {
+    let (x, y) = xs;
+
+    x.partial_mul(y) == y.partial_mul(x)
+  }
+}
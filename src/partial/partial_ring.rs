@@ -0,0 +1,120 @@
+//!
+//! Algebraic _partial_ _ring_ traits.
+//!
+//! An algebraic _partial_ _ring_ `S` is an _additive_ and a
+//! _multiplicative_ _partial_ _magma_, with _zero_ `0` and _one_ `1`
+//! identity elements, and a partial _negation_. Partial rings
+//! approximate commutative rings, e.g. the integers, so the ring
+//! axioms are tested where both sides are defined. Identities are
+//! always defined.
+//!
+//! # Axioms
+//!
+//! 1. Magmas: additive and multiplicative partial magma axioms hold.
+//! 2. Identities: ∀x ∈ S, 0+x = x+0 = x, and 1\*x = x\*1 = x.
+//! 3. Negation: ∀x ∈ S, x+(-x) = 0, where -x is defined.
+//! 4. Distributivity (L): x, y, z ∈ S, x\*(y+z) = x\*y + x\*z, where
+//!    both sides are defined.
+//! 5. Distributivity (R): x, y, z ∈ S, (x+y)\*z = x\*z + y\*z, where
+//!    both sides are defined.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a ring.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use types::*;
+use partial::partial_magma::*;
+
+
+///
+/// An algebraic _partial ring_.
+///
+pub trait PartialRing: PartialAddMagma + PartialMulMagma {
+
+  /// Unique zero (additive identity) element.
+  fn zero() -> Self;
+
+
+  /// Unique one (multiplicative identity) element.
+  fn one() -> Self;
+
+
+  /// The partial additive inverse, or `None` where negation is
+  /// undefined.
+  fn partial_negate(&self) -> Option<Self>;
+
+
+  /// The partial subtraction, or `None` where subtraction is
+  /// undefined.
+  fn partial_sub(&self, other: &Self) -> Option<Self> {
+    other.partial_negate().and_then(|y| self.partial_add(&y))
+  }
+
+
+  /// Test the left additive identity axiom.
+  fn axiom_left_add_identity(&self) -> bool {
+    Self::zero().partial_add(self).as_ref() == Some(self)
+  }
+
+
+  /// Test the right additive identity axiom.
+  fn axiom_right_add_identity(&self) -> bool {
+    self.partial_add(&Self::zero()).as_ref() == Some(self)
+  }
+
+
+  /// Test the left multiplicative identity axiom.
+  fn axiom_left_mul_identity(&self) -> bool {
+    Self::one().partial_mul(self).as_ref() == Some(self)
+  }
+
+
+  /// Test the right multiplicative identity axiom.
+  fn axiom_right_mul_identity(&self) -> bool {
+    self.partial_mul(&Self::one()).as_ref() == Some(self)
+  }
+
+
+  /// Test the axiom of negation, where negation is defined.
+  fn axiom_negate(&self) -> bool {
+    let zero = self.partial_negate().and_then(|y| self.partial_add(&y));
+
+    defined_eq(zero, Some(Self::zero()))
+  }
+
+
+  /// Test the axiom of left distributivity, where defined.
+  fn axiom_left_distributivity(xs: Triple<Self>) -> bool {
+    let (x, y, z) = xs;
+
+    let left = y.partial_add(z).and_then(|yz| x.partial_mul(&yz));
+    let right = x.partial_mul(y)
+      .and_then(|xy| x.partial_mul(z).and_then(|xz| xy.partial_add(&xz)));
+
+    defined_eq(left, right)
+  }
+
+
+  /// Test the axiom of right distributivity, where defined.
+  fn axiom_right_distributivity(xs: Triple<Self>) -> bool {
+    let (x, y, z) = xs;
+
+    let left = y.partial_add(z).and_then(|yz| yz.partial_mul(x));
+    let right = y.partial_mul(x)
+      .and_then(|yx| z.partial_mul(x).and_then(|zx| yx.partial_add(&zx)));
+
+    defined_eq(left, right)
+  }
+
+
+  /// Test the property of subtraction, where defined: (x-y)+y = x.
+  fn prop_sub(xs: Pair<Self>) -> bool {
+    let (x, y) = xs;
+
+    let sum = x.partial_sub(y).and_then(|d| d.partial_add(y));
+
+    defined_eq(sum.as_ref(), Some(x))
+  }
+}
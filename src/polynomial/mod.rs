@@ -0,0 +1,9 @@
+//!
+//! Algebraic traits for _polynomials_.
+//!
+//! The `polynomial` module provides a polynomial type with
+//! coefficients in a _field_. These polynomials form a _Euclidean
+//! domain_, so they can be used to build _quotient rings_ such as
+//! the finite fields `GF(p)[x]/(f)`.
+//!
+pub mod polynomial;
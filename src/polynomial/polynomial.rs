@@ -0,0 +1,302 @@
+//!
+//! Algebraic trait implementations for _polynomials_.
+//!
+//! Polynomials in one indeterminate `x`, with coefficients from a
+//! _field_ `F`, form a _commutative_ _ring_ `F[x]`. Polynomial long
+//! division makes `F[x]` a _Euclidean_ _domain_ with the polynomial
+//! degree as the Euclidean function.
+//!
+//! Polynomials are stored as vectors of coefficients, lowest degree
+//! first, without trailing (high degree) zero coefficients. The zero
+//! polynomial has no coefficients at all.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a polynomial ring.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// A polynomial with coefficients from a field.
+///
+#[derive(Clone, Debug)]
+pub struct Polynomial<F> {
+  coeffs: Vec<F>,
+}
+
+
+impl<F: Field + Clone> Polynomial<F> {
+
+  /// A new polynomial from its coefficients, lowest degree first.
+  pub fn new(coeffs: Vec<F>) -> Self {
+    let mut coeffs = coeffs;
+
+    while coeffs.last().is_some_and(|c| c.is_zero()) {
+      coeffs.pop();
+    }
+
+    Polynomial { coeffs }
+  }
+
+
+  /// A constant polynomial.
+  pub fn constant(c: F) -> Self {
+    Self::new(vec![c])
+  }
+
+
+  /// The polynomial indeterminate `x`.
+  pub fn x() -> Self {
+    Self::new(vec![F::zero(), F::one()])
+  }
+
+
+  /// The polynomial coefficients, lowest degree first.
+  pub fn coeffs(&self) -> &[F] {
+    &self.coeffs
+  }
+
+
+  /// The leading (highest degree) coefficient. The zero polynomial
+  /// has a zero leading coefficient.
+  pub fn lead(&self) -> F {
+    self.coeffs.last().cloned().unwrap_or_else(F::zero)
+  }
+
+
+  /// Evaluate the polynomial at a value (using Horner's method).
+  pub fn eval(&self, x: &F) -> F {
+    self.coeffs.iter().rev().fold(F::zero(), |acc, c| acc.mul(x).add(c))
+  }
+}
+
+
+///
+/// Polynomials are equal when their coefficients are equal.
+///
+impl<F: Field + Clone> PartialEq for Polynomial<F> {
+  fn eq(&self, other: &Self) -> bool {
+    self.coeffs == other.coeffs
+  }
+}
+
+
+///
+/// Polynomials form an additive magma.
+///
+impl<F: Field + Clone> AddMagma for Polynomial<F> {
+
+  /// Addition is coefficient-wise addition.
+  fn add(&self, other: &Self) -> Self {
+    let n = self.coeffs.len().max(other.coeffs.len());
+    let zero = F::zero();
+
+    let coeffs = (0..n).map(|i| {
+      let x = self.coeffs.get(i).unwrap_or(&zero);
+      let y = other.coeffs.get(i).unwrap_or(&zero);
+
+      x.add(y)
+    });
+
+    Self::new(coeffs.collect())
+  }
+}
+
+
+///
+/// Polynomials form an additive semigroup.
+///
+impl<F: Field + Clone> AddSemigroup for Polynomial<F> {}
+
+
+///
+/// Polynomials form an additive monoid.
+///
+impl<F: Field + Clone> AddMonoid for Polynomial<F> {
+
+  /// Zero is the polynomial with no coefficients.
+  fn zero() -> Self {
+    Self::new(Vec::new())
+  }
+}
+
+
+///
+/// Polynomials form an additive group.
+///
+impl<F: Field + Clone> AddGroup for Polynomial<F> {
+
+  /// Negation is coefficient-wise negation.
+  fn negate(&self) -> Self {
+    Self::new(self.coeffs.iter().map(|c| c.negate()).collect())
+  }
+}
+
+
+///
+/// Polynomials form an additive commutative group.
+///
+impl<F: Field + Clone> AddComGroup for Polynomial<F> {}
+
+
+///
+/// Polynomials form a multiplicative magma.
+///
+impl<F: Field + Clone> MulMagma for Polynomial<F> {
+
+  /// Multiplication is coefficient convolution.
+  fn mul(&self, other: &Self) -> Self {
+    if self.coeffs.is_empty() || other.coeffs.is_empty() {
+      return Self::zero();
+    }
+
+    let n = self.coeffs.len() + other.coeffs.len() - 1;
+    let mut coeffs = vec![F::zero(); n];
+
+    for (i, x) in self.coeffs.iter().enumerate() {
+      for (j, y) in other.coeffs.iter().enumerate() {
+        coeffs[i + j] = coeffs[i + j].add(&x.mul(y));
+      }
+    }
+
+    Self::new(coeffs)
+  }
+}
+
+
+///
+/// Polynomials form a multiplicative semigroup.
+///
+impl<F: Field + Clone> MulSemigroup for Polynomial<F> {}
+
+
+///
+/// Polynomials form a multiplicative monoid.
+///
+impl<F: Field + Clone> MulMonoid for Polynomial<F> {
+
+  /// One is the constant polynomial `1`.
+  fn one() -> Self {
+    Self::constant(F::one())
+  }
+}
+
+
+///
+/// Polynomials form a ring.
+///
+impl<F: Field + Clone> Ring for Polynomial<F> {}
+
+
+///
+/// Polynomials form a commutative ring.
+///
+impl<F: Field + Clone> ComRing for Polynomial<F> {}
+
+
+///
+/// Polynomials form a Euclidean domain.
+///
+impl<F: Field + Clone> EuclidDomain for Polynomial<F> {
+
+  /// Division is polynomial long division.
+  fn div_rem(&self, other: &Self) -> (Self, Self) {
+    assert!(!other.is_zero(), "polynomial division by zero");
+
+    let m = other.coeffs.len();
+    let inv = other.lead().invert();
+
+    let mut rem = self.coeffs.clone();
+    let mut quot = vec![F::zero(); rem.len().saturating_sub(m) + 1];
+
+    while rem.len() >= m {
+      let shift = rem.len() - m;
+      let c = rem[rem.len() - 1].mul(&inv);
+
+      for (i, y) in other.coeffs.iter().enumerate() {
+        rem[shift + i] = rem[shift + i].sub(&c.mul(y));
+      }
+
+      quot[shift] = c;
+
+      // The leading term is now zero by construction.
+      rem.pop();
+
+      while rem.last().is_some_and(|c| c.is_zero()) {
+        rem.pop();
+      }
+    }
+
+    (Self::new(quot), Self::new(rem))
+  }
+
+
+  /// Degree is the polynomial degree.
+  fn degree(&self) -> usize {
+    self.coeffs.len().saturating_sub(1)
+  }
+
+
+  /// The normal form is the _monic_ associate.
+  fn normal(&self) -> Self {
+    if self.is_zero() {
+      return Self::zero();
+    }
+
+    self.mul(&Self::constant(self.lead().invert()))
+  }
+}
+
+
+///
+/// Polynomials over _finite_ fields have decidable irreducibility.
+///
+impl<F: Field + Clone + Finite> Irreducible for Polynomial<F> {
+
+  /// Irreducibility is tested by trial division by every monic
+  /// polynomial of up to half the degree, so is only practical for
+  /// small fields and degrees.
+  fn is_irreducible(&self) -> bool {
+    if self.coeffs.len() < 2 {
+      return false;
+    }
+
+    let elements: Vec<F> = F::elements().collect();
+
+    (1..=self.degree() / 2).all(|d| {
+      // Odometer over the non-leading coefficients of each divisor.
+      let mut digits = vec![0; d];
+
+      loop {
+        let mut coeffs: Vec<F> =
+          digits.iter().map(|&i| elements[i].clone()).collect();
+
+        coeffs.push(F::one());
+
+        if Self::new(coeffs).divides(self) {
+          return false;
+        }
+
+        match digits.iter().position(|&i| i + 1 < elements.len()) {
+          Some(k) => {
+            digits[k] += 1;
+
+            for i in &mut digits[..k] {
+              *i = 0;
+            }
+          },
+          None => return true,
+        }
+      }
+    })
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "polynomial_test.rs"]
+mod polynomial_test;
@@ -0,0 +1,155 @@
+use prelude::*;
+use tests::prelude::*;
+use proptest::collection::*;
+
+
+/// Polynomials with coefficients in the finite field _GF(7)_.
+type Poly7 = Polynomial<QuotientField<i64>>;
+
+
+prop_compose! {
+
+  /// Generate one random polynomial of degree < 5 over GF(7).
+  fn p7_1()(xs in vec(i64_1(), 0..5)) -> Poly7 {
+    let ideal = Ideal::new(7);
+
+    Polynomial::new(xs.into_iter().map(|x| ideal.field(x).unwrap()).collect())
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate two random polynomials over GF(7).
+  fn p7_2()(ps in (p7_1(), p7_1())) -> Two<Poly7> {
+    ps
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random polynomials over GF(7).
+  fn p7_3()(ps in (p7_1(), p7_1(), p7_1())) -> Three<Poly7> {
+    ps
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate two random real polynomials of degree < 5, with small
+  /// coefficients.
+  fn pf_2()(xs in vec(-10.0..10.0, 0..5), ys in vec(-10.0..10.0, 0..5))
+    -> Two<Polynomial<f64>> {
+    (Polynomial::new(xs), Polynomial::new(ys))
+  }
+}
+
+
+/// Test that evaluation is multiplicative, up to an equivalence.
+fn eval_mul<F, E>(xs: Two<Polynomial<F>>, x: &F, eq: &E) -> bool
+  where F: Field + Clone, E: Equivalence<F> {
+  let (p, q) = xs;
+
+  eq.equiv(&p.mul(&q).eval(x), &p.eval(x).mul(&q.eval(x)))
+}
+
+
+proptest! {
+  #![proptest_config(config_with(1000, 500))]
+
+
+  #[test]
+  fn axiom_add_associativity((ref p, ref q, ref r) in p7_3()) {
+    AddSemigroup::axiom_add_associativity((p, q, r))
+  }
+
+
+  #[test]
+  fn axiom_mul_associativity((ref p, ref q, ref r) in p7_3()) {
+    MulSemigroup::axiom_mul_associativity((p, q, r))
+  }
+
+
+  #[test]
+  fn axiom_left_add_identity(ref p in p7_1()) {
+    AddMonoid::axiom_left_add_identity(p)
+  }
+
+
+  #[test]
+  fn axiom_left_negate(ref p in p7_1()) {
+    AddGroup::axiom_left_negate(p)
+  }
+
+
+  #[test]
+  fn axiom_add_commutivity((ref p, ref q) in p7_2()) {
+    AddComGroup::axiom_add_commutivity((p, q))
+  }
+
+
+  #[test]
+  fn axiom_left_distributivity((ref p, ref q, ref r) in p7_3()) {
+    Ring::axiom_left_distributivity((p, q, r))
+  }
+
+
+  #[test]
+  fn axiom_right_distributivity((ref p, ref q, ref r) in p7_3()) {
+    Ring::axiom_right_distributivity((p, q, r))
+  }
+
+
+  #[test]
+  fn axiom_mul_commutivity((ref p, ref q) in p7_2()) {
+    ComRing::axiom_mul_commutivity((p, q))
+  }
+
+
+  #[test]
+  fn axiom_division((ref p, ref q) in p7_2()) {
+    EuclidDomain::axiom_division((p, q))
+  }
+
+
+  #[test]
+  fn axiom_remainder((ref p, ref q) in p7_2()) {
+    EuclidDomain::axiom_remainder((p, q))
+  }
+
+
+  #[test]
+  fn prop_gcd_divides((ref p, ref q) in p7_2()) {
+    EuclidDomain::prop_gcd_divides((p, q))
+  }
+
+
+  #[test]
+  fn prop_bezout((ref p, ref q) in p7_2()) {
+    EuclidDomain::prop_bezout((p, q))
+  }
+
+
+  #[test]
+  fn prop_eval_mul((ref p, ref q) in p7_2(), ref x in i64_1()) {
+    let x = Ideal::new(7).field(*x).unwrap();
+
+    prop_assert!(p.mul(q).eval(&x) == p.eval(&x).mul(&q.eval(&x)));
+  }
+
+
+  #[test]
+  fn prop_eval_mul_exact((p, q) in p7_2(), ref x in i64_1()) {
+    let x = Ideal::new(7).field(*x).unwrap();
+
+    prop_assert!(eval_mul((p, q), &x, &Exact));
+  }
+
+
+  #[test]
+  fn prop_eval_mul_numeric((p, q) in pf_2(), ref x in -2.0..2.0) {
+    prop_assert!(eval_mul((p, q), x, &Within(&1e-9)));
+  }
+}
@@ -9,34 +9,63 @@ pub use types::*;
 pub use logic::*;
 pub use numeric::*;
 pub use numeric::equal::*;
+pub use numeric::equivalence::*;
 
 
 // Magmas.
 pub use magma::*;
+pub use magma::magma::*;
 pub use magma::add_magma::*;
 pub use magma::mul_magma::*;
 
 
+// Partial structures.
+pub use partial::partial_magma::*;
+pub use partial::partial_ring::*;
+pub use partial::checked::*;
+
+
+// Quasigroups and loops.
+pub use quasigroup::*;
+pub use quasigroup::quasigroup::*;
+pub use quasigroup::loops::*;
+pub use quasigroup::subtraction::*;
+pub use quasigroup::octonion::*;
+
+
 // Semigroups.
 pub use semigroup::*;
+pub use semigroup::semigroup::*;
 pub use semigroup::add_semigroup::*;
 pub use semigroup::mul_semigroup::*;
+pub use semigroup::green::*;
 
 
 // Monoids.
 pub use monoid::*;
+pub use monoid::monoid::*;
 pub use monoid::add_monoid::*;
 pub use monoid::mul_monoid::*;
+pub use monoid::endo::*;
+pub use monoid::transformation::*;
+pub use monoid::wrapper::*;
+pub use monoid::fold::*;
+pub use monoid::parallel::*;
+pub use monoid::scan::*;
 
 
 //Groups.
 pub use group::*;
+pub use group::group::*;
 pub use group::add_group::*;
 pub use group::mul_group::*;
+pub use group::subgroup::*;
+pub use group::quotient_group::*;
 
 
 // Commutative groups.
 pub use com_group::*;
+pub use com_group::com_group::*;
 pub use com_group::add_com_group::*;
 pub use com_group::mul_com_group::*;
 
@@ -53,9 +82,74 @@ pub use com_ring::com_ring::*;
 pub use field::field::*;
 
 
+// Euclidean domains.
+pub use euclid_domain::euclid_domain::*;
+
+
+// Ideals and quotient rings.
+pub use ideal::ideal::*;
+pub use ideal::quotient::*;
+
+
+// Homomorphisms.
+pub use homomorphism::magma_hom::*;
+pub use homomorphism::group_hom::*;
+pub use homomorphism::ring_hom::*;
+pub use homomorphism::reduction::*;
+pub use homomorphism::conjugation::*;
+
+
+// Cayley tables.
+pub use cayley::table::*;
+pub use cayley::element::*;
+pub use cayley::ring_table::*;
+
+
+// Finite types.
+pub use finite::finite::*;
+pub use finite::exhaustive::*;
+pub use finite::classify::*;
+
+
+// Finite groups.
+pub use finite_group::finite_group::*;
+pub use finite_group::cyclic::*;
+pub use finite_group::dihedral::*;
+pub use finite_group::klein_four::*;
+pub use finite_group::quaternion::*;
+
+
+// Free structures.
+pub use free::free_monoid::*;
+pub use free::free_group::*;
+
+
+// Group presentations.
+pub use presentation::permutation::*;
+pub use presentation::presentation::*;
+pub use presentation::rewriting::*;
+pub use presentation::todd_coxeter::*;
+
+
+// Finite automata.
+pub use automata::transition::*;
+pub use automata::dfa::*;
+
+
+// Range query trees.
+pub use range::segment_tree::*;
+pub use range::fenwick_tree::*;
+
+
+// Operator wrappers.
+pub use operator::alg::*;
+
+
 // Other number types.
 pub use complex::*;
 pub use complex::complex::*;
 pub use rational::*;
 pub use rational::rational::*;
+pub use polynomial::*;
+pub use polynomial::polynomial::*;
 
@@ -0,0 +1,13 @@
+//!
+//! Finitely _presented_ groups.
+//!
+//! A _presentation_ `⟨A | R⟩` defines a group by a set of generators
+//! `A` and a set of _relators_ `R`, i.e. words in the free group over
+//! `A` that are equal to the identity. The presented group is the
+//! quotient of the free group by the smallest normal subgroup
+//! containing the relators.
+//!
+pub mod permutation;
+pub mod presentation;
+pub mod rewriting;
+pub mod todd_coxeter;
@@ -0,0 +1,137 @@
+//!
+//! The algebraic _permutation_ group.
+//!
+//! A _permutation_ is a bijection from a set `{0, 1, 2, ...}` to
+//! itself. Permutations that move finitely many points form a group
+//! under composition, with the identity permutation as identity and
+//! the inverse bijection as inverse.
+//!
+//! Permutations here act on the _right_, i.e. the product `p·q` is
+//! the permutation applying `p` then `q`. This matches the action of
+//! group elements on cosets in a coset table.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a permutation group.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// A permutation of `{0, 1, 2, ...}` that moves finitely many
+/// points.
+///
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Permutation {
+  images: Vec<usize>,
+}
+
+
+impl Permutation {
+
+  /// A new permutation from the images of points `0..n`, with points
+  /// from `n` fixed. Panics if the images are not a bijection.
+  pub fn new(mut images: Vec<usize>) -> Self {
+    let mut seen = vec![false; images.len()];
+
+    for &x in &images {
+      assert!(x < images.len() && !seen[x], "images must be a bijection");
+      seen[x] = true;
+    }
+
+    while images.last().is_some_and(|&x| x == images.len() - 1) {
+      images.pop();
+    }
+
+    Permutation { images }
+  }
+
+
+  /// The permutation cycling the points `(x1 x2 ... xn)`. Panics if
+  /// the cycle points are not distinct.
+  pub fn cycle(points: &[usize]) -> Self {
+    let n = points.iter().max().map_or(0, |&x| x + 1);
+    let mut images: Vec<usize> = (0..n).collect();
+
+    for (i, &x) in points.iter().enumerate() {
+      images[x] = points[(i + 1) % points.len()];
+    }
+
+    Self::new(images)
+  }
+
+
+  /// The image of a point under the permutation.
+  pub fn apply(&self, x: usize) -> usize {
+    self.images.get(x).cloned().unwrap_or(x)
+  }
+
+
+  /// The smallest `n` such that the permutation fixes all points from
+  /// `n` onwards.
+  pub fn degree(&self) -> usize {
+    self.images.len()
+  }
+}
+
+
+///
+/// Permutations form a magma.
+///
+impl Magma for Permutation {
+
+  /// The operation is composition, applying `self` then `other`.
+  fn op(&self, other: &Self) -> Self {
+    let n = self.degree().max(other.degree());
+
+    Self::new((0..n).map(|x| other.apply(self.apply(x))).collect())
+  }
+}
+
+
+///
+/// Permutations form a semigroup.
+///
+impl Semigroup for Permutation {}
+
+
+///
+/// Permutations form a monoid.
+///
+impl Monoid for Permutation {
+
+  /// The identity permutation fixes all points.
+  fn id() -> Self {
+    Permutation { images: Vec::new() }
+  }
+}
+
+
+///
+/// Permutations form a group.
+///
+impl Group for Permutation {
+
+  /// The inverse is the inverse bijection.
+  fn inverse(&self) -> Self {
+    let mut images = vec![0; self.degree()];
+
+    for (x, &y) in self.images.iter().enumerate() {
+      images[y] = x;
+    }
+
+    Permutation { images }
+  }
+}
+
+
+// Groups are loops.
+group_loop! {[] Permutation}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "permutation_test.rs"]
+mod permutation_test;
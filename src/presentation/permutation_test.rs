@@ -0,0 +1,92 @@
+use prelude::*;
+use proptest::prelude::*;
+use tests::prelude::*;
+
+
+prop_compose! {
+
+  /// Generate one random permutation of `{0, 1, ..., 7}`.
+  fn perm_1()(xs in Just((0..8).collect::<Vec<usize>>()).prop_shuffle())
+    -> Permutation {
+    Permutation::new(xs)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random permutations.
+  fn perm_3()(xs in (perm_1(), perm_1(), perm_1())) -> Three<Permutation> {
+    xs
+  }
+}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_associativity((ref x, ref y, ref z) in perm_3()) {
+    Semigroup::axiom_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_left_identity((ref x, _, _) in perm_3()) {
+    Monoid::axiom_left_identity(x)
+  }
+
+
+  #[test]
+  fn axiom_right_identity((ref x, _, _) in perm_3()) {
+    Monoid::axiom_right_identity(x)
+  }
+
+
+  #[test]
+  fn axiom_left_inverse((ref x, _, _) in perm_3()) {
+    Group::axiom_left_inverse(x)
+  }
+
+
+  #[test]
+  fn axiom_right_inverse((ref x, _, _) in perm_3()) {
+    Group::axiom_right_inverse(x)
+  }
+
+
+  #[test]
+  fn prop_right_action((ref x, ref y, _) in perm_3(), p in 0..10usize) {
+    prop_assert!(x.op(y).apply(p) == y.apply(x.apply(p)));
+  }
+}
+
+
+#[test]
+fn cycles() {
+  let (a, b) = (Permutation::cycle(&[0, 1, 2]), Permutation::cycle(&[0, 1]));
+
+  assert_eq!(a.apply(2), 0);
+  assert_eq!(a.apply(5), 5);
+  assert_eq!(a.op(&a).op(&a), Permutation::id());
+  assert_eq!(a.inverse(), Permutation::cycle(&[2, 1, 0]));
+  assert_ne!(a.op(&b), b.op(&a));
+}
+
+
+#[test]
+fn fixed_points() {
+  let p = Permutation::new(vec![1, 0, 2, 3]);
+
+  assert_eq!(p.degree(), 2);
+  assert_eq!(p, Permutation::cycle(&[0, 1]));
+  assert_eq!(Permutation::new(vec![0, 1, 2]), Permutation::id());
+}
+
+
+#[test]
+#[should_panic]
+fn not_bijection() {
+  Permutation::new(vec![0, 2, 2]);
+}
@@ -0,0 +1,109 @@
+//!
+//! Finite group _presentations_.
+//!
+//! A _presentation_ `⟨a1, ..., an | r1, ..., rm⟩` has generators
+//! numbered `0..n` and relators given as words in the free group over
+//! the generators. For example the dihedral group of order `2n` has
+//! the presentation `⟨a, b | a^n, b^2, (ab)^2⟩`.
+//!
+//! The group defined by a presentation is explored by _coset_
+//! _enumeration_, which (when the group is finite) computes its
+//! order and a faithful permutation representation. Knuth-Bendix
+//! completion of a presentation (when it terminates) gives normal
+//! forms deciding equality of group elements.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a group presentation.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// A finite presentation of a group by generators and relators.
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Presentation {
+  generators: usize,
+  relators: Vec<FreeGroup<usize>>,
+}
+
+
+impl Presentation {
+
+  /// A new presentation with generators `0..generators` and the given
+  /// relators. Panics if a relator uses an unknown generator.
+  pub fn new(generators: usize, relators: Vec<FreeGroup<usize>>) -> Self {
+    let letters = relators.iter().flat_map(|r| r.letters());
+
+    for &(a, _) in letters {
+      assert!(a < generators, "relator generator out of range");
+    }
+
+    Presentation { generators, relators }
+  }
+
+
+  /// The free group word of a single generator.
+  pub fn generator(a: usize) -> FreeGroup<usize> {
+    FreeGroup::generator(a)
+  }
+
+
+  /// The number of generators.
+  pub fn generators(&self) -> usize {
+    self.generators
+  }
+
+
+  /// The presentation relators.
+  pub fn relators(&self) -> &[FreeGroup<usize>] {
+    &self.relators
+  }
+
+
+  /// Enumerate the cosets of the trivial subgroup, i.e. the group
+  /// elements, defining at most `limit` cosets along the way.
+  pub fn enumerate(&self, limit: usize)
+    -> Result<CosetTable, EnumerationError> {
+    CosetTable::enumerate(self, &[], limit)
+  }
+
+
+  /// Enumerate the cosets of the subgroup generated by `subgroup`
+  /// words, defining at most `limit` cosets along the way.
+  pub fn enumerate_cosets(&self, subgroup: &[FreeGroup<usize>], limit: usize)
+    -> Result<CosetTable, EnumerationError> {
+    CosetTable::enumerate(self, subgroup, limit)
+  }
+
+
+  /// The order of the presented group, defining at most `limit`
+  /// cosets during enumeration.
+  pub fn order(&self, limit: usize) -> Result<usize, EnumerationError> {
+    self.enumerate(limit).map(|table| table.len())
+  }
+
+
+  /// A confluent rewriting system for the presented group, allowing
+  /// at most `limit` rules. Generator `a` is rewriting letter `2a`
+  /// and its inverse is letter `2a + 1`.
+  pub fn rewriting_system(&self, limit: usize)
+    -> Result<RewritingSystem, CompletionError> {
+    let letters = |w: &FreeGroup<usize>| -> Word {
+      w.letters().iter().map(|&(a, i)| 2 * a + i as usize).collect()
+    };
+    let inverses = (0..2 * self.generators).map(|x| (vec![x, x ^ 1], vec![]));
+    let relators = self.relators.iter().map(|r| (letters(r), vec![]));
+
+    RewritingSystem::new(inverses.chain(relators).collect()).complete(limit)
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "presentation_test.rs"]
+mod presentation_test;
@@ -0,0 +1,73 @@
+use prelude::*;
+
+
+/// The dihedral group of order `2n`, `⟨a, b | a^n, b^2, (ab)^2⟩`.
+fn dihedral(n: i64) -> Presentation {
+  let (a, b) = (Presentation::generator(0), Presentation::generator(1));
+
+  Presentation::new(2, vec![a.pow(n), b.pow(2), a.op(&b).pow(2)])
+}
+
+
+/// The quaternion group, `⟨a, b | a^4, a^2 b^-2, b^-1 a b a⟩`.
+fn quaternion() -> Presentation {
+  let (a, b) = (Presentation::generator(0), Presentation::generator(1));
+  let relators = vec![
+    a.pow(4),
+    a.pow(2).op(&b.pow(-2)),
+    b.inverse().op(&a).op(&b).op(&a),
+  ];
+
+  Presentation::new(2, relators)
+}
+
+
+#[test]
+fn dihedral_orders() {
+  for n in 1..10 {
+    assert_eq!(dihedral(n).order(1000), Ok(2 * n as usize));
+  }
+}
+
+
+#[test]
+fn quaternion_order() {
+  assert_eq!(quaternion().order(1000), Ok(8));
+}
+
+
+#[test]
+fn symmetric_orders() {
+  let (a, b) = (Presentation::generator(0), Presentation::generator(1));
+  let s4 = Presentation::new(2, vec![a.pow(2), b.pow(3), a.op(&b).pow(4)]);
+  let a5 = Presentation::new(2, vec![a.pow(2), b.pow(3), a.op(&b).pow(5)]);
+
+  assert_eq!(s4.order(1000), Ok(24));
+  assert_eq!(a5.order(1000), Ok(60));
+}
+
+
+#[test]
+fn cyclic_orders() {
+  let a = Presentation::generator(0);
+
+  for n in 1..20 {
+    assert_eq!(Presentation::new(1, vec![a.pow(n)]).order(100), Ok(n as usize));
+  }
+  assert_eq!(Presentation::new(0, vec![]).order(1), Ok(1));
+}
+
+
+#[test]
+fn coset_limit() {
+  let free = Presentation::new(2, vec![]);
+
+  assert_eq!(free.order(500), Err(EnumerationError::CosetLimit(500)));
+}
+
+
+#[test]
+#[should_panic]
+fn unknown_generator() {
+  Presentation::new(1, vec![Presentation::generator(1)]);
+}
@@ -0,0 +1,486 @@
+//!
+//! String _rewriting_ systems and _Knuth-Bendix_ completion.
+//!
+//! A _rewriting_ _system_ over an alphabet `{0, 1, ..., n-1}` is a
+//! set of rules `l -> r` between words, oriented so that `r` is less
+//! than `l` in the _shortlex_ order (shorter words first, then
+//! lexicographically). A word is _reduced_ by repeatedly replacing
+//! rule left hand sides with right hand sides until no rule applies.
+//!
+//! A system is _confluent_ when every word has a unique reduced
+//! word, its _normal_ _form_. _Knuth-Bendix_ completion adds rules
+//! resolving _critical_ _pairs_ (overlaps of rule left hand sides)
+//! until the system is confluent. Completion may not terminate, so
+//! callers supply a rule limit.
+//!
+//! A confluent system for a monoid or group presentation decides
+//! equality of elements: two words are equal in the presented
+//! structure exactly when their normal forms are equal.
+//!
+//! # Identities
+//!
+//! As with quotient rings, the identity element has no way of
+//! knowing its rewriting system, so it is the empty word with no
+//! system, and adopts the system of any element it is combined with.
+//! Elements of different rewriting systems are never equal, and
+//! combining them panics.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of Knuth-Bendix
+//! completion.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use std::fmt;
+use std::rc::Rc;
+use std::error::Error;
+use std::cmp::Ordering;
+use prelude::*;
+
+
+///
+/// A word over the alphabet `{0, 1, ..., n-1}`.
+///
+pub type Word = Vec<usize>;
+
+
+///
+/// The _shortlex_ order of words, i.e. shorter words first, with
+/// words of equal length ordered lexicographically.
+///
+pub fn shortlex(x: &[usize], y: &[usize]) -> Ordering {
+  x.len().cmp(&y.len()).then_with(|| x.cmp(y))
+}
+
+
+///
+/// The reasons Knuth-Bendix completion can fail.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CompletionError {
+
+  /// Completion needed more rules than the given limit.
+  RuleLimit(usize),
+}
+
+
+impl fmt::Display for CompletionError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      CompletionError::RuleLimit(n) =>
+        write!(f, "Knuth-Bendix completion exceeded {} rules", n),
+    }
+  }
+}
+
+
+impl Error for CompletionError {}
+
+
+///
+/// A string rewriting system with shortlex oriented rules.
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RewritingSystem {
+  rules: Vec<(Word, Word)>,
+}
+
+
+impl RewritingSystem {
+
+  /// A new rewriting system from relations between words, orienting
+  /// each relation by shortlex order and dropping trivial relations.
+  pub fn new(relations: Vec<(Word, Word)>) -> Self {
+    let mut rules = Vec::new();
+
+    for (x, y) in relations {
+      push_rule(&mut rules, x, y);
+    }
+
+    RewritingSystem { rules }
+  }
+
+
+  /// The rewriting rules `(l, r)`, with `r` less than `l` in shortlex
+  /// order.
+  pub fn rules(&self) -> &[(Word, Word)] {
+    &self.rules
+  }
+
+
+  /// Reduce a word by applying rules until none apply.
+  pub fn reduce(&self, word: &[usize]) -> Word {
+    let mut word = word.to_vec();
+
+    'rewrite: loop {
+      for (l, r) in &self.rules {
+        if let Some(p) = position(&word, l) {
+          word.splice(p..p + l.len(), r.iter().cloned());
+          continue 'rewrite;
+        }
+      }
+      return word;
+    }
+  }
+
+
+  /// Test a word is reduced, i.e. no rule applies to it.
+  pub fn is_reduced(&self, word: &[usize]) -> bool {
+    self.rules.iter().all(|(l, _)| position(word, l).is_none())
+  }
+
+
+  /// Test all critical pairs of the system reduce to the same word.
+  pub fn is_confluent(&self) -> bool {
+    self.critical_pairs().iter().all(|(x, y)| {
+      self.reduce(x) == self.reduce(y)
+    })
+  }
+
+
+  /// Complete the system with Knuth-Bendix completion, allowing at
+  /// most `limit` rules.
+  pub fn complete(&self, limit: usize) -> Result<Self, CompletionError> {
+    let mut system = self.interreduce();
+
+    loop {
+      let mut added = false;
+
+      for (x, y) in system.critical_pairs() {
+        let (x, y) = (system.reduce(&x), system.reduce(&y));
+
+        if x != y {
+          push_rule(&mut system.rules, x, y);
+          added = true;
+
+          if system.rules.len() > limit {
+            return Err(CompletionError::RuleLimit(limit));
+          }
+        }
+      }
+
+      if !added {
+        return Ok(system);
+      }
+
+      system = system.interreduce();
+    }
+  }
+
+
+  /// The reduced words over `letters` of at most `max_len` letters,
+  /// in shortlex order.
+  pub fn normal_forms(&self, letters: usize, max_len: usize) -> Vec<Word> {
+    let mut words = vec![Vec::new()];
+    let mut last = 0;
+
+    for _ in 0..max_len {
+      let next = words.len();
+
+      for i in last..next {
+        for a in 0..letters {
+          let mut word = words[i].clone();
+          word.push(a);
+
+          if self.is_reduced(&word) {
+            words.push(word);
+          }
+        }
+      }
+
+      last = next;
+    }
+
+    words
+  }
+
+
+  /// The critical pairs from overlaps and inclusions of rule left
+  /// hand sides.
+  fn critical_pairs(&self) -> Vec<(Word, Word)> {
+    let mut pairs = Vec::new();
+
+    for (i, (l1, r1)) in self.rules.iter().enumerate() {
+      for (j, (l2, r2)) in self.rules.iter().enumerate() {
+        for k in 1..l1.len().min(l2.len()) {
+          if l1[l1.len() - k..] == l2[..k] {
+            pairs.push((
+              [&r1[..], &l2[k..]].concat(),
+              [&l1[..l1.len() - k], &r2[..]].concat(),
+            ));
+          }
+        }
+
+        if i != j {
+          if let Some(p) = position(l1, l2) {
+            pairs.push((
+              r1.clone(),
+              [&l1[..p], &r2[..], &l1[p + l2.len()..]].concat(),
+            ));
+          }
+        }
+      }
+    }
+
+    pairs
+  }
+
+
+  /// An equivalent system where no rule left hand side is reducible
+  /// by other rules, and all right hand sides are reduced.
+  fn interreduce(&self) -> Self {
+    let mut rules = self.rules.clone();
+    let mut i = 0;
+
+    while i < rules.len() {
+      let (l, r) = rules.remove(i);
+      let others = RewritingSystem { rules: rules.clone() };
+
+      if others.is_reduced(&l) {
+        rules.insert(i, (l, others.reduce(&r)));
+        i += 1;
+      }
+      else {
+        push_rule(&mut rules, others.reduce(&l), others.reduce(&r));
+        i = 0;
+      }
+    }
+
+    RewritingSystem { rules }
+  }
+}
+
+
+/// Add the shortlex oriented rule of a non-trivial relation.
+fn push_rule(rules: &mut Vec<(Word, Word)>, x: Word, y: Word) {
+  match shortlex(&x, &y) {
+    Ordering::Greater => rules.push((x, y)),
+    Ordering::Less => rules.push((y, x)),
+    Ordering::Equal => {}
+  }
+}
+
+
+/// The position of the first occurrence of a sub-word in a word.
+fn position(word: &[usize], sub: &[usize]) -> Option<usize> {
+  if sub.len() > word.len() {
+    return None;
+  }
+  (0..=word.len() - sub.len()).find(|&p| word[p..p + sub.len()] == *sub)
+}
+
+
+///
+/// An element of a finitely presented monoid, represented by its
+/// normal form in a confluent rewriting system.
+///
+#[derive(Clone, Debug)]
+pub struct PresentedMonoid {
+  word: Word,
+  system: Option<Rc<RewritingSystem>>,
+}
+
+
+impl PresentedMonoid {
+
+  /// The element of a word in a (confluent) rewriting system.
+  pub fn new(word: &[usize], system: &Rc<RewritingSystem>) -> Self {
+    PresentedMonoid {
+      word: system.reduce(word),
+      system: Some(Rc::clone(system)),
+    }
+  }
+
+
+  /// The element normal form.
+  pub fn word(&self) -> &[usize] {
+    &self.word
+  }
+
+
+  /// The rewriting system of the element, or `None` for the identity
+  /// (which belongs to every presented monoid).
+  pub fn system(&self) -> Option<&Rc<RewritingSystem>> {
+    self.system.as_ref()
+  }
+
+
+  /// The rewriting system common to two elements. Panics if the
+  /// elements belong to different rewriting systems.
+  fn common(&self, other: &Self) -> Option<Rc<RewritingSystem>> {
+    match (&self.system, &other.system) {
+      (Some(s), Some(t)) => {
+        assert!(same_system(s, t), "elements from different rewriting systems");
+
+        Some(Rc::clone(s))
+      },
+      (Some(s), None) | (None, Some(s)) => Some(Rc::clone(s)),
+      (None, None) => None,
+    }
+  }
+}
+
+
+/// Test two shared rewriting systems are the same system.
+fn same_system(s: &Rc<RewritingSystem>, t: &Rc<RewritingSystem>) -> bool {
+  Rc::ptr_eq(s, t) || s == t
+}
+
+
+///
+/// Presented monoid elements are equal when they belong to the same
+/// rewriting system and their normal forms are equal.
+///
+impl PartialEq for PresentedMonoid {
+  fn eq(&self, other: &Self) -> bool {
+    let systems = match (&self.system, &other.system) {
+      (Some(s), Some(t)) => same_system(s, t),
+      _ => true,
+    };
+
+    systems && self.word == other.word
+  }
+}
+
+
+///
+/// Presented monoid elements form a magma.
+///
+impl Magma for PresentedMonoid {
+
+  /// The operation is the normal form of word concatenation.
+  fn op(&self, other: &Self) -> Self {
+    let word = self.word.op(&other.word);
+
+    match self.common(other) {
+      Some(system) => Self::new(&word, &system),
+      None => Self::id(),
+    }
+  }
+}
+
+
+///
+/// Presented monoid elements form a semigroup.
+///
+impl Semigroup for PresentedMonoid {}
+
+
+///
+/// Presented monoid elements form a monoid.
+///
+impl Monoid for PresentedMonoid {
+
+  /// The identity is the empty word.
+  fn id() -> Self {
+    PresentedMonoid { word: Vec::new(), system: None }
+  }
+}
+
+
+///
+/// An element of a finitely presented group, represented by its
+/// normal form in a confluent rewriting system.
+///
+/// Generator `a` of the group presentation is letter `2a` of the
+/// rewriting system alphabet and its inverse is letter `2a + 1` (see
+/// [`Presentation::rewriting_system`]).
+///
+/// [`Presentation::rewriting_system`]:
+/// crate::presentation::presentation::Presentation::rewriting_system
+///
+#[derive(Clone, Debug)]
+pub struct PresentedGroup {
+  element: PresentedMonoid,
+}
+
+
+impl PresentedGroup {
+
+  /// The element of a free group word in a (confluent) rewriting
+  /// system of a group presentation.
+  pub fn new(word: &FreeGroup<usize>, system: &Rc<RewritingSystem>) -> Self {
+    let letters: Word =
+      word.letters().iter().map(|&(a, i)| 2 * a + i as usize).collect();
+
+    PresentedGroup { element: PresentedMonoid::new(&letters, system) }
+  }
+
+
+  /// The element normal form, as rewriting system letters.
+  pub fn word(&self) -> &[usize] {
+    self.element.word()
+  }
+}
+
+
+///
+/// Presented group elements are equal when they belong to the same
+/// rewriting system and their normal forms are equal.
+///
+impl PartialEq for PresentedGroup {
+  fn eq(&self, other: &Self) -> bool {
+    self.element == other.element
+  }
+}
+
+
+///
+/// Presented group elements form a magma.
+///
+impl Magma for PresentedGroup {
+
+  /// The operation is the normal form of word concatenation.
+  fn op(&self, other: &Self) -> Self {
+    PresentedGroup { element: self.element.op(&other.element) }
+  }
+}
+
+
+///
+/// Presented group elements form a semigroup.
+///
+impl Semigroup for PresentedGroup {}
+
+
+///
+/// Presented group elements form a monoid.
+///
+impl Monoid for PresentedGroup {
+
+  /// The identity is the empty word.
+  fn id() -> Self {
+    PresentedGroup { element: PresentedMonoid::id() }
+  }
+}
+
+
+///
+/// Presented group elements form a group.
+///
+impl Group for PresentedGroup {
+
+  /// The inverse is the normal form of the reversed word with each
+  /// letter inverted.
+  fn inverse(&self) -> Self {
+    let word: Word = self.word().iter().rev().map(|a| a ^ 1).collect();
+
+    match self.element.system {
+      Some(ref system) => {
+        PresentedGroup { element: PresentedMonoid::new(&word, system) }
+      }
+      None => Self::id(),
+    }
+  }
+}
+
+
+// Groups are loops.
+group_loop! {[] PresentedGroup}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "rewriting_test.rs"]
+mod rewriting_test;
@@ -0,0 +1,223 @@
+use std::rc::Rc;
+use std::cmp::Ordering;
+use prelude::*;
+use proptest::prelude::*;
+use tests::prelude::*;
+
+
+/// The dihedral group of order 8, `⟨a, b | a^4, b^2, (ab)^2⟩`.
+fn dihedral() -> Presentation {
+  let (a, b) = (Presentation::generator(0), Presentation::generator(1));
+
+  Presentation::new(2, vec![a.pow(4), b.pow(2), a.op(&b).pow(2)])
+}
+
+
+thread_local! {
+
+  /// The completed rewriting system of the dihedral group of order 8,
+  /// shared between test cases.
+  static SYSTEM: Rc<RewritingSystem> =
+    Rc::new(dihedral().rewriting_system(100).unwrap());
+}
+
+
+/// The completed rewriting system of the dihedral group of order 8.
+fn dihedral_system() -> Rc<RewritingSystem> {
+  SYSTEM.with(Rc::clone)
+}
+
+
+prop_compose! {
+
+  /// Generate one random free group word over two generators.
+  fn word_1()(xs in prop::collection::vec((0..2usize, any::<bool>()), 0..10))
+    -> FreeGroup<usize> {
+    FreeGroup::new(xs)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random free group words over two generators.
+  fn word_3()(xs in (word_1(), word_1(), word_1()))
+    -> Three<FreeGroup<usize>> {
+    xs
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random dihedral group elements.
+  fn d8_3()(xs in word_3()) -> Three<PresentedGroup> {
+    let system = dihedral_system();
+    let element = |w| PresentedGroup::new(w, &system);
+
+    (element(&xs.0), element(&xs.1), element(&xs.2))
+  }
+}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_associativity((ref x, ref y, ref z) in d8_3()) {
+    Semigroup::axiom_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_left_identity((ref x, _, _) in d8_3()) {
+    Monoid::axiom_left_identity(x)
+  }
+
+
+  #[test]
+  fn axiom_right_identity((ref x, _, _) in d8_3()) {
+    Monoid::axiom_right_identity(x)
+  }
+
+
+  #[test]
+  fn axiom_left_inverse((ref x, _, _) in d8_3()) {
+    Group::axiom_left_inverse(x)
+  }
+
+
+  #[test]
+  fn axiom_right_inverse((ref x, _, _) in d8_3()) {
+    Group::axiom_right_inverse(x)
+  }
+
+
+  #[test]
+  fn prop_reduce_idempotent((ref x, _, _) in d8_3()) {
+    prop_assert!(dihedral_system().reduce(x.word()) == x.word());
+  }
+
+
+  #[test]
+  fn prop_agrees_with_todd_coxeter((ref x, ref y, _) in word_3()) {
+    let table = dihedral().enumerate(100).unwrap();
+    let element = |w| PresentedGroup::new(w, &dihedral_system());
+    let equal = element(x) == element(y);
+
+    prop_assert!(equal == (table.permutation(x) == table.permutation(y)));
+  }
+}
+
+
+#[test]
+fn shortlex_order() {
+  assert_eq!(shortlex(&[1], &[0, 0]), Ordering::Less);
+  assert_eq!(shortlex(&[0, 1], &[1, 0]), Ordering::Less);
+  assert_eq!(shortlex(&[1, 0], &[1, 0]), Ordering::Equal);
+}
+
+
+#[test]
+fn free_commutative_monoid() {
+  let system = RewritingSystem::new(vec![(vec![0, 1], vec![1, 0])]);
+  let complete = system.complete(10).unwrap();
+
+  assert_eq!(complete.rules(), &[(vec![1, 0], vec![0, 1])]);
+  assert_eq!(complete.reduce(&[1, 1, 0, 1, 0]), vec![0, 0, 1, 1, 1]);
+  assert_eq!(complete.normal_forms(2, 4).len(), 15);
+}
+
+
+#[test]
+fn presented_monoid() {
+  let system = RewritingSystem::new(vec![(vec![0, 0, 0], vec![0])]);
+  let system = Rc::new(system.complete(10).unwrap());
+  let a = PresentedMonoid::new(&[0], &system);
+
+  assert_eq!(system.normal_forms(1, 10).len(), 3);
+  assert_eq!(a.op(&a).op(&a), a);
+  assert_ne!(a.op(&a), PresentedMonoid::id());
+  assert_eq!(PresentedMonoid::id().op(&a), a);
+  assert_eq!(PresentedMonoid::new(&[0, 0, 0, 0], &system).word(), &[0, 0]);
+}
+
+
+#[test]
+fn presented_monoid_systems() {
+  let cube = RewritingSystem::new(vec![(vec![0, 0, 0], vec![0])]);
+  let square = RewritingSystem::new(vec![(vec![0, 0], vec![0])]);
+  let (cube, square) = (Rc::new(cube), Rc::new(square));
+  let a = PresentedMonoid::new(&[0], &cube);
+
+  assert_ne!(a, PresentedMonoid::new(&[0], &square));
+  assert_eq!(a, PresentedMonoid::new(&[0], &Rc::new((*cube).clone())));
+  assert_eq!(PresentedMonoid::new(&[], &cube), PresentedMonoid::id());
+  assert_eq!(a.op(&PresentedMonoid::id()).system(), Some(&cube));
+  assert_eq!(PresentedMonoid::id().system(), None);
+}
+
+
+#[test]
+#[should_panic(expected = "different rewriting systems")]
+fn mixed_systems_panic() {
+  let cube = RewritingSystem::new(vec![(vec![0, 0, 0], vec![0])]);
+  let square = RewritingSystem::new(vec![(vec![0, 0], vec![0])]);
+  let a = PresentedMonoid::new(&[0], &Rc::new(cube));
+
+  let _ = a.op(&PresentedMonoid::new(&[0], &Rc::new(square)));
+}
+
+
+#[test]
+fn group_orders() {
+  let (a, b) = (Presentation::generator(0), Presentation::generator(1));
+  let groups = vec![
+    Presentation::new(2, vec![a.pow(3), b.pow(2), a.op(&b).pow(2)]),
+    Presentation::new(2, vec![a.pow(2), b.pow(3), a.op(&b).pow(3)]),
+    Presentation::new(2, vec![
+      a.pow(4), a.pow(2).op(&b.pow(-2)), b.inverse().op(&a).op(&b).op(&a),
+    ]),
+    dihedral(),
+  ];
+
+  for group in &groups {
+    let system = group.rewriting_system(200).unwrap();
+    let order = group.order(1000).unwrap();
+
+    assert!(system.is_confluent());
+    assert_eq!(system.normal_forms(4, order).len(), order);
+  }
+}
+
+
+#[test]
+fn group_equality() {
+  let system = dihedral_system();
+  let (a, b) = (Presentation::generator(0), Presentation::generator(1));
+  let element = |w: &FreeGroup<usize>| PresentedGroup::new(w, &system);
+
+  assert_eq!(element(&a.op(&b)), element(&b.op(&a.pow(3))));
+  assert_eq!(element(&a.pow(5)), element(&a));
+  assert_ne!(element(&a.op(&b)), element(&b.op(&a)));
+  assert_eq!(element(&a).inverse(), element(&a.pow(3)));
+  assert_eq!(PresentedGroup::id().inverse(), PresentedGroup::id());
+}
+
+
+#[test]
+fn rule_limit() {
+  let braid = RewritingSystem::new(vec![(vec![0, 1, 0], vec![1, 0, 1])]);
+
+  assert!(!braid.is_confluent());
+  assert_eq!(braid.complete(20), Err(CompletionError::RuleLimit(20)));
+}
+
+
+#[test]
+fn error_display() {
+  let error = CompletionError::RuleLimit(10);
+
+  assert_eq!(error.to_string(), "Knuth-Bendix completion exceeded 10 rules");
+}
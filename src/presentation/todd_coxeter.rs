@@ -0,0 +1,349 @@
+//!
+//! _Todd-Coxeter_ coset enumeration.
+//!
+//! Coset enumeration builds a _coset_ _table_ for a subgroup `H` of a
+//! finitely presented group `G`, i.e. the action of each generator
+//! (and generator inverse) on the right cosets of `H`. The table is
+//! filled by defining new cosets and tracing relators from every
+//! coset, merging cosets found to be equal (_coincidences_).
+//!
+//! This implementation uses the _HLT_ (Haselgrove, Leech and Trotter)
+//! strategy. Enumeration terminates when `H` has finite index, but
+//! no bound on the number of intermediate cosets is possible in
+//! general, so callers supply a coset limit.
+//!
+//! When `H` is trivial the coset table describes the _regular_
+//! action of `G`, and the generator permutations form a group
+//! isomorphic to `G`.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of coset enumeration.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use std::fmt;
+use std::error::Error;
+use prelude::*;
+
+
+///
+/// The reasons coset enumeration can fail.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EnumerationError {
+
+  /// Enumeration needed more cosets than the given limit.
+  CosetLimit(usize),
+}
+
+
+impl fmt::Display for EnumerationError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      EnumerationError::CosetLimit(n) =>
+        write!(f, "coset enumeration exceeded {} cosets", n),
+    }
+  }
+}
+
+
+impl Error for EnumerationError {}
+
+
+///
+/// A complete coset table for a subgroup of a presented group.
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CosetTable {
+  table: Vec<Vec<usize>>,
+}
+
+
+impl CosetTable {
+
+  /// Enumerate the cosets of the subgroup generated by `subgroup`
+  /// words in the group presented by `presentation`, defining at most
+  /// `limit` cosets.
+  pub fn enumerate(
+    presentation: &Presentation,
+    subgroup: &[FreeGroup<usize>],
+    limit: usize,
+  ) -> Result<Self, EnumerationError> {
+    let mut e = Enumeration::new(presentation.generators(), limit);
+    let words = |ws: &[FreeGroup<usize>]| -> Vec<Vec<usize>> {
+      ws.iter().map(|w| w.letters().iter().map(column).collect()).collect()
+    };
+    let relators = words(presentation.relators());
+
+    for word in &words(subgroup) {
+      e.scan_and_fill(0, word)?;
+    }
+
+    let mut c = 0;
+
+    while c < e.table.len() {
+      for relator in &relators {
+        if e.is_live(c) {
+          e.scan_and_fill(c, relator)?;
+        }
+      }
+
+      for x in 0..e.columns {
+        if e.is_live(c) && e.table[c][x].is_none() {
+          e.define(c, x)?;
+        }
+      }
+
+      c += 1;
+    }
+
+    Ok(e.compact())
+  }
+
+
+  /// The number of cosets, i.e. the subgroup index.
+  pub fn len(&self) -> usize {
+    self.table.len()
+  }
+
+
+  /// Test for an empty table (never true, as the subgroup itself is
+  /// a coset).
+  pub fn is_empty(&self) -> bool {
+    self.table.is_empty()
+  }
+
+
+  /// The coset reached from coset `c` by multiplying by generator
+  /// `a`, or its inverse when `inverted`.
+  pub fn action(&self, c: usize, a: usize, inverted: bool) -> usize {
+    self.table[c][column(&(a, inverted))]
+  }
+
+
+  /// The permutation of cosets by generator `a`.
+  pub fn generator(&self, a: usize) -> Permutation {
+    let images = (0..self.len()).map(|c| self.action(c, a, false));
+
+    Permutation::new(images.collect())
+  }
+
+
+  /// The permutation of cosets by a free group word.
+  pub fn permutation(&self, word: &FreeGroup<usize>) -> Permutation {
+    word.lift(|&a| self.generator(a))
+  }
+
+
+  /// The permutation group generated by the generator permutations.
+  pub fn group(&self) -> Subgroup<Permutation> {
+    let columns = self.table.first().map_or(0, |row| row.len());
+    let gens: Vec<Permutation> =
+      (0..columns / 2).map(|a| self.generator(a)).collect();
+
+    Subgroup::span(&gens)
+  }
+}
+
+
+/// The coset table column of a generator letter.
+fn column(letter: &(usize, bool)) -> usize {
+  2 * letter.0 + letter.1 as usize
+}
+
+
+/// The coset table column of an inverse generator letter.
+fn inverse(x: usize) -> usize {
+  x ^ 1
+}
+
+
+///
+/// The working state of a coset enumeration.
+///
+struct Enumeration {
+  columns: usize,
+  limit: usize,
+  table: Vec<Vec<Option<usize>>>,
+  parent: Vec<usize>,
+}
+
+
+impl Enumeration {
+
+  /// A new enumeration with only the subgroup coset defined.
+  fn new(generators: usize, limit: usize) -> Self {
+    Enumeration {
+      columns: 2 * generators,
+      limit,
+      table: vec![vec![None; 2 * generators]],
+      parent: vec![0],
+    }
+  }
+
+
+  /// Test a coset has not been merged into another.
+  fn is_live(&self, c: usize) -> bool {
+    self.parent[c] == c
+  }
+
+
+  /// The live coset representing coset `c`.
+  fn find(&mut self, c: usize) -> usize {
+    let mut r = c;
+
+    while self.parent[r] != r {
+      r = self.parent[r];
+    }
+
+    let mut c = c;
+
+    while self.parent[c] != r {
+      let next = self.parent[c];
+      self.parent[c] = r;
+      c = next;
+    }
+
+    r
+  }
+
+
+  /// Define a new coset as the image of coset `c` in column `x`.
+  fn define(&mut self, c: usize, x: usize) -> Result<(), EnumerationError> {
+    let d = self.table.len();
+
+    if d >= self.limit {
+      return Err(EnumerationError::CosetLimit(self.limit));
+    }
+
+    self.table.push(vec![None; self.columns]);
+    self.parent.push(d);
+    self.table[c][x] = Some(d);
+    self.table[d][inverse(x)] = Some(c);
+
+    Ok(())
+  }
+
+
+  /// Trace a word from coset `c` forwards and backwards, defining new
+  /// cosets until the word closes, then process any deduction or
+  /// coincidence.
+  fn scan_and_fill(&mut self, c: usize, word: &[usize])
+    -> Result<(), EnumerationError> {
+    if word.is_empty() {
+      return Ok(());
+    }
+
+    // The unscanned part of the word is `word[i..j]`.
+    let (mut f, mut b) = (c, c);
+    let (mut i, mut j) = (0, word.len());
+
+    loop {
+      while i < j && self.table[f][word[i]].is_some() {
+        f = self.table[f][word[i]].unwrap();
+        i += 1;
+      }
+
+      if i == j {
+        if f != b {
+          self.coincidence(f, b);
+        }
+        return Ok(());
+      }
+
+      while j > i && self.table[b][inverse(word[j - 1])].is_some() {
+        b = self.table[b][inverse(word[j - 1])].unwrap();
+        j -= 1;
+      }
+
+      if i == j {
+        if f != b {
+          self.coincidence(f, b);
+        }
+        return Ok(());
+      }
+      else if i + 1 == j {
+        self.table[f][word[i]] = Some(b);
+        self.table[b][inverse(word[i])] = Some(f);
+        return Ok(());
+      }
+      else {
+        self.define(f, word[i])?;
+      }
+    }
+  }
+
+
+  /// Merge two cosets and all cosets consequently equal to them.
+  fn coincidence(&mut self, a: usize, b: usize) {
+    let mut queue = Vec::new();
+
+    self.merge(a, b, &mut queue);
+
+    let mut i = 0;
+
+    while i < queue.len() {
+      let e = queue[i];
+      i += 1;
+
+      for x in 0..self.columns {
+        if let Some(f) = self.table[e][x] {
+          self.table[f][inverse(x)] = None;
+
+          let (e1, f1) = (self.find(e), self.find(f));
+
+          if let Some(g) = self.table[e1][x] {
+            self.merge(f1, g, &mut queue);
+          }
+          else if let Some(g) = self.table[f1][inverse(x)] {
+            self.merge(e1, g, &mut queue);
+          }
+          else {
+            self.table[e1][x] = Some(f1);
+            self.table[f1][inverse(x)] = Some(e1);
+          }
+        }
+      }
+    }
+  }
+
+
+  /// Merge the classes of two cosets, queueing the larger coset.
+  fn merge(&mut self, a: usize, b: usize, queue: &mut Vec<usize>) {
+    let (a, b) = (self.find(a), self.find(b));
+
+    if a != b {
+      let (min, max) = (a.min(b), a.max(b));
+      self.parent[max] = min;
+      queue.push(max);
+    }
+  }
+
+
+  /// The complete table of live cosets, renumbered in order.
+  fn compact(mut self) -> CosetTable {
+    let live: Vec<usize> =
+      (0..self.table.len()).filter(|&c| self.is_live(c)).collect();
+    let mut number = vec![0; self.table.len()];
+
+    for (n, &c) in live.iter().enumerate() {
+      number[c] = n;
+    }
+
+    let table = live.iter().map(|&c| {
+      (0..self.columns).map(|x| {
+        let d = self.table[c][x].expect("complete coset table");
+        number[self.find(d)]
+      }).collect()
+    }).collect();
+
+    CosetTable { table }
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "todd_coxeter_test.rs"]
+mod todd_coxeter_test;
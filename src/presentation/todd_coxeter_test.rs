@@ -0,0 +1,98 @@
+use prelude::*;
+
+
+/// The dihedral group of order `2n`, `⟨a, b | a^n, b^2, (ab)^2⟩`.
+fn dihedral(n: i64) -> Presentation {
+  let (a, b) = (Presentation::generator(0), Presentation::generator(1));
+
+  Presentation::new(2, vec![a.pow(n), b.pow(2), a.op(&b).pow(2)])
+}
+
+
+#[test]
+fn relators_act_trivially() {
+  let d6 = dihedral(6);
+  let table = d6.enumerate(1000).unwrap();
+
+  for relator in d6.relators() {
+    assert_eq!(table.permutation(relator), Permutation::id());
+  }
+}
+
+
+#[test]
+fn regular_representation() {
+  for n in 2..8 {
+    let table = dihedral(n).enumerate(1000).unwrap();
+    let group = table.group();
+
+    assert_eq!(group.order(), table.len());
+    assert!(group.elements().iter().all(|p| p.degree() <= table.len()));
+  }
+}
+
+
+#[test]
+fn inverse_columns() {
+  let table = dihedral(5).enumerate(1000).unwrap();
+
+  for c in 0..table.len() {
+    for a in 0..2 {
+      assert_eq!(table.action(table.action(c, a, false), a, true), c);
+    }
+  }
+}
+
+
+#[test]
+fn subgroup_index() {
+  let d = dihedral(7);
+  let (a, b) = (Presentation::generator(0), Presentation::generator(1));
+
+  assert_eq!(d.enumerate_cosets(&[b], 1000).map(|t| t.len()), Ok(7));
+  assert_eq!(d.enumerate_cosets(&[a.pow(7)], 1000).map(|t| t.len()), Ok(14));
+  assert_eq!(d.enumerate_cosets(&[a], 1000).map(|t| t.len()), Ok(2));
+}
+
+
+#[test]
+fn presented_quaternion() {
+  let (a, b) = (Presentation::generator(0), Presentation::generator(1));
+  let q8 = Presentation::new(2, vec![
+    a.pow(4), a.pow(2).op(&b.pow(-2)), b.inverse().op(&a).op(&b).op(&a),
+  ]);
+  let table = q8.enumerate(1000).unwrap();
+  let (i, j) = (table.generator(0), table.generator(1));
+
+  assert_eq!(table.group().order(), 8);
+  assert_eq!(i.op(&i), j.op(&j));
+  assert_ne!(i.op(&j), j.op(&i));
+}
+
+
+#[test]
+fn infinite_group_limit() {
+  let relator = FreeGroup::new(vec![(1, false), (0, false)]);
+  let z = Presentation::new(2, vec![relator]);
+
+  assert_eq!(z.enumerate(2000).err(), Some(EnumerationError::CosetLimit(2000)));
+}
+
+
+#[test]
+fn trivial_group_scan() {
+  let (a, b) = (Presentation::generator(0), Presentation::generator(1));
+  let trivial = Presentation::new(2, vec![
+    b.inverse().op(&a.pow(-2)), b.pow(-2), a.inverse(),
+  ]);
+
+  assert_eq!(trivial.enumerate(1000).unwrap().group().order(), 1);
+}
+
+
+#[test]
+fn error_display() {
+  let error = EnumerationError::CosetLimit(10);
+
+  assert_eq!(error.to_string(), "coset enumeration exceeded 10 cosets");
+}
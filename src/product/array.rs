@@ -0,0 +1,228 @@
+//!
+//! Algebraic trait implementations for fixed-size _arrays_.
+//!
+//! Arrays `[T; N]` of algebraic structures form the `N`-fold _direct
+//! product_ of the structure with itself, with component-wise
+//! operations and identities. For example `[i64; 3]` forms a ring,
+//! and `[f64; 3]` a numeric additive commutative group (i.e. 3D
+//! vectors under addition).
+//!
+//! As with tuples, direct products of _fields_ are not fields, so
+//! arrays only implement the structure traits up to _commutative_
+//! _ring_. Array elements are _invertible_ when all of their
+//! components are invertible.
+//!
+//! Numeric comparisons of arrays use the component error type for
+//! every component.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a direct product.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+use std::array::from_fn;
+
+
+///
+/// Numeric equality for arrays of numeric types.
+///
+impl<T: NumEq, const N: usize> NumEq for [T; N] {
+
+  /// The numeric error type is the (shared) component error type.
+  type Error = T::Error;
+
+
+  /// Equality is component-wise numeric equality.
+  fn num_eq(&self, other: &Self, eps: &Self::Error) -> bool {
+    self.iter().zip(other.iter()).all(|(x, y)| x.num_eq(y, eps))
+  }
+
+
+  /// Equality within default component error bounds.
+  fn eq(&self, other: &Self) -> bool {
+    self.iter().zip(other.iter()).all(|(x, y)| NumEq::eq(x, y))
+  }
+
+
+  /// Inequality within default component error bounds.
+  fn ne(&self, other: &Self) -> bool {
+    !NumEq::eq(self, other)
+  }
+}
+
+
+///
+/// Arrays of magmas form a magma.
+///
+impl<T: Magma, const N: usize> Magma for [T; N] {
+
+  /// The operation is component-wise.
+  fn op(&self, other: &Self) -> Self {
+    from_fn(|i| self[i].op(&other[i]))
+  }
+}
+
+
+///
+/// Arrays of semigroups form a semigroup.
+///
+impl<T: Semigroup, const N: usize> Semigroup for [T; N] {}
+
+
+///
+/// Arrays of monoids form a monoid.
+///
+impl<T: Monoid, const N: usize> Monoid for [T; N] {
+
+  /// The identity is the array of component identities.
+  fn id() -> Self {
+    from_fn(|_| T::id())
+  }
+}
+
+
+///
+/// Arrays of groups form a group.
+///
+impl<T: Group, const N: usize> Group for [T; N] {
+
+  /// The inverse is component-wise.
+  fn inverse(&self) -> Self {
+    from_fn(|i| self[i].inverse())
+  }
+}
+
+
+// Groups are loops.
+group_loop! {[T: Group, const N: usize] [T; N]}
+
+
+///
+/// Arrays of commutative groups form a commutative group.
+///
+impl<T: ComGroup, const N: usize> ComGroup for [T; N] {}
+
+
+///
+/// Arrays of additive magmas form an additive magma.
+///
+impl<T: AddMagma, const N: usize> AddMagma for [T; N] {
+
+  /// Addition is component-wise.
+  fn add(&self, other: &Self) -> Self {
+    from_fn(|i| self[i].add(&other[i]))
+  }
+}
+
+
+///
+/// Arrays of additive semigroups form an additive semigroup.
+///
+impl<T: AddSemigroup, const N: usize> AddSemigroup for [T; N] {}
+
+
+///
+/// Arrays of additive monoids form an additive monoid.
+///
+impl<T: AddMonoid, const N: usize> AddMonoid for [T; N] {
+
+  /// Zero is the array of component zeros.
+  fn zero() -> Self {
+    from_fn(|_| T::zero())
+  }
+}
+
+
+///
+/// Arrays of additive groups form an additive group.
+///
+impl<T: AddGroup, const N: usize> AddGroup for [T; N] {
+
+  /// Negation is component-wise.
+  fn negate(&self) -> Self {
+    from_fn(|i| self[i].negate())
+  }
+}
+
+
+///
+/// Arrays of additive commutative groups form an additive
+/// commutative group.
+///
+impl<T: AddComGroup, const N: usize> AddComGroup for [T; N] {}
+
+
+///
+/// Arrays of multiplicative magmas form a multiplicative magma.
+///
+impl<T: MulMagma, const N: usize> MulMagma for [T; N] {
+
+  /// Multiplication is component-wise.
+  fn mul(&self, other: &Self) -> Self {
+    from_fn(|i| self[i].mul(&other[i]))
+  }
+}
+
+
+///
+/// Arrays of multiplicative semigroups form a multiplicative
+/// semigroup.
+///
+impl<T: MulSemigroup, const N: usize> MulSemigroup for [T; N] {}
+
+
+///
+/// Arrays of multiplicative monoids form a multiplicative monoid.
+///
+impl<T: MulMonoid, const N: usize> MulMonoid for [T; N] {
+
+  /// One is the array of component ones.
+  fn one() -> Self {
+    from_fn(|_| T::one())
+  }
+}
+
+
+///
+/// Arrays of multiplicative groups form a multiplicative group.
+///
+impl<T: MulGroup, const N: usize> MulGroup for [T; N] {
+
+  /// Inversion is component-wise.
+  fn invert(&self) -> Self {
+    from_fn(|i| self[i].invert())
+  }
+
+
+  /// Arrays are invertible when all components are invertible.
+  fn is_invertible(&self) -> bool {
+    self.iter().all(|x| x.is_invertible())
+  }
+}
+
+
+///
+/// Arrays of multiplicative commutative groups form a
+/// multiplicative commutative group.
+///
+impl<T: MulComGroup, const N: usize> MulComGroup for [T; N] {}
+
+
+///
+/// Arrays of rings form a ring.
+///
+impl<T: Ring, const N: usize> Ring for [T; N] {}
+
+
+///
+/// Arrays of commutative rings form a commutative ring.
+///
+impl<T: ComRing, const N: usize> ComRing for [T; N] {}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "array_test.rs"]
+mod array_test;
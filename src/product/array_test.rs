@@ -0,0 +1,90 @@
+use prelude::*;
+use tests::prelude::*;
+use proptest::prelude::*;
+
+
+prop_compose! {
+
+  /// Generate three random `[i16; 4]` arrays.
+  fn i16x4_3()(xs in any::<Three<[i16; 4]>>()) -> Three<[i16; 4]> {
+    xs
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate one random (bounded) `[f64; 3]` array.
+  fn f64x3_1()(xs in [-1e6..1e6, -1e6..1e6, -1e6..1e6]) -> [f64; 3] {
+    xs
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random `[f64; 3]` arrays.
+  fn f64x3_3()(xs in (f64x3_1(), f64x3_1(), f64x3_1())) -> Three<[f64; 3]> {
+    xs
+  }
+}
+
+
+/// Compile-time check that a type forms a numeric additive
+/// commutative group.
+fn is_num_add_com_group<G: NumAddComGroup>() {}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_mul_associativity_i16x4((ref x, ref y, ref z) in i16x4_3()) {
+    MulSemigroup::axiom_mul_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_right_distributivity_i16x4((ref x, ref y, ref z) in i16x4_3()) {
+    Ring::axiom_right_distributivity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_left_negate_i16x4((ref x, _, _) in i16x4_3()) {
+    AddGroup::axiom_left_negate(x)
+  }
+
+
+  #[test]
+  fn axiom_add_associativity_f64x3((ref x, ref y, ref z) in f64x3_3()) {
+    NumAddSemigroup::axiom_add_associativity((x, y, z, &1e-6))
+  }
+
+
+  #[test]
+  fn axiom_add_commutivity_f64x3((ref x, ref y, _) in f64x3_3()) {
+    NumAddComGroup::axiom_add_commutivity((x, y, &F64_EPS))
+  }
+
+
+  #[test]
+  fn axiom_left_negate_f64x3(ref x in f64x3_1()) {
+    NumAddGroup::axiom_left_negate(x, &F64_EPS)
+  }
+}
+
+
+#[test]
+fn product_groups() {
+  is_num_add_com_group::<[f64; 3]>();
+  is_num_add_com_group::<[(f32, f64); 2]>();
+}
+
+
+#[test]
+fn invertible_components() {
+  assert!(MulGroup::is_invertible(&[1.0, 2.0, 3.0]));
+  assert!(!MulGroup::is_invertible(&[1.0, 0.0, 3.0]));
+}
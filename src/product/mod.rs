@@ -0,0 +1,15 @@
+//!
+//! Algebraic traits for _direct products_.
+//!
+//! The _direct product_ of algebraic structures `S` and `T` is the
+//! set of pairs `(s, t)` with _component-wise_ operations. Direct
+//! products of magmas, semigroups, monoids, groups and rings are
+//! again magmas, semigroups, monoids, groups and rings.
+//!
+//! This module implements `un_algebra` structure traits for Rust's
+//! built-in tuple (up to arity 4) and fixed-size array types, where
+//! the components implement them.
+//!
+pub mod tuple;
+
+pub mod array;
@@ -0,0 +1,246 @@
+//!
+//! Algebraic trait implementations for _tuples_.
+//!
+//! Tuples of algebraic structures form their _direct product_, with
+//! component-wise operations and identities. For example `(i32,
+//! BigRational)` forms a ring, and `(f32, f64)` a numeric
+//! commutative ring.
+//!
+//! Direct products of _fields_ are not fields--e.g. `(1, 0)` is a
+//! non-zero element with no inverse--so tuples only implement the
+//! structure traits up to _commutative_ _ring_. Tuple elements are
+//! _invertible_ when all of their components are invertible.
+//!
+//! Numeric comparisons of tuples use a tuple of the component error
+//! types.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a direct product.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// Trait implementation macro for tuple types.
+///
+/// A macro used to avoid writing repetitive, boilerplate structure
+/// trait implementations for each tuple arity. The macro arguments
+/// are pairs of component type parameters and tuple indexes.
+///
+macro_rules! tuple_product {
+  ($($type:ident $index:tt),+) => {
+
+    ///
+    /// Numeric equality for tuples of numeric types.
+    ///
+    impl<$($type: NumEq),+> NumEq for ($($type,)+) {
+
+      /// The numeric error type is a tuple of component error types.
+      type Error = ($($type::Error,)+);
+
+
+      /// Equality is component-wise numeric equality.
+      fn num_eq(&self, other: &Self, eps: &Self::Error) -> bool {
+        true $(&& self.$index.num_eq(&other.$index, &eps.$index))+
+      }
+
+
+      /// Equality within default component error bounds.
+      fn eq(&self, other: &Self) -> bool {
+        true $(&& NumEq::eq(&self.$index, &other.$index))+
+      }
+
+
+      /// Inequality within default component error bounds.
+      fn ne(&self, other: &Self) -> bool {
+        !NumEq::eq(self, other)
+      }
+    }
+
+
+    ///
+    /// Tuples of magmas form a magma.
+    ///
+    impl<$($type: Magma),+> Magma for ($($type,)+) {
+
+      /// The operation is component-wise.
+      fn op(&self, other: &Self) -> Self {
+        ($(self.$index.op(&other.$index),)+)
+      }
+    }
+
+
+    ///
+    /// Tuples of semigroups form a semigroup.
+    ///
+    impl<$($type: Semigroup),+> Semigroup for ($($type,)+) {}
+
+
+    ///
+    /// Tuples of monoids form a monoid.
+    ///
+    impl<$($type: Monoid),+> Monoid for ($($type,)+) {
+
+      /// The identity is the tuple of component identities.
+      fn id() -> Self {
+        ($($type::id(),)+)
+      }
+    }
+
+
+    ///
+    /// Tuples of groups form a group.
+    ///
+    impl<$($type: Group),+> Group for ($($type,)+) {
+
+      /// The inverse is component-wise.
+      fn inverse(&self) -> Self {
+        ($(self.$index.inverse(),)+)
+      }
+    }
+
+
+    // Tuples of groups are loops.
+    group_loop! {[$($type: Group),+] ($($type,)+)}
+
+
+    ///
+    /// Tuples of commutative groups form a commutative group.
+    ///
+    impl<$($type: ComGroup),+> ComGroup for ($($type,)+) {}
+
+
+    ///
+    /// Tuples of additive magmas form an additive magma.
+    ///
+    impl<$($type: AddMagma),+> AddMagma for ($($type,)+) {
+
+      /// Addition is component-wise.
+      fn add(&self, other: &Self) -> Self {
+        ($(self.$index.add(&other.$index),)+)
+      }
+    }
+
+
+    ///
+    /// Tuples of additive semigroups form an additive semigroup.
+    ///
+    impl<$($type: AddSemigroup),+> AddSemigroup for ($($type,)+) {}
+
+
+    ///
+    /// Tuples of additive monoids form an additive monoid.
+    ///
+    impl<$($type: AddMonoid),+> AddMonoid for ($($type,)+) {
+
+      /// Zero is the tuple of component zeros.
+      fn zero() -> Self {
+        ($($type::zero(),)+)
+      }
+    }
+
+
+    ///
+    /// Tuples of additive groups form an additive group.
+    ///
+    impl<$($type: AddGroup),+> AddGroup for ($($type,)+) {
+
+      /// Negation is component-wise.
+      fn negate(&self) -> Self {
+        ($(self.$index.negate(),)+)
+      }
+    }
+
+
+    ///
+    /// Tuples of additive commutative groups form an additive
+    /// commutative group.
+    ///
+    impl<$($type: AddComGroup),+> AddComGroup for ($($type,)+) {}
+
+
+    ///
+    /// Tuples of multiplicative magmas form a multiplicative magma.
+    ///
+    impl<$($type: MulMagma),+> MulMagma for ($($type,)+) {
+
+      /// Multiplication is component-wise.
+      fn mul(&self, other: &Self) -> Self {
+        ($(self.$index.mul(&other.$index),)+)
+      }
+    }
+
+
+    ///
+    /// Tuples of multiplicative semigroups form a multiplicative
+    /// semigroup.
+    ///
+    impl<$($type: MulSemigroup),+> MulSemigroup for ($($type,)+) {}
+
+
+    ///
+    /// Tuples of multiplicative monoids form a multiplicative monoid.
+    ///
+    impl<$($type: MulMonoid),+> MulMonoid for ($($type,)+) {
+
+      /// One is the tuple of component ones.
+      fn one() -> Self {
+        ($($type::one(),)+)
+      }
+    }
+
+
+    ///
+    /// Tuples of multiplicative groups form a multiplicative group.
+    ///
+    impl<$($type: MulGroup),+> MulGroup for ($($type,)+) {
+
+      /// Inversion is component-wise.
+      fn invert(&self) -> Self {
+        ($(self.$index.invert(),)+)
+      }
+
+
+      /// Tuples are invertible when all components are invertible.
+      fn is_invertible(&self) -> bool {
+        true $(&& self.$index.is_invertible())+
+      }
+    }
+
+
+    ///
+    /// Tuples of multiplicative commutative groups form a
+    /// multiplicative commutative group.
+    ///
+    impl<$($type: MulComGroup),+> MulComGroup for ($($type,)+) {}
+
+
+    ///
+    /// Tuples of rings form a ring.
+    ///
+    impl<$($type: Ring),+> Ring for ($($type,)+) {}
+
+
+    ///
+    /// Tuples of commutative rings form a commutative ring.
+    ///
+    impl<$($type: ComRing),+> ComRing for ($($type,)+) {}
+
+  };
+}
+
+
+// Direct products of one to four structures.
+tuple_product! {A 0}
+tuple_product! {A 0, B 1}
+tuple_product! {A 0, B 1, C 2}
+tuple_product! {A 0, B 1, C 2, D 3}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "tuple_test.rs"]
+mod tuple_test;
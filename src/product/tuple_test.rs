@@ -0,0 +1,133 @@
+use prelude::*;
+use tests::prelude::*;
+
+
+prop_compose! {
+
+  /// Generate one random `(i32, i64)` pair.
+  fn ii_1()(x in i32_1(), y in i64_1()) -> (i32, i64) {
+    (x, y)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random `(i32, i64)` pairs.
+  fn ii_3()(xs in (ii_1(), ii_1(), ii_1())) -> Three<(i32, i64)> {
+    xs
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate one random (bounded) `(f64, f32, f64)` triple.
+  fn fff_1()(x in -1e3..1e3, y in -1e3f32..1e3, z in -1e3..1e3)
+    -> (f64, f32, f64) {
+    (x, y, z)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate two random `(f64, f32, f64)` triples.
+  fn fff_2()(xs in (fff_1(), fff_1())) -> Two<(f64, f32, f64)> {
+    xs
+  }
+}
+
+
+/// Default error term for `(f64, f32, f64)` triples.
+const FFF_EPS: (f64, f32, f64) = (1e-9, 1e-3, 1e-9);
+
+
+/// Compile-time check that a type forms a ring.
+fn is_ring<R: Ring>() {}
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_add_associativity_ii((ref x, ref y, ref z) in ii_3()) {
+    AddSemigroup::axiom_add_associativity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_left_add_identity_ii(ref x in ii_1()) {
+    AddMonoid::axiom_left_add_identity(x)
+  }
+
+
+  #[test]
+  fn axiom_left_negate_ii(ref x in ii_1()) {
+    AddGroup::axiom_left_negate(x)
+  }
+
+
+  #[test]
+  fn axiom_left_distributivity_ii((ref x, ref y, ref z) in ii_3()) {
+    Ring::axiom_left_distributivity((x, y, z))
+  }
+
+
+  #[test]
+  fn axiom_mul_commutivity_ii((ref x, ref y, _) in ii_3()) {
+    ComRing::axiom_mul_commutivity((x, y))
+  }
+
+
+  #[test]
+  fn axiom_add_commutivity_fff((ref x, ref y) in fff_2()) {
+    NumAddComGroup::axiom_add_commutivity((x, y, &FFF_EPS))
+  }
+
+
+  #[test]
+  fn axiom_right_negate_fff(ref x in fff_1()) {
+    NumAddGroup::axiom_right_negate(x, &FFF_EPS)
+  }
+
+
+  #[test]
+  fn axiom_mul_commutivity_fff((ref x, ref y) in fff_2()) {
+    NumComRing::axiom_mul_commutivity((x, y, &FFF_EPS))
+  }
+
+
+  #[test]
+  fn axiom_left_invert_fff(ref x in fff_1()) {
+    prop_assume!(MulGroup::is_invertible(x));
+
+    NumMulGroup::axiom_left_invert(x, &FFF_EPS)
+  }
+}
+
+
+#[test]
+fn product_rings() {
+  is_ring::<(i8,)>();
+  is_ring::<(i32, BigRational)>();
+  is_ring::<(i64, Quotient<i64>, BigRational, i16)>();
+}
+
+
+#[test]
+fn num_eq_component_errors() {
+  let x = (1.0, 1.0f32);
+  let y = (1.0 + 1e-6, 1.0 + 1e-6);
+
+  assert!(x.num_eq(&y, &(1e-3, 1e-3)));
+  assert!(x.num_ne(&y, &(1e-9, 1e-3)));
+}
+
+
+#[test]
+fn invertible_components() {
+  assert!(MulGroup::is_invertible(&(2.0, 3.0f32)));
+  assert!(!MulGroup::is_invertible(&(2.0, 0.0f32)));
+}
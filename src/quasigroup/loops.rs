@@ -0,0 +1,144 @@
+//!
+//! Algebraic _loop_ trait.
+//!
+//! An algebraic _loop_ is a _quasigroup_ `S` with an _identity_
+//! element `e`. Loops need not be associative, but an associative
+//! loop is a group.
+//!
+//! A _Moufang_ _loop_ is a loop where the _Moufang_ _identity_
+//! `z·(x·(z·y)) = ((z·x)·z)·y` holds for all `x, y, z`. Every group is
+//! a Moufang loop, and the best known non-associative Moufang loop is
+//! the loop of unit _octonions_.
+//!
+//! # Axioms
+//!
+//! 1. Quasigroup: quasigroup axioms hold.
+//! 2. Identity: ∃e ∈ S, ∀x ∈ S, e·x = x·e = x.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a loop.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use types::*;
+use numeric::equal::*;
+use numeric::equivalence::*;
+use quasigroup::quasigroup::*;
+
+
+///
+/// An algebraic _loop_.
+///
+pub trait Loop: Quasigroup {
+
+  /// The loop identity element.
+  fn identity() -> Self;
+
+
+  /// Test the left identity axiom.
+  fn axiom_left_identity(&self) -> bool {
+    self.axiom_left_identity_by(&Exact)
+  }
+
+
+  /// Test the right identity axiom.
+  fn axiom_right_identity(&self) -> bool {
+    self.axiom_right_identity_by(&Exact)
+  }
+
+
+  /// Test the Moufang identity, which holds exactly for Moufang
+  /// loops.
+  fn prop_moufang(xs: Triple<Self>) -> bool {
+    Self::prop_moufang_by(xs, &Exact)
+  }
+
+
+  /// Test associativity, which holds exactly for loops that are
+  /// groups.
+  fn prop_associativity(xs: Triple<Self>) -> bool {
+    Self::prop_associativity_by(xs, &Exact)
+  }
+
+
+  /// Test the left identity axiom up to an equivalence.
+  fn axiom_left_identity_by<E>(&self, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    eq.equiv(&Self::identity().op(self), self)
+  }
+
+
+  /// Test the right identity axiom up to an equivalence.
+  fn axiom_right_identity_by<E>(&self, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    eq.equiv(&self.op(&Self::identity()), self)
+  }
+
+
+  /// Test the Moufang identity up to an equivalence.
+  fn prop_moufang_by<E>(xs: Triple<Self>, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    let (x, y, z) = xs;
+
+    eq.equiv(&z.op(&x.op(&z.op(y))), &z.op(x).op(z).op(y))
+  }
+
+
+  /// Test associativity up to an equivalence.
+  fn prop_associativity_by<E>(xs: Triple<Self>, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    let (x, y, z) = xs;
+
+    eq.equiv(&x.op(y).op(z), &x.op(&y.op(z)))
+  }
+}
+
+
+///
+/// A "numeric" algebraic _loop_.
+///
+/// `NumLoop` trait is for types that only form loops when "numeric"
+/// comparisons are used, e.g. floating point types.
+///
+pub trait NumLoop: Loop + NumQuasigroup {
+
+  /// Numerically test the left identity axiom.
+  fn axiom_left_identity(&self, eps: &Self::Error) -> bool {
+    self.axiom_left_identity_by(&Within(eps))
+  }
+
+
+  /// Numerically test the right identity axiom.
+  fn axiom_right_identity(&self, eps: &Self::Error) -> bool {
+    self.axiom_right_identity_by(&Within(eps))
+  }
+
+
+  /// Numerically test the Moufang identity.
+  fn prop_moufang(xs: NumTriple<Self>) -> bool {
+    let (x, y, z, eps) = xs;
+
+    Self::prop_moufang_by((x, y, z), &Within(eps))
+  }
+
+
+  /// Numerically test associativity.
+  fn prop_associativity(xs: NumTriple<Self>) -> bool {
+    let (x, y, z, eps) = xs;
+
+    Self::prop_associativity_by((x, y, z), &Within(eps))
+  }
+}
+
+
+///
+/// Loops with numeric equality form numeric loops.
+///
+impl<T: Loop + NumEq> NumLoop for T {}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "loops_test.rs"]
+mod loops_test;
@@ -0,0 +1,65 @@
+use prelude::*;
+use tests::prelude::*;
+
+
+/// The smallest non-associative loop.
+struct Loop5;
+
+impl CayleyTable<5> for Loop5 {
+  type Kind = Loops;
+
+  const OP: Table<5> = [
+    [0, 1, 2, 3, 4],
+    [1, 0, 3, 4, 2],
+    [2, 4, 0, 1, 3],
+    [3, 2, 4, 0, 1],
+    [4, 3, 1, 2, 0],
+  ];
+}
+
+
+type L5 = Cayley<Loop5, 5>;
+
+
+#[test]
+fn exhaustive_loop_axioms() {
+  let left = exhaustive_1::<L5, _>(Loop::axiom_left_identity);
+  let right = exhaustive_1::<L5, _>(Loop::axiom_right_identity);
+  let division = exhaustive_2::<L5, _>(Quasigroup::axiom_left_division);
+
+  assert_eq!((left, right, division), (Ok(()), Ok(()), Ok(())));
+}
+
+
+#[test]
+fn non_moufang_loop() {
+  let moufang = exhaustive_3::<L5, _>(Loop::prop_moufang);
+  let associativity = exhaustive_3::<L5, _>(Loop::prop_associativity);
+
+  assert!(moufang.is_err());
+  assert_eq!(associativity, Err((L5::new(1), L5::new(1), L5::new(2))));
+}
+
+
+#[test]
+fn groups_are_moufang() {
+  let moufang = exhaustive_3::<S3, _>(Loop::prop_moufang);
+
+  assert_eq!(moufang, Ok(()));
+  assert_eq!(S3::identity(), Cayley::new(0));
+}
+
+
+#[test]
+fn groups_are_loops() {
+  let division = exhaustive_2::<Cyclic<5>, _>(|xs| {
+    Quasigroup::axiom_left_division(xs)
+      && Quasigroup::axiom_right_division(xs)
+  });
+  let moufang = exhaustive_3::<Dihedral<4>, _>(Loop::prop_moufang);
+  let r = Dihedral::<4>::r();
+
+  assert_eq!((division, moufang), (Ok(()), Ok(())));
+  assert_eq!(r.left_div(&Dihedral::id()), r.inverse());
+  assert_eq!(Permutation::identity(), Permutation::id());
+}
@@ -0,0 +1,70 @@
+//!
+//! Algebraic _quasigroup_ and _loop_ traits and implementations.
+//!
+//! The `quasigroup` module provides support for types that form
+//! algebraic _quasigroups_ (magmas with division) and _loops_
+//! (quasigroups with identity). Quasigroups sit between magmas and
+//! groups: every group is a loop, and an associative loop is a group.
+//!
+
+
+///
+/// Quasigroup and loop trait implementation macro for groups.
+///
+/// A macro used to avoid writing repetitive, boilerplate `Quasigroup`
+/// and `Loop` implementations for groups. Every group is a loop, with
+/// left division `x\y = x^-1·y`, right division `y/x = y·x^-1` and the
+/// group identity. The macro arguments are the implementation
+/// generics (in brackets) and the group type.
+///
+/// `Group` has `Loop` as a super-trait, so `Group` implementations
+/// outside this crate use the macro too, e.g. `group_loop! {[] G}` or
+/// `group_loop! {[T: Group] G<T>}` alongside `impl Group for G`.
+///
+#[macro_export]
+macro_rules! group_loop {
+  ([$($generics:tt)*] $type:ty) => {
+    ///
+    /// Groups form quasigroups.
+    ///
+    impl<$($generics)*> $crate::quasigroup::quasigroup::Quasigroup
+      for $type {
+
+      /// Left division `x\y` is `x^-1·y`.
+      fn left_div(&self, other: &Self) -> Self {
+        let inverse = $crate::group::group::Group::inverse(self);
+
+        $crate::magma::magma::Magma::op(&inverse, other)
+      }
+
+
+      /// Right division `y/x` is `y·x^-1`.
+      fn right_div(&self, other: &Self) -> Self {
+        let inverse = $crate::group::group::Group::inverse(other);
+
+        $crate::magma::magma::Magma::op(self, &inverse)
+      }
+    }
+
+
+    ///
+    /// Groups form loops.
+    ///
+    impl<$($generics)*> $crate::quasigroup::loops::Loop for $type {
+
+      /// The identity is the group identity.
+      fn identity() -> Self {
+        $crate::monoid::monoid::Monoid::id()
+      }
+    }
+  };
+}
+
+
+pub mod quasigroup;
+
+pub mod loops;
+
+pub mod subtraction;
+
+pub mod octonion;
@@ -0,0 +1,285 @@
+//!
+//! The _Moufang_ _loop_ of unit _octonions_.
+//!
+//! The _octonions_ are the eight dimensional real algebra built from
+//! the real numbers by three steps of the _Cayley-Dickson_ _process_
+//! (see [`CayleyDicksonPair`]). Octonion multiplication is neither
+//! commutative nor associative, but it is _alternative_, so the
+//! non-zero octonions form a _Moufang_ _loop_ under multiplication.
+//! General octonion products are only exact up to rounding, so the
+//! non-zero octonions form a "numeric" Moufang loop.
+//!
+//! The sixteen octonions `±1, ±e1, ..., ±e7` are closed under
+//! multiplication, and form the smallest non-associative Moufang loop
+//! containing the quaternion group. Their products have components
+//! `0` and `±1` only, so (unlike general octonions) they can be
+//! multiplied exactly with floating point components.
+//!
+//! [`CayleyDicksonPair`]:
+//! crate::complex::cayley_dickson_process::CayleyDicksonPair
+//!
+//! # References
+//!
+//! See [references] for a formal definition of the octonions.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+use complex::cayley_dickson_process::*;
+
+
+///
+/// Octonions, as a Cayley-Dickson pair of quaternions.
+///
+pub type Octonion =
+  CayleyDicksonPair<CayleyDicksonPair<CayleyDicksonPair<f64>>>;
+
+
+///
+/// The octonion with components `c` in the basis `e0, ..., e7`.
+///
+pub fn octonion(c: [f64; 8]) -> Octonion {
+  let complex = |i: usize| CayleyDicksonPair::new((c[i], c[i + 1]));
+  let quaternion = |i: usize| {
+    CayleyDicksonPair::new((complex(i), complex(i + 2)))
+  };
+
+  CayleyDicksonPair::new((quaternion(0), quaternion(4)))
+}
+
+
+///
+/// The components of an octonion in the basis `e0, ..., e7`.
+///
+pub fn components(x: &Octonion) -> [f64; 8] {
+  let mut c = [0.0; 8];
+  let (q0, q1) = x.pair();
+
+  for (i, q) in [q0, q1].iter().enumerate() {
+    let (z0, z1) = q.pair();
+
+    for (j, z) in [z0, z1].iter().enumerate() {
+      let (a, b) = z.pair();
+
+      c[4 * i + 2 * j] = *a;
+      c[4 * i + 2 * j + 1] = *b;
+    }
+  }
+  c
+}
+
+
+///
+/// A non-zero octonion, under octonion multiplication.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct NonZeroOctonion(Octonion);
+
+
+impl NonZeroOctonion {
+
+  /// A new non-zero octonion, or `None` for the zero octonion.
+  pub fn new(x: Octonion) -> Option<Self> {
+    if x != Octonion::zero() {
+      Some(NonZeroOctonion(x))
+    }
+    else {
+      None
+    }
+  }
+
+
+  /// The octonion value.
+  pub fn octonion(&self) -> &Octonion {
+    &self.0
+  }
+
+
+  /// The squared norm `x·x*`, i.e. the sum of the squared components.
+  pub fn norm_sqr(&self) -> f64 {
+    components(&self.0).iter().map(|c| c * c).sum()
+  }
+
+
+  /// The inverse `x*/(x·x*)`, as octonions are a division algebra.
+  pub fn inverse(&self) -> Self {
+    let mut scale = [0.0; 8];
+    scale[0] = 1.0 / self.norm_sqr();
+
+    NonZeroOctonion(self.0.conjugate().mul(&octonion(scale)))
+  }
+}
+
+
+///
+/// Non-zero octonions form a magma.
+///
+impl Magma for NonZeroOctonion {
+
+  /// The operation is octonion multiplication.
+  fn op(&self, other: &Self) -> Self {
+    NonZeroOctonion(self.0.mul(&other.0))
+  }
+}
+
+
+///
+/// Non-zero octonions form a quasigroup.
+///
+impl Quasigroup for NonZeroOctonion {
+
+  /// Left division `x\y` is `x^-1·y`, as octonions are alternative.
+  fn left_div(&self, other: &Self) -> Self {
+    self.inverse().op(other)
+  }
+
+
+  /// Right division `y/x` is `y·x^-1`, as octonions are alternative.
+  fn right_div(&self, other: &Self) -> Self {
+    self.op(&other.inverse())
+  }
+}
+
+
+///
+/// Non-zero octonions form a loop.
+///
+impl Loop for NonZeroOctonion {
+
+  /// The identity is the octonion `1`.
+  fn identity() -> Self {
+    NonZeroOctonion(Octonion::one())
+  }
+}
+
+
+///
+/// Non-zero octonions have numeric equality.
+///
+impl NumEq for NonZeroOctonion {
+
+  /// Error type is the octonion component error type.
+  type Error = f64;
+
+
+  /// Component-wise numeric equality.
+  fn num_eq(&self, other: &Self, eps: &Self::Error) -> bool {
+    self.0.num_eq(&other.0, eps)
+  }
+}
+
+
+///
+/// A unit octonion `±e0, ..., ±e7`, where `e0` is `1`.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct OctonionUnit {
+  basis: usize,
+  negative: bool,
+}
+
+
+impl OctonionUnit {
+
+  /// The unit octonion `±e_basis`. Panics if the basis index is out
+  /// of range.
+  pub fn new(basis: usize, negative: bool) -> Self {
+    assert!(basis < 8, "octonion basis index out of range");
+
+    OctonionUnit { basis, negative }
+  }
+
+
+  /// The basis index of the unit.
+  pub fn basis(&self) -> usize {
+    self.basis
+  }
+
+
+  /// Test for a negative unit.
+  pub fn is_negative(&self) -> bool {
+    self.negative
+  }
+
+
+  /// The unit as an octonion.
+  pub fn octonion(&self) -> Octonion {
+    let mut c = [0.0; 8];
+    c[self.basis] = if self.negative { -1.0 } else { 1.0 };
+
+    octonion(c)
+  }
+
+
+  /// The unit equal to an octonion. Panics if the octonion is not a
+  /// unit.
+  fn from_octonion(x: &Octonion) -> Self {
+    Self::elements().find(|u| u.octonion() == *x).expect("unit octonion")
+  }
+
+
+  /// The octonion conjugate, which is the inverse of a unit.
+  pub fn conjugate(&self) -> Self {
+    Self::from_octonion(&self.octonion().conjugate())
+  }
+}
+
+
+///
+/// Unit octonions form a magma.
+///
+impl Magma for OctonionUnit {
+
+  /// The operation is octonion multiplication.
+  fn op(&self, other: &Self) -> Self {
+    Self::from_octonion(&self.octonion().mul(&other.octonion()))
+  }
+}
+
+
+///
+/// Unit octonions form a quasigroup.
+///
+impl Quasigroup for OctonionUnit {
+
+  /// Left division `x\y` is `x^-1·y`, as octonions are alternative.
+  fn left_div(&self, other: &Self) -> Self {
+    self.conjugate().op(other)
+  }
+
+
+  /// Right division `y/x` is `y·x^-1`, as octonions are alternative.
+  fn right_div(&self, other: &Self) -> Self {
+    self.op(&other.conjugate())
+  }
+}
+
+
+///
+/// Unit octonions form a loop.
+///
+impl Loop for OctonionUnit {
+
+  /// The identity is the octonion `1`.
+  fn identity() -> Self {
+    Self::new(0, false)
+  }
+}
+
+
+///
+/// Unit octonions form a finite type.
+///
+impl Finite for OctonionUnit {
+
+  /// The units `e0, -e0, e1, -e1, ..., e7, -e7`.
+  fn elements() -> impl Iterator<Item = Self> {
+    (0..16).map(|i| Self::new(i / 2, i % 2 == 1))
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "octonion_test.rs"]
+mod octonion_test;
@@ -0,0 +1,169 @@
+use prelude::*;
+use proptest::prelude::*;
+use tests::prelude::*;
+
+
+prop_compose! {
+
+  /// Generate one random octonion with small integer components, so
+  /// that products are exact.
+  fn octonion_1()(c in prop::array::uniform8(-8i32..8)) -> Octonion {
+    let mut components = [0.0; 8];
+
+    for (x, y) in components.iter_mut().zip(c.iter()) {
+      *x = *y as f64;
+    }
+    octonion(components)
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random octonions.
+  fn octonion_3()(xs in (octonion_1(), octonion_1(), octonion_1()))
+    -> Three<Octonion> {
+    xs
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate one random non-zero octonion with float components.
+  fn non_zero_1()(c in prop::array::uniform8(-1.0..1.0f64)
+    .prop_filter("non-zero", |c| c.iter().any(|x| x.abs() > 0.1)))
+    -> NonZeroOctonion {
+    NonZeroOctonion::new(octonion(c)).unwrap()
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random non-zero octonions.
+  fn non_zero_3()(xs in (non_zero_1(), non_zero_1(), non_zero_1()))
+    -> Three<NonZeroOctonion> {
+    xs
+  }
+}
+
+
+/// Error term for numeric octonion comparisons.
+const EPS: f64 = 1e-9;
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn num_axiom_left_division((ref x, ref y, _) in non_zero_3()) {
+    NumQuasigroup::axiom_left_division((x, y, &EPS))
+  }
+
+
+  #[test]
+  fn num_axiom_right_division((ref x, ref y, _) in non_zero_3()) {
+    NumQuasigroup::axiom_right_division((x, y, &EPS))
+  }
+
+
+  #[test]
+  fn num_axiom_left_identity((ref x, _, _) in non_zero_3()) {
+    NumLoop::axiom_left_identity(x, &EPS)
+  }
+
+
+  #[test]
+  fn num_axiom_right_identity((ref x, _, _) in non_zero_3()) {
+    NumLoop::axiom_right_identity(x, &EPS)
+  }
+
+
+  #[test]
+  fn num_prop_moufang((ref x, ref y, ref z) in non_zero_3()) {
+    NumLoop::prop_moufang((x, y, z, &EPS))
+  }
+
+
+  #[test]
+  fn prop_moufang((ref x, ref y, ref z) in octonion_3()) {
+    prop_assert!(z.mul(&x.mul(&z.mul(y))) == z.mul(x).mul(z).mul(y));
+  }
+
+
+  #[test]
+  fn prop_alternative((ref x, ref y, _) in octonion_3()) {
+    prop_assert!(x.mul(x).mul(y) == x.mul(&x.mul(y)));
+  }
+}
+
+
+#[test]
+fn exhaustive_loop_axioms() {
+  let left = exhaustive_1::<OctonionUnit, _>(Loop::axiom_left_identity);
+  let right = exhaustive_1::<OctonionUnit, _>(Loop::axiom_right_identity);
+
+  assert_eq!((left, right), (Ok(()), Ok(())));
+}
+
+
+#[test]
+fn exhaustive_division() {
+  let left = exhaustive_2::<OctonionUnit, _>(Quasigroup::axiom_left_division);
+  let right =
+    exhaustive_2::<OctonionUnit, _>(Quasigroup::axiom_right_division);
+
+  assert_eq!((left, right), (Ok(()), Ok(())));
+}
+
+
+#[test]
+fn moufang_not_associative() {
+  let moufang = exhaustive_3::<OctonionUnit, _>(Loop::prop_moufang);
+  let associativity =
+    exhaustive_3::<OctonionUnit, _>(Loop::prop_associativity);
+
+  assert_eq!(moufang, Ok(()));
+  assert!(associativity.is_err());
+}
+
+
+#[test]
+fn non_zero_octonions() {
+  let units: Vec<NonZeroOctonion> = OctonionUnit::elements()
+    .map(|u| NonZeroOctonion::new(u.octonion()).unwrap())
+    .collect();
+  let (e1, e2, e4) = (&units[2], &units[4], &units[8]);
+  let c = [1.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 2.0];
+  let x = NonZeroOctonion::new(octonion(c)).unwrap();
+
+  assert_eq!(NonZeroOctonion::new(Octonion::zero()), None);
+  assert_eq!(x.norm_sqr(), 9.0);
+  assert_eq!(components(x.octonion())[7], 2.0);
+  assert!(x.op(&x.inverse()).num_eq(&NonZeroOctonion::identity(), &EPS));
+  assert!(!NumLoop::prop_associativity((e1, e2, e4, &EPS)));
+}
+
+
+#[test]
+fn units() {
+  let (one, e1) = (OctonionUnit::identity(), OctonionUnit::new(1, false));
+
+  assert_eq!(OctonionUnit::size(), 16);
+  assert_eq!(e1.op(&e1), OctonionUnit::new(0, true));
+  assert_eq!(e1.conjugate(), OctonionUnit::new(1, true));
+  assert_eq!(e1.op(&e1.conjugate()), one);
+  assert!(OctonionUnit::new(3, true).is_negative());
+  assert_eq!(OctonionUnit::new(3, true).basis(), 3);
+}
+
+
+#[test]
+fn classified_loop() {
+  let result = classify::<OctonionUnit>();
+
+  assert_eq!(result.structure(), Structure::Loop);
+  assert!(result.to_string().starts_with("loop;"), "loop display");
+}
@@ -0,0 +1,113 @@
+//!
+//! Algebraic _quasigroup_ trait.
+//!
+//! An algebraic _quasigroup_ is a _magma_ `S`, where the equations
+//! `x·z = y` and `z·x = y` have unique solutions `z` for all `x, y`,
+//! written `x\y` (_left_ _division_) and `y/x` (_right_ _division_).
+//! Equivalently, the operation table of a finite quasigroup is a
+//! _Latin_ _square_: every row and column contains every element
+//! exactly once.
+//!
+//! Quasigroups need not be associative or have an identity, e.g.
+//! integer subtraction forms a quasigroup.
+//!
+//! # Axioms
+//!
+//! 1. Magma: magma axioms hold.
+//! 2. Left division: ∀x, y ∈ S, x·(x\y) = y and x\(x·y) = y.
+//! 3. Right division: ∀x, y ∈ S, (y/x)·x = y and (y·x)/x = y.
+//!
+//! Floating point types (e.g. the non-zero octonions) only satisfy
+//! the axioms with numeric equality, i.e. as "numeric" quasigroups.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a quasigroup.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use types::*;
+use magma::magma::*;
+use numeric::equal::*;
+use numeric::equivalence::*;
+
+
+///
+/// An algebraic _quasigroup_.
+///
+pub trait Quasigroup: Magma {
+
+  /// The left division `x\y`, the unique `z` with `x·z = y`.
+  fn left_div(&self, other: &Self) -> Self;
+
+
+  /// The right division `y/x`, the unique `z` with `z·x = y`.
+  fn right_div(&self, other: &Self) -> Self;
+
+
+  /// Test the axiom of left division.
+  fn axiom_left_division(xs: Pair<Self>) -> bool {
+    Self::axiom_left_division_by(xs, &Exact)
+  }
+
+
+  /// Test the axiom of right division.
+  fn axiom_right_division(xs: Pair<Self>) -> bool {
+    Self::axiom_right_division_by(xs, &Exact)
+  }
+
+
+  /// Test the axiom of left division up to an equivalence.
+  fn axiom_left_division_by<E>(xs: Pair<Self>, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    let (x, y) = xs;
+
+    eq.equiv(&x.op(&x.left_div(y)), y) && eq.equiv(&x.left_div(&x.op(y)), y)
+  }
+
+
+  /// Test the axiom of right division up to an equivalence.
+  fn axiom_right_division_by<E>(xs: Pair<Self>, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    let (x, y) = xs;
+
+    eq.equiv(&y.right_div(x).op(x), y) && eq.equiv(&y.op(x).right_div(x), y)
+  }
+}
+
+
+///
+/// A "numeric" algebraic _quasigroup_.
+///
+/// `NumQuasigroup` trait is for types that only form quasigroups when
+/// "numeric" comparisons are used, e.g. floating point types.
+///
+pub trait NumQuasigroup: Quasigroup + NumEq {
+
+  /// Numerically test the axiom of left division.
+  fn axiom_left_division(xs: NumPair<Self>) -> bool {
+    let (x, y, eps) = xs;
+
+    Self::axiom_left_division_by((x, y), &Within(eps))
+  }
+
+
+  /// Numerically test the axiom of right division.
+  fn axiom_right_division(xs: NumPair<Self>) -> bool {
+    let (x, y, eps) = xs;
+
+    Self::axiom_right_division_by((x, y), &Within(eps))
+  }
+}
+
+
+///
+/// Quasigroups with numeric equality form numeric quasigroups.
+///
+impl<T: Quasigroup + NumEq> NumQuasigroup for T {}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "quasigroup_test.rs"]
+mod quasigroup_test;
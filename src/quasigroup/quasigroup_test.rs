@@ -0,0 +1,57 @@
+use prelude::*;
+
+
+/// Subtraction modulo 3 (a quasigroup without identity).
+struct Sub3;
+
+impl CayleyTable<3> for Sub3 {
+  type Kind = Quasigroups;
+
+  const OP: Table<3> = [[0, 2, 1], [1, 0, 2], [2, 1, 0]];
+}
+
+
+/// Multiplication modulo 3 (a monoid, not a quasigroup).
+struct Mul3;
+
+impl CayleyTable<3> for Mul3 {
+  type Kind = Monoids;
+
+  const OP: Table<3> = [[0, 0, 0], [0, 1, 2], [0, 2, 1]];
+}
+
+
+#[test]
+fn exhaustive_division() {
+  type S3 = Cayley<Sub3, 3>;
+
+  let left = exhaustive_2::<S3, _>(Quasigroup::axiom_left_division);
+  let right = exhaustive_2::<S3, _>(Quasigroup::axiom_right_division);
+
+  assert_eq!((left, right), (Ok(()), Ok(())));
+}
+
+
+#[test]
+fn divisions() {
+  let (x, y) = (Cayley::<Sub3, 3>::new(1), Cayley::<Sub3, 3>::new(2));
+
+  assert_eq!(x.op(&x.left_div(&y)), y);
+  assert_eq!(y.right_div(&x).op(&x), y);
+  assert_eq!(x.left_div(&y), Cayley::new(2));
+  assert_eq!(y.right_div(&x), Cayley::new(0));
+}
+
+
+#[test]
+fn classified_quasigroup() {
+  let result = classify::<Cayley<Sub3, 3>>();
+
+  assert_eq!(result.structure(), Structure::Quasigroup);
+}
+
+
+#[test]
+fn no_division() {
+  assert_eq!(Mul3::validate_quasigroup(), Err(TableError::NotLatinRow(0)));
+}
@@ -0,0 +1,75 @@
+//!
+//! The _subtraction_ _quasigroup_ of an additive group.
+//!
+//! The elements of an additive group `S` form a quasigroup under
+//! _subtraction_ `x - y`, with left division `x\y = x - y` and right
+//! division `y/x = y + x`. Subtraction is neither associative nor
+//! commutative, and has no (two-sided) identity unless every element
+//! is its own negation.
+//!
+//! So e.g. Rust's signed integer types form quasigroups under
+//! (wrapping) subtraction.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a quasigroup.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// An additive group element under subtraction.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Subtraction<T>(pub T);
+
+
+///
+/// Additive group elements form a magma under subtraction.
+///
+impl<T: AddGroup> Magma for Subtraction<T> {
+
+  /// The operation is subtraction.
+  fn op(&self, other: &Self) -> Self {
+    Subtraction(self.0.add(&other.0.negate()))
+  }
+}
+
+
+///
+/// Additive group elements form a quasigroup under subtraction.
+///
+impl<T: AddGroup> Quasigroup for Subtraction<T> {
+
+  /// Left division `x\y` is the subtraction `x - y`.
+  fn left_div(&self, other: &Self) -> Self {
+    self.op(other)
+  }
+
+
+  /// Right division `y/x` is the addition `y + x`.
+  fn right_div(&self, other: &Self) -> Self {
+    Subtraction(self.0.add(&other.0))
+  }
+}
+
+
+///
+/// Finite additive group elements under subtraction form a finite
+/// type.
+///
+impl<T: AddGroup + Finite> Finite for Subtraction<T> {
+
+  /// The elements of the additive group.
+  fn elements() -> impl Iterator<Item = Self> {
+    T::elements().map(Subtraction)
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "subtraction_test.rs"]
+mod subtraction_test;
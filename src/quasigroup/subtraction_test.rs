@@ -0,0 +1,53 @@
+use prelude::*;
+use proptest::prelude::*;
+use tests::prelude::*;
+
+
+proptest! {
+  #![proptest_config(standard())]
+
+
+  #[test]
+  fn axiom_left_division(x in any::<i32>(), y in any::<i32>()) {
+    Quasigroup::axiom_left_division((&Subtraction(x), &Subtraction(y)))
+  }
+
+
+  #[test]
+  fn axiom_right_division(x in any::<i64>(), y in any::<i64>()) {
+    Quasigroup::axiom_right_division((&Subtraction(x), &Subtraction(y)))
+  }
+}
+
+
+#[test]
+fn exhaustive_division() {
+  let left = exhaustive_2::<Subtraction<i8>, _>(|xs| {
+    Quasigroup::axiom_left_division(xs)
+  });
+  let right = exhaustive_2::<Subtraction<i8>, _>(|xs| {
+    Quasigroup::axiom_right_division(xs)
+  });
+
+  assert_eq!((left, right), (Ok(()), Ok(())));
+}
+
+
+#[test]
+fn subtraction() {
+  let (x, y, z) = (Subtraction(7), Subtraction(3), Subtraction(2));
+
+  assert_eq!(x.op(&y), Subtraction(4));
+  assert_eq!(x.left_div(&y), Subtraction(4));
+  assert_eq!(x.right_div(&y), Subtraction(10));
+  assert_ne!(x.op(&y).op(&z), x.op(&y.op(&z)));
+  assert_eq!(Subtraction(i32::MIN).op(&Subtraction(1)), Subtraction(i32::MAX));
+}
+
+
+#[test]
+fn classified_quasigroup() {
+  let result = classify::<Subtraction<i8>>();
+
+  assert_eq!(result.structure(), Structure::Quasigroup);
+}
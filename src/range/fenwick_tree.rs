@@ -0,0 +1,131 @@
+//!
+//! Additive commutative group _Fenwick_ _trees_.
+//!
+//! A _Fenwick_ (or _binary_ _indexed_) _tree_ stores partial sums of
+//! a sequence of values, so that the sum of any prefix of `n` values
+//! is the sum of at most `log n` partial sums, and adding to a value
+//! only changes the partial sums that include it.
+//!
+//! Partial sums are added out of sequence order, so the values must
+//! be commutative, and range sums are differences of prefix sums, so
+//! the values must have negatives, i.e. they must form an _additive_
+//! _commutative_ _group_.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of an additive
+//! commutative group.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use std::ops::Range;
+use prelude::*;
+
+
+///
+/// A Fenwick tree of additive commutative group values.
+///
+/// Partial sum `k` (indexed from one) is the sum of the values up to
+/// index `k`, starting after the index `k` with its lowest set bit
+/// cleared.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct FenwickTree<A> {
+  sums: Vec<A>,
+}
+
+
+impl<A: AddComGroup + Clone> FenwickTree<A> {
+
+  /// A new Fenwick tree of a sequence of values.
+  pub fn new(xs: &[A]) -> Self {
+    let mut sums = xs.to_vec();
+
+    for k in 1..=sums.len() {
+      let parent = k + (k & k.wrapping_neg());
+
+      if parent <= sums.len() {
+        sums[parent - 1] = sums[parent - 1].add(&sums[k - 1]);
+      }
+    }
+
+    FenwickTree { sums }
+  }
+
+
+  /// The number of values in the tree.
+  pub fn len(&self) -> usize {
+    self.sums.len()
+  }
+
+
+  /// Test for a tree of no values.
+  pub fn is_empty(&self) -> bool {
+    self.sums.is_empty()
+  }
+
+
+  /// Add to the value at an index. Panics if the index is out of
+  /// range.
+  pub fn add_at(&mut self, i: usize, x: &A) {
+    assert!(i < self.len(), "index {} out of range", i);
+
+    let mut k = i + 1;
+
+    while k <= self.len() {
+      self.sums[k - 1] = self.sums[k - 1].add(x);
+      k += k & k.wrapping_neg();
+    }
+  }
+
+
+  /// The sum of the first `n` values. Panics if `n` is out of range.
+  pub fn prefix(&self, n: usize) -> A {
+    assert!(n <= self.len(), "prefix {} out of range", n);
+
+    let (mut k, mut sum) = (n, A::zero());
+
+    while k > 0 {
+      sum = sum.add(&self.sums[k - 1]);
+      k -= k & k.wrapping_neg();
+    }
+
+    sum
+  }
+
+
+  /// The sum of the values in an index range, or zero for an empty
+  /// range. Panics if the range is out of bounds.
+  pub fn query(&self, range: Range<usize>) -> A {
+    assert!(range.start <= range.end, "range {:?} out of bounds", range);
+
+    self.prefix(range.end).sub(&self.prefix(range.start))
+  }
+
+
+  /// The value at an index. Panics if the index is out of range.
+  pub fn get(&self, i: usize) -> A {
+    self.query(i..i + 1)
+  }
+
+
+  /// Replace the value at an index. Panics if the index is out of
+  /// range.
+  pub fn set(&mut self, i: usize, x: &A) {
+    let delta = x.sub(&self.get(i));
+
+    self.add_at(i, &delta);
+  }
+
+
+  /// The sum of all the values in the tree.
+  pub fn total(&self) -> A {
+    self.prefix(self.len())
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "fenwick_tree_test.rs"]
+mod fenwick_tree_test;
@@ -0,0 +1,85 @@
+use prelude::*;
+use proptest::prelude::*;
+use tests::prelude::*;
+
+
+prop_compose! {
+
+  /// Generate a random `i32` vector, an index range and an update.
+  fn i32s_range()
+    (xs in prop::collection::vec(any::<i32>(), 1..40))
+    (l in 0..xs.len(), r in 0..=xs.len(), i in 0..xs.len(),
+     x in any::<i32>(), xs in Just(xs))
+    -> (Vec<i32>, (usize, usize), (usize, i32)) {
+    (xs, (l.min(r), l.max(r)), (i, x))
+  }
+}
+
+
+proptest! {
+  #![proptest_config(config_with(1000, 500))]
+
+
+  #[test]
+  fn prop_query((ref xs, (l, r), _) in i32s_range()) {
+    let tree = FenwickTree::new(xs);
+
+    prop_assert!(tree.query(l..r) == add_concat(xs[l..r].iter().cloned()));
+  }
+
+
+  #[test]
+  fn prop_add_at((ref xs, (l, r), (i, x)) in i32s_range()) {
+    let mut tree = FenwickTree::new(xs);
+    let mut ys = xs.clone();
+
+    tree.add_at(i, &x);
+    ys[i] = ys[i].add(&x);
+
+    prop_assert!(tree == FenwickTree::new(&ys));
+    prop_assert!(tree.query(l..r) == add_concat(ys[l..r].iter().cloned()));
+  }
+
+
+  #[test]
+  fn prop_set((ref xs, _, (i, x)) in i32s_range()) {
+    let mut tree = FenwickTree::new(xs);
+
+    tree.set(i, &x);
+
+    prop_assert!(tree.get(i) == x);
+  }
+}
+
+
+#[test]
+fn prefix_sums() {
+  let mut tree = FenwickTree::new(&[3, 1, 4, 1, 5, 9, 2, 6]);
+
+  assert_eq!(tree.len(), 8);
+  assert_eq!(tree.prefix(4), 9);
+  assert_eq!(tree.query(2..6), 19);
+  assert_eq!(tree.query(3..3), 0);
+  assert_eq!(tree.total(), 31);
+
+  tree.add_at(0, &-3);
+
+  assert_eq!(tree.get(0), 0);
+  assert_eq!(tree.total(), 28);
+}
+
+
+#[test]
+fn empty_tree() {
+  let tree = FenwickTree::<i8>::new(&[]);
+
+  assert!(tree.is_empty());
+  assert_eq!(tree.total(), 0);
+}
+
+
+#[test]
+#[should_panic]
+fn prefix_out_of_range() {
+  FenwickTree::new(&[1, 2]).prefix(3);
+}
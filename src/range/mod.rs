@@ -0,0 +1,13 @@
+//!
+//! Algebraic _range_ _query_ data structures.
+//!
+//! Range query trees store a sequence of values so that the
+//! combination of any contiguous range of values can be queried, and
+//! single values updated, in logarithmic time. The structures they
+//! need from their values are algebraic: a _segment_ _tree_ only
+//! needs a monoid, while a _Fenwick_ _tree_ (storing prefix sums)
+//! needs an additive commutative group, to recover range sums as
+//! differences of prefix sums.
+//!
+pub mod segment_tree;
+pub mod fenwick_tree;
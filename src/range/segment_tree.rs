@@ -0,0 +1,127 @@
+//!
+//! Monoid _segment_ _trees_.
+//!
+//! A _segment_ _tree_ stores a sequence of monoid values in the
+//! leaves of a binary tree, with each internal node holding the
+//! combination of the values below it. The combination of any range
+//! of `n` values is then the combination of at most `2 log n` nodes,
+//! and updating a value only changes the nodes above it.
+//!
+//! Nodes are only ever combined in sequence order, so segment trees
+//! of non-commutative monoids (e.g. strings) give correct results.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of a monoid.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use std::ops::Range;
+use prelude::*;
+
+
+///
+/// A segment tree of monoid values.
+///
+/// The tree is stored implicitly, with the children of node `k` at
+/// nodes `2k` and `2k + 1`, and value `i` at leaf node `n + i`.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct SegmentTree<M> {
+  nodes: Vec<M>,
+}
+
+
+impl<M: Monoid + Clone> SegmentTree<M> {
+
+  /// A new segment tree of a sequence of values.
+  pub fn new(xs: &[M]) -> Self {
+    let n = xs.len();
+    let mut nodes = vec![M::id(); n];
+
+    nodes.extend_from_slice(xs);
+
+    for k in (1..n).rev() {
+      nodes[k] = nodes[2 * k].op(&nodes[2 * k + 1]);
+    }
+
+    SegmentTree { nodes }
+  }
+
+
+  /// The number of values in the tree.
+  pub fn len(&self) -> usize {
+    self.nodes.len() / 2
+  }
+
+
+  /// Test for a tree of no values.
+  pub fn is_empty(&self) -> bool {
+    self.nodes.is_empty()
+  }
+
+
+  /// The value at an index. Panics if the index is out of range.
+  pub fn get(&self, i: usize) -> &M {
+    assert!(i < self.len(), "index {} out of range", i);
+
+    &self.nodes[self.len() + i]
+  }
+
+
+  /// Replace the value at an index. Panics if the index is out of
+  /// range.
+  pub fn set(&mut self, i: usize, x: M) {
+    assert!(i < self.len(), "index {} out of range", i);
+
+    let mut k = self.len() + i;
+
+    self.nodes[k] = x;
+
+    while k > 1 {
+      k /= 2;
+      self.nodes[k] = self.nodes[2 * k].op(&self.nodes[2 * k + 1]);
+    }
+  }
+
+
+  /// The combination of the values in an index range, or the
+  /// identity for an empty range. Panics if the range is out of
+  /// bounds.
+  pub fn query(&self, range: Range<usize>) -> M {
+    assert!(range.start <= range.end && range.end <= self.len(),
+            "range {:?} out of bounds", range);
+
+    let (mut lo, mut hi) = (range.start + self.len(), range.end + self.len());
+    let (mut left, mut right) = (M::id(), M::id());
+
+    while lo < hi {
+      if lo % 2 == 1 {
+        left = left.op(&self.nodes[lo]);
+        lo += 1;
+      }
+
+      if hi % 2 == 1 {
+        hi -= 1;
+        right = self.nodes[hi].op(&right);
+      }
+
+      lo /= 2;
+      hi /= 2;
+    }
+
+    left.op(&right)
+  }
+
+
+  /// The combination of all the values in the tree.
+  pub fn total(&self) -> M {
+    self.query(0..self.len())
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "segment_tree_test.rs"]
+mod segment_tree_test;
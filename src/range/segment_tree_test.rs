@@ -0,0 +1,76 @@
+use prelude::*;
+use proptest::prelude::*;
+use tests::prelude::*;
+
+
+prop_compose! {
+
+  /// Generate a random string vector, an index range and an update.
+  fn strings_range()
+    (xs in prop::collection::vec("[a-c]{0,2}", 1..40))
+    (l in 0..xs.len(), r in 0..=xs.len(), i in 0..xs.len(),
+     x in "[a-c]{0,2}", xs in Just(xs))
+    -> (Vec<String>, (usize, usize), (usize, String)) {
+    (xs, (l.min(r), l.max(r)), (i, x))
+  }
+}
+
+
+proptest! {
+  #![proptest_config(config_with(1000, 500))]
+
+
+  #[test]
+  fn prop_query((ref xs, (l, r), _) in strings_range()) {
+    let tree = SegmentTree::new(xs);
+
+    prop_assert!(tree.query(l..r) == concat(xs[l..r].iter().cloned()));
+  }
+
+
+  #[test]
+  fn prop_set((ref xs, (l, r), (i, ref x)) in strings_range()) {
+    let mut tree = SegmentTree::new(xs);
+    let mut ys = xs.clone();
+
+    tree.set(i, x.clone());
+    ys[i] = x.clone();
+
+    prop_assert!(tree == SegmentTree::new(&ys));
+    prop_assert!(tree.query(l..r) == concat(ys[l..r].iter().cloned()));
+  }
+}
+
+
+#[test]
+fn range_minimums() {
+  let xs: Vec<Min<u8>> = [5, 3, 8, 1, 9, 2].iter().cloned().map(Min).collect();
+  let mut tree = SegmentTree::new(&xs);
+
+  assert_eq!(tree.len(), 6);
+  assert_eq!(tree.query(0..3), Min(3));
+  assert_eq!(tree.query(4..6), Min(2));
+  assert_eq!(tree.query(2..2), Min(u8::MAX));
+  assert_eq!(tree.total(), Min(1));
+
+  tree.set(3, Min(7));
+
+  assert_eq!(tree.get(3), &Min(7));
+  assert_eq!(tree.total(), Min(2));
+}
+
+
+#[test]
+fn empty_tree() {
+  let tree = SegmentTree::<String>::new(&[]);
+
+  assert!(tree.is_empty());
+  assert_eq!(tree.total(), "");
+}
+
+
+#[test]
+#[should_panic]
+fn query_out_of_bounds() {
+  SegmentTree::new(&[Sum(1), Sum(2)]).query(1..3);
+}
@@ -10,6 +10,6 @@
 //! directory, but Rust's trait coherence restrictions make it much
 //! simpler to include it in the crate source.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 pub mod rational;
@@ -10,7 +10,7 @@
 //! library, the "big rational" number type used here is from the very
 //! handy [num] crate.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use prelude::*;
 pub use num::traits::*;
@@ -19,11 +19,13 @@
 //!
 //! See [references] for a formal definition of a ring.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use types::*;
 use monoid::mul_monoid::*;
 use com_group::add_com_group::*;
+use numeric::equal::*;
+use numeric::equivalence::*;
 
 
 ///
@@ -39,71 +41,146 @@ pub trait Ring: AddComGroup + MulMonoid {
 
   /// Test the axiom of left distributivity.
   fn axiom_left_distributivity(xs: Triple<Self>) -> bool {
-    let (x, y, z) = xs;
-
-    x.mul(&y.add(z)) == x.mul(y).add(&x.mul(z))
+    Self::axiom_left_distributivity_by(xs, &Exact)
   }
 
 
   /// Test the axiom of right distributivity.
   fn axiom_right_distributivity(xs: Triple<Self>) -> bool {
-    let (x, y, z) = xs;
-
-    y.add(z).mul(x) == y.mul(x).add(&z.mul(x))
+    Self::axiom_right_distributivity_by(xs, &Exact)
   }
 
 
   /// Test the property of left zero absorbption.
   fn prop_left_zero_absorb(&self) -> bool {
-    self.mul(&Self::zero()) == Self::zero()
+    self.prop_left_zero_absorb_by(&Exact)
   }
 
 
   /// Test the property of right zero absorbption.
   fn prop_right_zero_absorb(&self) -> bool {
-    Self::zero().mul(self) == Self::zero()
+    self.prop_right_zero_absorb_by(&Exact)
   }
 
 
   /// Test the property of right multiplicative negation.
   fn prop_right_mul_negate(xs: Pair<Self>) -> bool {
-    let (x, y) = xs;
-
-    x.mul(&y.negate()) == x.mul(y).negate()
+    Self::prop_right_mul_negate_by(xs, &Exact)
   }
 
 
   /// Test the property of left multiplicative negation.
   fn prop_left_mul_negate(xs: Pair<Self>) -> bool {
-    let (x, y) = xs;
-
-    x.negate().mul(y) == x.mul(y).negate()
+    Self::prop_left_mul_negate_by(xs, &Exact)
   }
 
 
   /// Test the property of multiplicative negation.
   fn prop_mul_negate(xs: Pair<Self>) -> bool {
-    let (x, y) = xs;
-
-    x.negate().mul(&y.negate()) == x.mul(y)
+    Self::prop_mul_negate_by(xs, &Exact)
   }
 
 
   /// Test the property of left one negation.
   fn prop_left_one_negate(&self) -> bool {
-    Self::one().negate().mul(self) == self.negate()
+    self.prop_left_one_negate_by(&Exact)
   }
 
 
   /// Test the property of right one negation.
   fn prop_right_one_negate(&self) -> bool {
-    self.mul(&Self::one().negate()) == self.negate()
+    self.prop_right_one_negate_by(&Exact)
   }
 
 
   /// Test the property of right ones negation.
   fn prop_right_ones_negate(&self) -> bool {
-    Self::one().negate().mul(&Self::one().negate()) == Self::one()
+    self.prop_right_ones_negate_by(&Exact)
+  }
+
+
+  /// Test the axiom of left distributivity up to an equivalence.
+  fn axiom_left_distributivity_by<E>(xs: Triple<Self>, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    let (x, y, z) = xs;
+
+    eq.equiv(&x.mul(&y.add(z)), &x.mul(y).add(&x.mul(z)))
+  }
+
+
+  /// Test the axiom of right distributivity up to an equivalence.
+  fn axiom_right_distributivity_by<E>(xs: Triple<Self>, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    let (x, y, z) = xs;
+
+    eq.equiv(&y.add(z).mul(x), &y.mul(x).add(&z.mul(x)))
+  }
+
+
+  /// Test the property of left zero absorbption up to an equivalence.
+  fn prop_left_zero_absorb_by<E>(&self, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    eq.equiv(&self.mul(&Self::zero()), &Self::zero())
+  }
+
+
+  /// Test the property of right zero absorbption up to an equivalence.
+  fn prop_right_zero_absorb_by<E>(&self, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    eq.equiv(&Self::zero().mul(self), &Self::zero())
+  }
+
+
+  /// Test the property of right multiplicative negation up to an
+  /// equivalence.
+  fn prop_right_mul_negate_by<E>(xs: Pair<Self>, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    let (x, y) = xs;
+
+    eq.equiv(&x.mul(&y.negate()), &x.mul(y).negate())
+  }
+
+
+  /// Test the property of left multiplicative negation up to an
+  /// equivalence.
+  fn prop_left_mul_negate_by<E>(xs: Pair<Self>, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    let (x, y) = xs;
+
+    eq.equiv(&x.negate().mul(y), &x.mul(y).negate())
+  }
+
+
+  /// Test the property of multiplicative negation up to an
+  /// equivalence.
+  fn prop_mul_negate_by<E>(xs: Pair<Self>, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    let (x, y) = xs;
+
+    eq.equiv(&x.negate().mul(&y.negate()), &x.mul(y))
+  }
+
+
+  /// Test the property of left one negation up to an equivalence.
+  fn prop_left_one_negate_by<E>(&self, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    eq.equiv(&Self::one().negate().mul(self), &self.negate())
+  }
+
+
+  /// Test the property of right one negation up to an equivalence.
+  fn prop_right_one_negate_by<E>(&self, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    eq.equiv(&self.mul(&Self::one().negate()), &self.negate())
+  }
+
+
+  /// Test the property of right ones negation up to an equivalence.
+  fn prop_right_ones_negate_by<E>(&self, eq: &E) -> bool
+    where E: Equivalence<Self> {
+    let ones = Self::one().negate().mul(&Self::one().negate());
+
+    eq.equiv(&ones, &Self::one())
   }
 }
 
@@ -114,11 +191,11 @@ pub trait Ring: AddComGroup + MulMonoid {
 /// `NumRing` trait is for types that only form rings when "numeric"
 /// comparisons are allowed, e.g. floating point types.
 ///
-pub trait NumRing: NumAddComGroup + NumMulMonoid {
+pub trait NumRing: Ring + NumAddComGroup + NumMulMonoid {
 
   /// Test the axiom of identities.
   fn axiom_identities() -> bool {
-    Self::zero() != Self::one()
+    <Self as Ring>::axiom_identities()
   }
 
 
@@ -126,7 +203,7 @@ pub trait NumRing: NumAddComGroup + NumMulMonoid {
   fn axiom_left_distributivity(xs: NumTriple<Self>) -> bool {
     let (x, y, z, eps) = xs;
 
-    x.mul(&y.add(z)).num_eq(&x.mul(y).add(&x.mul(z)), eps)
+    Self::axiom_left_distributivity_by((x, y, z), &Within(eps))
   }
 
 
@@ -134,19 +211,19 @@ pub trait NumRing: NumAddComGroup + NumMulMonoid {
   fn axiom_right_distributivity(xs: NumTriple<Self>) -> bool {
     let (x, y, z, eps) = xs;
 
-    y.add(z).mul(x).num_eq(&y.mul(x).add(&z.mul(x)), eps)
+    Self::axiom_right_distributivity_by((x, y, z), &Within(eps))
   }
 
 
   /// Numerically test the property of left zero absorbption.
   fn prop_left_zero_absorb(&self, eps: &Self::Error) -> bool {
-    self.mul(&Self::zero()).num_eq(&Self::zero(), eps)
+    self.prop_left_zero_absorb_by(&Within(eps))
   }
 
 
   /// Numerically test the property of right zero absorbption.
   fn prop_right_zero_absorb(&self, eps: &Self::Error) -> bool {
-    Self::zero().mul(self).num_eq(&Self::zero(), eps)
+    self.prop_right_zero_absorb_by(&Within(eps))
   }
 
 
@@ -154,7 +231,7 @@ pub trait NumRing: NumAddComGroup + NumMulMonoid {
   fn prop_right_mul_negate(xs: NumPair<Self>) -> bool {
     let (x, y, eps) = xs;
 
-    x.mul(&y.negate()).num_eq(&x.mul(y).negate(), eps)
+    Self::prop_right_mul_negate_by((x, y), &Within(eps))
   }
 
 
@@ -162,7 +239,7 @@ pub trait NumRing: NumAddComGroup + NumMulMonoid {
   fn prop_left_mul_negate(xs: NumPair<Self>) -> bool {
     let (x, y, eps) = xs;
 
-    x.negate().mul(y).num_eq(&x.mul(y).negate(), eps)
+    Self::prop_left_mul_negate_by((x, y), &Within(eps))
   }
 
 
@@ -170,29 +247,35 @@ pub trait NumRing: NumAddComGroup + NumMulMonoid {
   fn prop_mul_negate(xs: NumPair<Self>) -> bool {
     let (x, y, eps) = xs;
 
-    x.negate().mul(&y.negate()).num_eq(&x.mul(y), eps)
+    Self::prop_mul_negate_by((x, y), &Within(eps))
   }
 
 
   /// Numerically test the property of left one negation.
   fn prop_left_one_negate(&self, eps: &Self::Error) -> bool {
-    Self::one().negate().mul(self).num_eq(&self.negate(), eps)
+    self.prop_left_one_negate_by(&Within(eps))
   }
 
 
   /// Numerically test the property of right one negation.
   fn prop_right_one_negate(&self, eps: &Self::Error) -> bool {
-    self.mul(&Self::one().negate()).num_eq(&self.negate(), eps)
+    self.prop_right_one_negate_by(&Within(eps))
   }
 
 
   /// Numerically test the property of right ones negation.
   fn prop_right_ones_negate(&self, eps: &Self::Error) -> bool {
-    Self::one().negate().mul(&Self::one().negate()).num_eq(&Self::one(), eps)
+    self.prop_right_ones_negate_by(&Within(eps))
   }
 }
 
 
+///
+/// Rings with numeric equality form numeric rings.
+///
+impl<T: Ring + NumEq> NumRing for T {}
+
+
 ///
 /// Trait implementation macro for integer types.
 ///
@@ -219,15 +302,25 @@ integer_ring! {
 
 
 ///
-/// IEEE 64 bit floating point types only form a _numeric_ ring.
+/// IEEE 32 bit floating point types form a ring.
+///
+/// Rounding means `f32` only forms a ring numerically: the `*_by`
+/// axiom tests hold with a `Within(eps)` equivalence (as do the
+/// `NumRing` tests), but the exact `axiom_*` tests, e.g.
+/// `axiom_left_distributivity`, are false for some values.
 ///
-impl NumRing for f32 {}
+impl Ring for f32 {}
 
 
 ///
-/// IEEE 64 bit floating point types only form a _numeric_ ring.
+/// IEEE 64 bit floating point types form a ring.
+///
+/// Rounding means `f64` only forms a ring numerically: the `*_by`
+/// axiom tests hold with a `Within(eps)` equivalence (as do the
+/// `NumRing` tests), but the exact `axiom_*` tests, e.g.
+/// `axiom_left_distributivity`, are false for some values.
 ///
-impl NumRing for f64 {}
+impl Ring for f64 {}
 
 
 // Module unit tests are in a separate file.
@@ -13,10 +13,12 @@
 //!
 //! See [references] for a formal definition of an additive semigroup.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use types::*;
 use magma::add_magma::*;
+use numeric::equal::*;
+use numeric::equivalence::*;
 
 
 ///
@@ -26,9 +28,16 @@ pub trait AddSemigroup: AddMagma {
 
   /// Test the axiom of associativity.
   fn axiom_add_associativity(xs: Triple<Self>) -> bool {
+    Self::axiom_add_associativity_by(xs, &Exact)
+  }
+
+
+  /// Test the axiom of associativity up to an equivalence.
+  fn axiom_add_associativity_by<E>(xs: Triple<Self>, eq: &E) -> bool
+    where E: Equivalence<Self> {
     let (x, y, z) = xs;
 
-    x.add(&y.add(z)) == x.add(y).add(z)
+    eq.equiv(&x.add(&y.add(z)), &x.add(y).add(z))
   }
 }
 
@@ -40,17 +49,24 @@ pub trait AddSemigroup: AddMagma {
 /// semigroups when "numeric" comparisons are used, e.g. floating
 /// point types.
 ///
-pub trait NumAddSemigroup: NumAddMagma {
+pub trait NumAddSemigroup: AddSemigroup + NumAddMagma {
 
   /// Numerically test the axiom of associativity.
   fn axiom_add_associativity(xs: NumTriple<Self>) -> bool {
     let (x, y, z, eps) = xs;
 
-    x.add(&y.add(z)).num_eq(&x.add(y).add(z), eps)
+    Self::axiom_add_associativity_by((x, y, z), &Within(eps))
   }
 }
 
 
+///
+/// Additive semigroups with numeric equality form numeric
+/// additive semigroups.
+///
+impl<T: AddSemigroup + NumEq> NumAddSemigroup for T {}
+
+
 ///
 /// Trait implementation macro for integer types.
 ///
@@ -83,17 +99,17 @@ integer_add_semigroup! {
 
 
 ///
-/// IEEE 32 bit floating point types only form a _numeric_ additive
+/// IEEE 32 bit floating point types form an additive
 /// semigroup.
 ///
-impl NumAddSemigroup for f32 {}
+impl AddSemigroup for f32 {}
 
 
 ///
-/// IEEE 64 bit floating point types only form a _numeric_ additive
+/// IEEE 64 bit floating point types form an additive
 /// semigroup.
 ///
-impl NumAddSemigroup for f64 {}
+impl AddSemigroup for f64 {}
 
 
 // Module unit tests are in a separate file.
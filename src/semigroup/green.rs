@@ -0,0 +1,245 @@
+//!
+//! _Green's_ _relations_ for finite semigroups.
+//!
+//! Green's relations classify the elements of a semigroup `S` by the
+//! _principal_ _ideals_ they generate. Writing `S¹` for `S` with an
+//! identity adjoined, for elements `x, y ∈ S`:
+//!
+//! 1. L: `x L y` when `S¹x = S¹y` (equal principal left ideals).
+//! 2. R: `x R y` when `xS¹ = yS¹` (equal principal right ideals).
+//! 3. J: `x J y` when `S¹xS¹ = S¹yS¹` (equal principal ideals).
+//! 4. H: `x H y` when `x L y` and `x R y`.
+//! 5. D: `x D y` when `x L z` and `z R y` for some `z ∈ S`.
+//!
+//! In a finite semigroup the D and J relations coincide. Each H-class
+//! containing an idempotent is a group, and the _minimal_ _ideal_ of
+//! a finite semigroup (its _kernel_) is its unique minimal J-class.
+//!
+//! A semigroup is _regular_ when every element `x` has some `y` with
+//! `x·y·x = x`, an _inverse_ semigroup when it is regular and its
+//! idempotents commute, and a _band_ when every element is
+//! idempotent.
+//!
+//! Green's relations can be computed for a finite semigroup type, or
+//! for a finite subsemigroup of any semigroup given by its elements,
+//! e.g. the transition monoid of a finite automaton.
+//!
+//! # References
+//!
+//! See [references] for a formal definition of Green's relations.
+//!
+#![doc = include_str!("../../doc/references.md")]
+
+use prelude::*;
+
+
+///
+/// Green's equivalence relations.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GreenRelation {
+
+  /// Equal principal left ideals.
+  L,
+
+  /// Equal principal right ideals.
+  R,
+
+  /// Both the L and R relations.
+  H,
+
+  /// The composition of the L and R relations.
+  D,
+
+  /// Equal principal two-sided ideals.
+  J,
+}
+
+
+///
+/// The idempotent elements of a finite semigroup, i.e. elements `e`
+/// with `e·e = e`.
+///
+pub fn idempotents<S: Semigroup + Finite>() -> Vec<S> {
+  S::elements().filter(|e| e.op(e) == *e).collect()
+}
+
+
+///
+/// Green's relations of a finite semigroup, computed from its
+/// principal ideals.
+///
+#[derive(Clone, Debug)]
+pub struct Green<S> {
+  elements: Vec<S>,
+  table: Vec<Vec<usize>>,
+  left: Vec<Vec<bool>>,
+  right: Vec<Vec<bool>>,
+  ideal: Vec<Vec<bool>>,
+}
+
+
+impl<S: Semigroup + Finite + Clone> Green<S> {
+
+  /// Green's relations of the semigroup `S`.
+  pub fn new() -> Self {
+    Self::of(S::elements().collect())
+  }
+}
+
+
+impl<S: Semigroup + Clone> Green<S> {
+
+  /// Green's relations of a finite subsemigroup, given by its
+  /// elements. Panics if the elements are not closed under the
+  /// semigroup operation.
+  pub fn of(elements: Vec<S>) -> Self {
+    let n = elements.len();
+    let index = |x: &S| {
+      elements.iter().position(|y| y == x).expect("closed elements")
+    };
+    let table: Vec<Vec<usize>> = elements.iter().map(|x| {
+      elements.iter().map(|y| index(&x.op(y))).collect()
+    }).collect();
+
+    let ideal_of = |x: usize, left: bool, right: bool| {
+      let mut ideal = vec![false; n];
+      ideal[x] = true;
+
+      for s in 0..n {
+        if left { ideal[table[s][x]] = true; }
+        if right { ideal[table[x][s]] = true; }
+        if left && right {
+          for t in 0..n {
+            ideal[table[table[s][x]][t]] = true;
+          }
+        }
+      }
+      ideal
+    };
+
+    Green {
+      left: (0..n).map(|x| ideal_of(x, true, false)).collect(),
+      right: (0..n).map(|x| ideal_of(x, false, true)).collect(),
+      ideal: (0..n).map(|x| ideal_of(x, true, true)).collect(),
+      elements,
+      table,
+    }
+  }
+
+
+  /// The semigroup elements.
+  pub fn elements(&self) -> &[S] {
+    &self.elements
+  }
+
+
+  /// Test two elements are related by a Green's relation.
+  pub fn related(&self, relation: GreenRelation, x: &S, y: &S) -> bool {
+    self.related_index(relation, self.index(x), self.index(y))
+  }
+
+
+  /// The equivalence classes of a Green's relation, in element order.
+  pub fn classes(&self, relation: GreenRelation) -> Vec<Vec<S>> {
+    let mut classes: Vec<Vec<usize>> = Vec::new();
+
+    for x in 0..self.elements.len() {
+      let class = classes.iter_mut().find(|class| {
+        self.related_index(relation, class[0], x)
+      });
+
+      match class {
+        Some(class) => class.push(x),
+        None => classes.push(vec![x]),
+      }
+    }
+
+    classes.iter().map(|class| self.values(class)).collect()
+  }
+
+
+  /// The minimal (two-sided) ideal, or _kernel_, of the semigroup,
+  /// which is empty only for the empty semigroup.
+  pub fn minimal_ideal(&self) -> Vec<S> {
+    let minimal = (0..self.elements.len()).min_by_key(|&x| {
+      self.ideal[x].iter().filter(|&&b| b).count()
+    });
+
+    match minimal {
+      Some(x) => {
+        let members: Vec<usize> = (0..self.elements.len())
+          .filter(|&y| self.ideal[x][y]).collect();
+        self.values(&members)
+      }
+      None => Vec::new(),
+    }
+  }
+
+
+  /// Test every element `x` has some `y` with `x·y·x = x`.
+  pub fn is_regular(&self) -> bool {
+    let n = self.elements.len();
+
+    (0..n).all(|x| (0..n).any(|y| self.table[self.table[x][y]][x] == x))
+  }
+
+
+  /// Test for a regular semigroup with commuting idempotents.
+  pub fn is_inverse(&self) -> bool {
+    let es: Vec<usize> = (0..self.elements.len())
+      .filter(|&e| self.table[e][e] == e).collect();
+
+    self.is_regular() && es.iter().all(|&e| {
+      es.iter().all(|&f| self.table[e][f] == self.table[f][e])
+    })
+  }
+
+
+  /// Test every element is idempotent.
+  pub fn is_band(&self) -> bool {
+    (0..self.elements.len()).all(|e| self.table[e][e] == e)
+  }
+
+
+  /// Test two element indexes are related by a Green's relation.
+  fn related_index(&self, relation: GreenRelation, x: usize, y: usize)
+    -> bool {
+    match relation {
+      GreenRelation::L => self.left[x] == self.left[y],
+      GreenRelation::R => self.right[x] == self.right[y],
+      GreenRelation::H => {
+        self.left[x] == self.left[y] && self.right[x] == self.right[y]
+      }
+      GreenRelation::D => (0..self.elements.len()).any(|z| {
+        self.left[x] == self.left[z] && self.right[z] == self.right[y]
+      }),
+      GreenRelation::J => self.ideal[x] == self.ideal[y],
+    }
+  }
+
+
+  /// The index of an element.
+  fn index(&self, x: &S) -> usize {
+    self.elements.iter().position(|y| y == x).expect("finite element")
+  }
+
+
+  /// The elements at a list of indexes.
+  fn values(&self, indexes: &[usize]) -> Vec<S> {
+    indexes.iter().map(|&x| self.elements[x].clone()).collect()
+  }
+}
+
+
+impl<S: Semigroup + Finite + Clone> Default for Green<S> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+
+// Module unit tests are in a separate file.
+#[cfg(test)]
+#[path = "green_test.rs"]
+mod green_test;
@@ -0,0 +1,112 @@
+use prelude::*;
+
+
+/// The null semigroup `{0, a}` with all products `0`.
+struct Null;
+
+impl CayleyTable<2> for Null {
+  type Kind = Semigroups;
+
+  const OP: Table<2> = [[0, 0], [0, 0]];
+}
+
+
+/// The left zero band `{a, b}` with `x·y = x`.
+struct LeftZero;
+
+impl CayleyTable<2> for LeftZero {
+  type Kind = Semigroups;
+
+  const OP: Table<2> = [[0, 0], [1, 1]];
+}
+
+
+/// The semilattice `{0, 1, 2}` with `x·y = min(x, y)`.
+struct Chain;
+
+impl CayleyTable<3> for Chain {
+  type Kind = Monoids;
+
+  const OP: Table<3> = [[0, 0, 0], [0, 1, 1], [0, 1, 2]];
+}
+
+
+type T3 = Transformation<3>;
+
+
+#[test]
+fn transformation_classes() {
+  let green = Green::<T3>::new();
+
+  // The J-classes of T_n are the transformations of each rank.
+  assert_eq!(green.classes(GreenRelation::J).len(), 3);
+  assert_eq!(green.classes(GreenRelation::D).len(), 3);
+
+  // L-classes are determined by images and R-classes by kernels.
+  assert_eq!(green.classes(GreenRelation::L).len(), 7);
+  assert_eq!(green.classes(GreenRelation::R).len(), 5);
+  assert_eq!(green.classes(GreenRelation::H).len(), 1 + 9 + 3);
+}
+
+
+#[test]
+fn transformation_properties() {
+  let green = Green::<T3>::new();
+  let minimal = green.minimal_ideal();
+
+  assert!(green.is_regular());
+  assert!(!green.is_inverse());
+  assert!(!green.is_band());
+  assert_eq!(minimal.len(), 3);
+  assert!(minimal.iter().all(|t| t.rank() == 1));
+  assert_eq!(idempotents::<T3>().len(), 10);
+}
+
+
+#[test]
+fn group_of_units_h_class() {
+  let green = Green::<T3>::new();
+  let units = green.classes(GreenRelation::H).into_iter()
+    .find(|class| class.contains(&T3::id())).unwrap();
+
+  assert_eq!(units.len(), 6);
+  assert!(units.iter().all(|t| t.rank() == 3));
+}
+
+
+#[test]
+fn groups() {
+  let green = Green::<Cyclic<6>>::new();
+
+  assert_eq!(green.classes(GreenRelation::H).len(), 1);
+  assert_eq!(green.minimal_ideal().len(), 6);
+  assert!(green.is_inverse());
+  assert_eq!(idempotents::<Cyclic<6>>(), vec![Cyclic::id()]);
+}
+
+
+#[test]
+fn bands() {
+  let (left, chain) = (Green::<Cayley<LeftZero, 2>>::new(), Green::new());
+  let chain: Green<Cayley<Chain, 3>> = chain;
+
+  assert!(left.is_band() && left.is_regular() && !left.is_inverse());
+  assert!(chain.is_band() && chain.is_inverse());
+  assert_eq!(left.classes(GreenRelation::L).len(), 1);
+  assert_eq!(left.classes(GreenRelation::R).len(), 2);
+  assert_eq!(chain.classes(GreenRelation::J).len(), 3);
+  assert_eq!(chain.minimal_ideal(), vec![Cayley::new(0)]);
+}
+
+
+#[test]
+fn non_regular() {
+  let green = Green::<Cayley<Null, 2>>::new();
+  let (zero, a) = (Cayley::new(0), Cayley::new(1));
+
+  assert!(!green.is_regular());
+  assert!(!green.related(GreenRelation::J, &zero, &a));
+  assert!(green.related(GreenRelation::H, &a, &a));
+  assert_eq!(green.minimal_ideal(), vec![zero]);
+  assert_eq!(idempotents::<Cayley<Null, 2>>(), vec![zero]);
+}
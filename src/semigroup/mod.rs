@@ -6,8 +6,13 @@
 //! form _additive_ or _multiplicative semigroups_, including their
 //! "numeric" counterparts.
 //!
+//! Finite semigroups can be analysed with _Green's_ _relations_,
+//! e.g. the transition semigroups of finite automata.
+//!
 pub mod semigroup;
 
 pub mod add_semigroup;
 
 pub mod mul_semigroup;
+
+pub mod green;
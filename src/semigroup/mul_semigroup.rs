@@ -14,10 +14,12 @@
 //! See [references] for a formal definition of a multiplicative
 //! semigroup.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use types::*;
 use magma::mul_magma::*;
+use numeric::equal::*;
+use numeric::equivalence::*;
 
 
 ///
@@ -27,9 +29,16 @@ pub trait MulSemigroup: MulMagma {
 
   /// Test the axiom of associativity.
   fn axiom_mul_associativity(xs: Triple<Self>) -> bool {
+    Self::axiom_mul_associativity_by(xs, &Exact)
+  }
+
+
+  /// Test the axiom of associativity up to an equivalence.
+  fn axiom_mul_associativity_by<E>(xs: Triple<Self>, eq: &E) -> bool
+    where E: Equivalence<Self> {
     let (x, y, z) = xs;
 
-    x.mul(&y.mul(z)) == x.mul(y).mul(z)
+    eq.equiv(&x.mul(&y.mul(z)), &x.mul(y).mul(z))
   }
 }
 
@@ -41,17 +50,24 @@ pub trait MulSemigroup: MulMagma {
 /// semigroups when "numeric" comparisons are used, e.g. floating
 /// point types.
 ///
-pub trait NumMulSemigroup: NumMulMagma {
+pub trait NumMulSemigroup: MulSemigroup + NumMulMagma {
 
   /// Numerically test the axiom of associativity.
   fn axiom_mul_associativity(xs: NumTriple<Self>) -> bool {
     let (x, y, z, eps) = xs;
 
-    x.mul(&y.mul(z)).num_eq(&x.mul(y).mul(z), eps)
+    Self::axiom_mul_associativity_by((x, y, z), &Within(eps))
   }
 }
 
 
+///
+/// Multiplicative semigroups with numeric equality form numeric
+/// multiplicative semigroups.
+///
+impl<T: MulSemigroup + NumEq> NumMulSemigroup for T {}
+
+
 ///
 /// Trait implementation macro for integer types.
 ///
@@ -84,17 +100,17 @@ integer_mul_semigroup! {
 
 
 ///
-/// IEEE 32 bit floating point types only form a _numeric_
-/// multiplicative semigroup.
+/// IEEE 32 bit floating point types form a multiplicative
+/// semigroup.
 ///
-impl NumMulSemigroup for f32 {}
+impl MulSemigroup for f32 {}
 
 
 ///
-/// IEEE 64 bit floating point types only form a _numeric_
-/// multiplicative semigroup.
+/// IEEE 64 bit floating point types form a multiplicative
+/// semigroup.
 ///
-impl NumMulSemigroup for f64 {}
+impl MulSemigroup for f64 {}
 
 
 // Module unit tests are in a separate file.
@@ -13,7 +13,7 @@
 //!
 //! See [references] for a formal definition of a semigroup.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 use types::*;
 use magma::magma::*;
@@ -33,3 +33,15 @@ pub trait Semigroup: Magma {
 }
 
 
+///
+/// Strings form a semigroup.
+///
+impl Semigroup for String {}
+
+
+///
+/// Vectors form a semigroup.
+///
+impl<T: Clone + PartialEq> Semigroup for Vec<T> {}
+
+
@@ -0,0 +1,37 @@
+//!
+//! Shared finite structure fixtures for unit tests.
+//!
+//! Small structures used by the unit tests of several modules are
+//! defined once here, e.g. the symmetric group `S3` of permutations
+//! of three elements as a Cayley table.
+//!
+use cayley::table::*;
+use cayley::element::*;
+
+
+///
+/// The Cayley table of the (non-commutative) symmetric group `S3`.
+///
+/// Element `0` is the identity, `1`, `2` and `5` are transpositions,
+/// and `3` and `4` are the 3-cycles (inverses of each other).
+///
+pub struct Sym3;
+
+impl CayleyTable<6> for Sym3 {
+  type Kind = Groups;
+
+  const OP: Table<6> = [
+    [0, 1, 2, 3, 4, 5],
+    [1, 0, 4, 5, 2, 3],
+    [2, 3, 0, 1, 5, 4],
+    [3, 2, 5, 4, 0, 1],
+    [4, 5, 1, 0, 3, 2],
+    [5, 4, 3, 2, 1, 0],
+  ];
+}
+
+
+///
+/// The symmetric group `S3` as Cayley table elements.
+///
+pub type S3 = Cayley<Sym3, 6>;
@@ -8,11 +8,13 @@
 //! The `tests` module provides random value generators and testing
 //! configuration helper functions for `un_algebra` generative tests.
 //!
-#![doc(include = "../doc/references.md")]
+#![doc = include_str!("../../doc/references.md")]
 
 pub mod random;
 
 pub mod config;
 
+pub mod fixtures;
+
 pub mod prelude;
 
@@ -5,5 +5,7 @@ pub use super::config::*;
 
 pub use super::random::*;
 
+pub use super::fixtures::*;
+
 
 
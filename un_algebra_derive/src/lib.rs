@@ -0,0 +1,364 @@
+//!
+//! Derive macros for `un_algebra` structure traits.
+//!
+//! Structs whose fields all form an algebraic structure form the
+//! _direct product_ of the field structures, with component-wise
+//! operations and identities (as `un_algebra` tuples do). Writing the
+//! impl blocks for each structure trait by hand quickly becomes
+//! tedious, e.g. a ring needs ten or more, so this crate derives them:
+//!
+//! ```ignore
+//! #[macro_use]
+//! extern crate un_algebra_derive;
+//! extern crate un_algebra;
+//!
+//! use un_algebra::prelude::*;
+//!
+//! #[derive(Clone, PartialEq, Debug)]
+//! #[derive(AddMagma, AddSemigroup, AddMonoid, AddGroup, AddComGroup)]
+//! #[derive(MulMagma, MulSemigroup, MulMonoid, Ring, ComRing)]
+//! struct Pair(i32, BigRational);
+//! ```
+//!
+//! Derives are provided for each abstract, additive and
+//! multiplicative structure trait up to _commutative_ _ring_. Direct
+//! products of fields are not fields, so there are no field derives.
+//! Derived `is_invertible` methods require all fields to be
+//! invertible. Groups are loops, so deriving `Group` also needs the
+//! `Quasigroup` and `Loop` derives.
+//!
+//! The `NumEq` derive compares fields numerically, with a tuple of
+//! the field error types as the error type. Structures with numeric
+//! equality are "numeric" structures, so deriving `NumEq` alongside
+//! e.g. `Ring` makes a struct of floating point fields a `NumRing`.
+//! The "numeric" structure derives (e.g. `NumRing`) are deprecated
+//! aliases of the structure derives (e.g. `Ring`).
+//!
+//! Derives can be used on structs with named fields, tuple structs
+//! and unit structs. Every field type is required to implement the
+//! derived trait.
+//!
+
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate syn;
+#[macro_use]
+extern crate quote;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as Tokens};
+use syn::{Data, DeriveInput, Fields, Generics, Index, Member, Type};
+
+
+///
+/// Structure trait methods, by how they are derived component-wise.
+///
+enum Method {
+
+  /// A binary operation, e.g. `add`.
+  Binary(&'static str),
+
+  /// An identity element, e.g. `zero`.
+  Identity(&'static str),
+
+  /// A unary operation, e.g. `negate`.
+  Unary(&'static str),
+
+  /// A predicate that holds when it holds for every field.
+  All(&'static str),
+}
+
+
+///
+/// The fields of a struct, as members and types.
+///
+fn fields(input: &DeriveInput) -> syn::Result<Vec<(Member, Type)>> {
+  let data = match input.data {
+    Data::Struct(ref data) => data,
+    _ => {
+      let message = "structure traits can only be derived for structs";
+
+      return Err(syn::Error::new_spanned(&input.ident, message));
+    }
+  };
+
+  let fields = data.fields.iter().enumerate().map(|(i, field)| {
+    let member = match field.ident {
+      Some(ref ident) => Member::Named(ident.clone()),
+      None => Member::Unnamed(Index::from(i)),
+    };
+
+    (member, field.ty.clone())
+  });
+
+  Ok(fields.collect())
+}
+
+
+///
+/// A struct construction expression from field values.
+///
+fn construct(input: &DeriveInput, values: Vec<Tokens>) -> Tokens {
+  match input.data {
+    Data::Struct(ref data) => match data.fields {
+      Fields::Named(ref named) => {
+        let names = named.named.iter().map(|field| &field.ident);
+
+        quote!(Self { #(#names: #values),* })
+      },
+      Fields::Unnamed(_) => quote!(Self(#(#values),*)),
+      Fields::Unit => quote!(Self),
+    },
+    _ => unreachable!(),
+  }
+}
+
+
+///
+/// The struct generics, with every field type bounded by a trait.
+///
+fn bounded(input: &DeriveInput, fields: &[(Member, Type)], path: &Tokens)
+           -> Generics {
+  let mut generics = input.generics.clone();
+
+  {
+    let clause = generics.make_where_clause();
+
+    for (_, ty) in fields {
+      clause.predicates.push(syn::parse_quote!(#ty: #path));
+    }
+  }
+
+  generics
+}
+
+
+///
+/// A component-wise implementation of a structure trait.
+///
+fn expand(input: &DeriveInput, name: &str, methods: &[Method])
+          -> syn::Result<Tokens> {
+  let fields = fields(input)?;
+  let ident = &input.ident;
+  let path = {
+    let name = Ident::new(name, Span::call_site());
+
+    quote!(::un_algebra::prelude::#name)
+  };
+
+  let generics = bounded(input, &fields, &path);
+  let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+  let items = methods.iter().map(|method| {
+    let members = fields.iter().map(|(member, _)| member);
+
+    match *method {
+      Method::Binary(method) => {
+        let method = Ident::new(method, Span::call_site());
+        let values = members
+          .map(|m| quote!(#path::#method(&self.#m, &other.#m)))
+          .collect();
+        let value = construct(input, values);
+
+        quote!(fn #method(&self, other: &Self) -> Self { #value })
+      },
+      Method::Identity(method) => {
+        let method = Ident::new(method, Span::call_site());
+        let values = members.map(|_| quote!(#path::#method())).collect();
+        let value = construct(input, values);
+
+        quote!(fn #method() -> Self { #value })
+      },
+      Method::Unary(method) => {
+        let method = Ident::new(method, Span::call_site());
+        let values = members.map(|m| quote!(#path::#method(&self.#m)))
+          .collect();
+        let value = construct(input, values);
+
+        quote!(fn #method(&self) -> Self { #value })
+      },
+      Method::All(method) => {
+        let method = Ident::new(method, Span::call_site());
+
+        quote! {
+          fn #method(&self) -> bool {
+            true #(&& #path::#method(&self.#members))*
+          }
+        }
+      },
+    }
+  });
+
+  Ok(quote! {
+    impl #impl_generics #path for #ident #ty_generics #where_clause {
+      #(#items)*
+    }
+  })
+}
+
+
+///
+/// A component-wise implementation of numeric equality.
+///
+fn expand_num_eq(input: &DeriveInput) -> syn::Result<Tokens> {
+  let fields = fields(input)?;
+  let ident = &input.ident;
+  let path = quote!(::un_algebra::prelude::NumEq);
+
+  let generics = bounded(input, &fields, &path);
+  let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+  let members: Vec<&Member> = fields.iter().map(|(m, _)| m).collect();
+  let types = fields.iter().map(|(_, ty)| ty);
+  let comparisons = members.iter().enumerate().map(|(i, m)| {
+    let index = Index::from(i);
+
+    quote!(#path::num_eq(&self.#m, &other.#m, &eps.#index))
+  });
+
+  Ok(quote! {
+    impl #impl_generics #path for #ident #ty_generics #where_clause {
+      type Error = (#(<#types as #path>::Error,)*);
+
+      fn num_eq(&self, other: &Self, eps: &Self::Error) -> bool {
+        true #(&& #comparisons)*
+      }
+
+      fn eq(&self, other: &Self) -> bool {
+        true #(&& #path::eq(&self.#members, &other.#members))*
+      }
+
+      fn ne(&self, other: &Self) -> bool {
+        !#path::eq(self, other)
+      }
+    }
+  })
+}
+
+
+///
+/// Parse a derive input and expand it, or report the expansion error.
+///
+fn derive<F>(input: TokenStream, expand: F) -> TokenStream
+  where F: Fn(&DeriveInput) -> syn::Result<Tokens> {
+  let input = syn::parse_macro_input!(input as DeriveInput);
+
+  expand(&input).unwrap_or_else(|e| e.to_compile_error()).into()
+}
+
+
+///
+/// Derive a component-wise implementation of numeric equality.
+///
+#[proc_macro_derive(NumEq)]
+pub fn derive_num_eq(input: TokenStream) -> TokenStream {
+  derive(input, expand_num_eq)
+}
+
+
+///
+/// Derive macro definition macro for structure traits.
+///
+/// A macro used to avoid writing repetitive, boilerplate derive
+/// functions for each structure trait. The macro arguments are the
+/// derive function, the trait, and the trait methods to derive.
+///
+macro_rules! structure_derive {
+  ($($function:ident: $name:ident [$($method:expr),*];)+) => {
+    $(
+      #[doc = concat!("Derive a component-wise `", stringify!($name),
+                      "` implementation.")]
+      #[proc_macro_derive($name)]
+      pub fn $function(input: TokenStream) -> TokenStream {
+        derive(input, |input| {
+          expand(input, stringify!($name), &[$($method),*])
+        })
+      }
+    )+
+  };
+}
+
+
+// Abstract structures.
+structure_derive! {
+  derive_magma: Magma [Method::Binary("op")];
+  derive_quasigroup: Quasigroup [
+    Method::Binary("left_div"), Method::Binary("right_div")
+  ];
+  derive_loop: Loop [Method::Identity("identity")];
+  derive_semigroup: Semigroup [];
+  derive_monoid: Monoid [Method::Identity("id")];
+  derive_group: Group [Method::Unary("inverse")];
+  derive_com_group: ComGroup [];
+}
+
+
+// Additive and multiplicative structures.
+structure_derive! {
+  derive_add_magma: AddMagma [Method::Binary("add")];
+  derive_add_semigroup: AddSemigroup [];
+  derive_add_monoid: AddMonoid [Method::Identity("zero")];
+  derive_add_group: AddGroup [Method::Unary("negate")];
+  derive_add_com_group: AddComGroup [];
+  derive_mul_magma: MulMagma [Method::Binary("mul")];
+  derive_mul_semigroup: MulSemigroup [];
+  derive_mul_monoid: MulMonoid [Method::Identity("one")];
+  derive_mul_group: MulGroup [
+    Method::Unary("invert"), Method::All("is_invertible")
+  ];
+  derive_mul_com_group: MulComGroup [];
+  derive_ring: Ring [];
+  derive_com_ring: ComRing [];
+}
+
+
+///
+/// Deprecated derive macro definition macro for "numeric" structure
+/// traits.
+///
+/// "Numeric" structure traits are implemented for every structure
+/// with numeric equality, so a "numeric" derive expands to the
+/// structure derive. The macro arguments are the derive function,
+/// the "numeric" trait, the structure trait, and the trait methods to
+/// derive.
+///
+macro_rules! numeric_derive {
+  ($($function:ident: $alias:ident => $name:ident
+     [$($method:expr),*];)+) => {
+    $(
+      #[doc = concat!("Derive a component-wise `", stringify!($name),
+                      "` implementation, making a `", stringify!($alias),
+                      "` with a `NumEq` implementation.")]
+      #[deprecated(note = "derive the structure trait and `NumEq`")]
+      #[proc_macro_derive($alias)]
+      pub fn $function(input: TokenStream) -> TokenStream {
+        derive(input, |input| {
+          expand(input, stringify!($name), &[$($method),*])
+        })
+      }
+    )+
+  };
+}
+
+
+// Deprecated "numeric" additive and multiplicative structures.
+numeric_derive! {
+  derive_num_add_magma: NumAddMagma => AddMagma [Method::Binary("add")];
+  derive_num_add_semigroup: NumAddSemigroup => AddSemigroup [];
+  derive_num_add_monoid: NumAddMonoid => AddMonoid [
+    Method::Identity("zero")
+  ];
+  derive_num_add_group: NumAddGroup => AddGroup [Method::Unary("negate")];
+  derive_num_add_com_group: NumAddComGroup => AddComGroup [];
+  derive_num_mul_magma: NumMulMagma => MulMagma [Method::Binary("mul")];
+  derive_num_mul_semigroup: NumMulSemigroup => MulSemigroup [];
+  derive_num_mul_monoid: NumMulMonoid => MulMonoid [
+    Method::Identity("one")
+  ];
+  derive_num_mul_group: NumMulGroup => MulGroup [
+    Method::Unary("invert"), Method::All("is_invertible")
+  ];
+  derive_num_mul_com_group: NumMulComGroup => MulComGroup [];
+  derive_num_ring: NumRing => Ring [];
+  derive_num_com_ring: NumComRing => ComRing [];
+}
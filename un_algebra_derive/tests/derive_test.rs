@@ -0,0 +1,194 @@
+#[macro_use]
+extern crate proptest;
+#[macro_use]
+extern crate un_algebra_derive;
+extern crate un_algebra;
+extern crate num;
+
+use num::rational::*;
+use proptest::prelude::*;
+use un_algebra::prelude::*;
+
+
+/// A tuple struct forming a commutative ring.
+#[derive(Clone, PartialEq, Debug)]
+#[derive(AddMagma, AddSemigroup, AddMonoid, AddGroup, AddComGroup)]
+#[derive(MulMagma, MulSemigroup, MulMonoid, Ring, ComRing)]
+struct Pair(i32, BigRational);
+
+
+/// A tuple struct forming a multiplicative commutative group.
+#[derive(Clone, PartialEq, Debug)]
+#[derive(MulMagma, MulSemigroup, MulMonoid, MulGroup, MulComGroup)]
+struct Ratios(BigRational, BigRational);
+
+
+/// A named field struct forming a commutative group.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Magma, Quasigroup, Loop, Semigroup, Monoid, Group, ComGroup)]
+struct Torus {
+  x: Cyclic<3>,
+  y: Cyclic<4>,
+}
+
+
+/// A named field struct forming a numeric commutative ring.
+#[derive(Clone, Copy, PartialEq, Debug, NumEq)]
+#[derive(AddMagma, AddSemigroup, AddMonoid, AddGroup, AddComGroup)]
+#[derive(MulMagma, MulSemigroup, MulMonoid, MulGroup, MulComGroup)]
+#[derive(Ring, ComRing)]
+struct Point {
+  x: f64,
+  y: f32,
+}
+
+
+/// A tuple struct forming a numeric commutative ring, with the
+/// deprecated "numeric" derives.
+#[allow(deprecated)]
+mod legacy {
+  #[derive(Clone, Copy, PartialEq, Debug, NumEq)]
+  #[derive(NumAddMagma, NumAddSemigroup, NumAddMonoid, NumAddGroup)]
+  #[derive(NumAddComGroup, NumMulMagma, NumMulSemigroup, NumMulMonoid)]
+  #[derive(NumMulGroup, NumMulComGroup, NumRing, NumComRing)]
+  pub struct Legacy(pub f64, pub f64);
+}
+
+use legacy::Legacy;
+
+
+/// A generic struct forming whatever its component forms.
+#[derive(Clone, PartialEq, Debug)]
+#[derive(Magma, Semigroup, Monoid)]
+struct Wrapped<T>(T);
+
+
+/// A unit struct forming the trivial group.
+#[derive(Clone, PartialEq, Debug)]
+#[derive(Magma, Quasigroup, Loop, Semigroup, Monoid, Group, ComGroup)]
+struct Trivial;
+
+
+/// A `Pair` from integer components.
+fn pair(x: i32, n: i64, d: i64) -> Pair {
+  Pair(x, BigRational::new(n.into(), d.into()))
+}
+
+
+prop_compose! {
+
+  /// Generate three random `Pair` values.
+  fn pair_3()(xs in any::<[(i32, i16); 3]>()) -> (Pair, Pair, Pair) {
+    let p = |(x, n): (i32, i16)| pair(x, n.into(), 7);
+
+    (p(xs[0]), p(xs[1]), p(xs[2]))
+  }
+}
+
+
+prop_compose! {
+
+  /// Generate three random `Point` values.
+  fn point_3()(xs in any::<[(i16, i8); 3]>()) -> (Point, Point, Point) {
+    let p = |(x, y): (i16, i8)| Point { x: x.into(), y: y.into() };
+
+    (p(xs[0]), p(xs[1]), p(xs[2]))
+  }
+}
+
+
+proptest! {
+
+  #[test]
+  fn pair_left_distributivity((ref x, ref y, ref z) in pair_3()) {
+    prop_assert!(Ring::axiom_left_distributivity((x, y, z)));
+  }
+
+
+  #[test]
+  fn pair_add_associativity((ref x, ref y, ref z) in pair_3()) {
+    prop_assert!(AddSemigroup::axiom_add_associativity((x, y, z)));
+  }
+
+
+  #[test]
+  fn pair_left_negate((ref x, _, _) in pair_3()) {
+    prop_assert!(AddGroup::axiom_left_negate(x));
+  }
+
+
+  #[test]
+  fn point_left_distributivity((ref x, ref y, ref z) in point_3()) {
+    let eps = (1e-9, 1e-6);
+
+    prop_assert!(NumRing::axiom_left_distributivity((x, y, z, &eps)));
+  }
+}
+
+
+#[test]
+fn component_wise_operations() {
+  let (x, y) = (pair(2, 1, 2), pair(3, 2, 3));
+
+  assert_eq!(x.add(&y), pair(5, 7, 6));
+  assert_eq!(x.mul(&y), pair(6, 1, 3));
+  assert_eq!(x.negate(), pair(-2, -1, 2));
+  assert_eq!(<Pair as AddMonoid>::zero(), pair(0, 0, 1));
+  assert_eq!(<Pair as MulMonoid>::one(), pair(1, 1, 1));
+}
+
+
+#[test]
+fn component_wise_invertibility() {
+  let q = |n: i64, d: i64| BigRational::new(n.into(), d.into());
+  let (x, y) = (Ratios(q(1, 2), q(3, 1)), Ratios(q(1, 2), q(0, 1)));
+
+  assert!(x.is_invertible());
+  assert!(!y.is_invertible());
+  assert_eq!(x.invert(), Ratios(q(2, 1), q(1, 3)));
+}
+
+
+#[test]
+fn component_wise_groups() {
+  let t = Torus { x: Cyclic::new(1), y: Cyclic::new(3) };
+
+  assert_eq!(t.op(&t), Torus { x: Cyclic::new(2), y: Cyclic::new(2) });
+  assert_eq!(t.inverse(), Torus { x: Cyclic::new(2), y: Cyclic::new(1) });
+  assert_eq!(Torus::id(), Torus { x: Cyclic::new(0), y: Cyclic::new(0) });
+  assert_eq!(Trivial.op(&Trivial.inverse()), Trivial::id());
+  assert_eq!(t.left_div(&t), Torus::identity());
+  assert_eq!(Torus::id().right_div(&t), t.inverse());
+
+  let w = Wrapped(String::from("un_"));
+
+  assert_eq!(w.op(&Wrapped("algebra".into())), Wrapped("un_algebra".into()));
+  assert_eq!(Wrapped::<String>::id(), Wrapped(String::new()));
+}
+
+
+#[test]
+fn component_wise_numeric() {
+  let p = Point { x: 0.1 + 0.2, y: 1.0 };
+  let q = Point { x: 0.3, y: 1.0 };
+  let r = Point { x: 0.3, y: 1.001 };
+
+  assert!(p.num_eq(&q, &(0.0, 0.0)));
+  assert!(p.num_ne(&r, &(0.0, 1e-6)));
+  assert!(p.num_eq(&r, &(0.0, 1e-2)));
+  assert!(NumEq::eq(&p, &q));
+  assert!(!Point { x: 1.0, y: 0.0 }.is_invertible());
+}
+
+
+#[test]
+fn deprecated_numeric_derives() {
+  let (x, y) = (Legacy(0.5, 2.0), Legacy(4.0, 0.25));
+  let eps = (1e-12, 1e-12);
+
+  assert_eq!(x.add(&y), Legacy(4.5, 2.25));
+  assert_eq!(x.mul(&y), Legacy(2.0, 0.5));
+  assert_eq!(x.invert(), Legacy(2.0, 0.5));
+  assert!(NumMulGroup::axiom_left_invert(&x, &eps));
+  assert!(NumComRing::axiom_mul_commutivity((&x, &y, &eps)));
+}